@@ -5,6 +5,11 @@ use rand_chacha::ChaCha8Rng;
 
 pub trait Bot {
     fn get_action(&mut self, state: &GameState) -> Action;
+    /// Asked instead of `get_action` when the bot may react to something in the opponent's turn,
+    /// e.g. with Shadowing. Declining is always legal.
+    fn get_reaction(&mut self, state: &GameState) -> Action {
+        self.get_action(state)
+    }
 }
 
 pub struct RandomBot {
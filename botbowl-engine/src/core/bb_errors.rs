@@ -51,6 +51,18 @@ impl fmt::Display for IllegalActionError {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct InvalidFormation {
+    pub name: String,
+}
+
+impl error::Error for InvalidFormation {}
+impl fmt::Display for InvalidFormation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Formation is not a legal setup: {}", self.name)
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct MissingActionError {}
 
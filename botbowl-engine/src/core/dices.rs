@@ -6,8 +6,8 @@ use std::{
 use rand::{distributions::Standard, prelude::Distribution};
 
 use super::{
-    model::{Coord, Direction, InjuryOutcome, Weather},
-    table::{NumBlockDices, SimpleAT},
+    model::{Coord, Direction, DugoutPlayerID, PlayerID, TeamType, Weather},
+    table::{CasualtyOutcome, LastingInjury, NumBlockDices, PrayerToNuffle, SimpleAT},
 };
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
@@ -88,6 +88,87 @@ impl_enum_try_from! {
     ()
 }
 
+impl_enum_try_from! {
+    #[repr(u8)]
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Deserialize, Serialize)]
+    pub enum D16 {
+        One = 1,
+        Two,
+        Three,
+        Four,
+        Five,
+        Six,
+        Seven,
+        Eight,
+        Nine,
+        Ten,
+        Eleven,
+        Twelve,
+        Thirteen,
+        Fourteen,
+        Fifteen,
+        Sixteen,
+    },
+    u8,
+    (),
+    ()
+}
+
+impl Distribution<D16> for Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> D16 {
+        D16::try_from(rng.gen_range(1..=16)).unwrap()
+    }
+}
+
+impl From<D16> for CasualtyOutcome {
+    /// The lasting injury is decided by a separate D6 roll, see `From<D6> for LastingInjury`
+    fn from(roll: D16) -> Self {
+        match roll as u8 {
+            1..=6 => CasualtyOutcome::BadlyHurt,
+            7..=9 => CasualtyOutcome::SeriouslyHurt,
+            10..=12 => CasualtyOutcome::SeriousInjury,
+            13..=14 => CasualtyOutcome::LastingInjury(None),
+            15..=16 => CasualtyOutcome::Dead,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl From<D16> for PrayerToNuffle {
+    fn from(roll: D16) -> Self {
+        match roll {
+            D16::One => PrayerToNuffle::TreacherousTrapdoor,
+            D16::Two => PrayerToNuffle::FriendsWithTheRef,
+            D16::Three => PrayerToNuffle::Stiletto,
+            D16::Four => PrayerToNuffle::IronMan,
+            D16::Five => PrayerToNuffle::KnuckleDusters,
+            D16::Six => PrayerToNuffle::BadHabits,
+            D16::Seven => PrayerToNuffle::GreasyCleats,
+            D16::Eight => PrayerToNuffle::BlessedStatueOfNuffle,
+            D16::Nine => PrayerToNuffle::MolesUnderThePitch,
+            D16::Ten => PrayerToNuffle::PerfectPassing,
+            D16::Eleven => PrayerToNuffle::FanInteraction,
+            D16::Twelve => PrayerToNuffle::NecessaryViolence,
+            D16::Thirteen => PrayerToNuffle::FoulingFrenzy,
+            D16::Fourteen => PrayerToNuffle::ThrowARock,
+            D16::Fifteen => PrayerToNuffle::UnderScrutiny,
+            D16::Sixteen => PrayerToNuffle::IntensiveTraining,
+        }
+    }
+}
+
+impl From<D6> for LastingInjury {
+    fn from(roll: D6) -> Self {
+        match roll {
+            D6::One | D6::Two => LastingInjury::HeadInjury,
+            D6::Three => LastingInjury::SmashedKnee,
+            D6::Four => LastingInjury::BrokenArm,
+            D6::Five => LastingInjury::NeckInjury,
+            D6::Six => LastingInjury::DislocatedShoulder,
+        }
+    }
+}
+
 impl Distribution<D8> for Standard {
     fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> D8 {
         D8::try_from(rng.gen_range(1..=8)).unwrap()
@@ -341,13 +422,21 @@ impl RollTarget<Sum2D6> for Sum2D6Target {
 pub enum RequestedRoll {
     BlockDice(NumBlockDices),
     Coin,
+    D3,
     D6,
     D6PassFail(D6Target),
     D6ThreeOutcomes(D6Target, D6Target),
     D8,
-    FoulArmor(Sum2D6Target),
-    FoulInjury(Sum2D6Target, Sum2D6Target),
+    D16,
+    /// Target with and without the fouler's armour modifier
+    FoulArmor(Sum2D6Target, Sum2D6Target),
+    /// Injury roll where a double gets the fouler sent off
+    FoulInjury,
     Deviate, // TODO: this should be called deviate
+    /// Randomly selects up to this many players of the team on the pitch
+    RandomPlayers(TeamType, u8),
+    /// Randomly selects up to this many players of the team in the reserves box
+    RandomReserves(TeamType, u8),
     Scatter,
     Sum2D6,
     Sum2D6PassFail(Sum2D6Target),
@@ -363,16 +452,21 @@ pub enum RollResult {
     Fail,
     FoulArmor {
         broken: bool,
+        modifier_used: bool,
         ejected: bool,
     },
     FoulInjury {
-        outcome: InjuryOutcome,
+        roll: Sum2D6,
         ejected: bool,
     },
     MiddleOutcome,
+    D3(D3),
     D6(D6),
     D8(D8),
+    D16(D16),
     Deviate(D6, D8),
+    RandomPlayers([Option<PlayerID>; 3]),
+    RandomReserves([Option<DugoutPlayerID>; 3]),
     Scatter(D8, D8, D8),
     Sum2D6(Sum2D6),
     ThrowIn {
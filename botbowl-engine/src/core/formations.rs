@@ -0,0 +1,315 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::bb_errors::InvalidFormation;
+use crate::core::gamestate::GameState;
+use crate::core::model::{
+    Coord, DugoutPlace, DugoutPlayerID, Position, Result, TeamType, HEIGHT_,
+    LINE_OF_SCRIMMAGE_Y_RANGE, NORTH_WING_Y_RANGE, SOUTH_WING_Y_RANGE, WIDTH_,
+};
+use crate::core::table::PlayerRole;
+
+/// Offset from the middle of the line of scrimmage, as (steps back into the own half, steps
+/// sideways). Mirrored automatically for the away team.
+pub type Offset = (Coord, Coord);
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Formation {
+    pub name: String,
+    pub linemen: Vec<Offset>,
+    pub blitzers: Vec<Offset>,
+    pub catchers: Vec<Offset>,
+    pub throwers: Vec<Offset>,
+}
+
+impl Formation {
+    pub fn line() -> Formation {
+        Formation {
+            name: "line".to_string(),
+            linemen: vec![(0, 0), (0, -1), (0, 1), (0, -3), (0, 3)],
+            blitzers: vec![(0, -2), (0, 2)],
+            catchers: vec![(2, 2), (2, -2)],
+            throwers: vec![(6, 3), (6, -3)],
+        }
+    }
+    /// Offensive, pushes through the middle with the ball carrier protected behind
+    pub fn wedge() -> Formation {
+        Formation {
+            name: "wedge".to_string(),
+            linemen: vec![(0, 0), (0, -1), (0, 1), (1, -2), (1, 2)],
+            blitzers: vec![(2, -3), (2, 3)],
+            catchers: vec![(3, -4), (3, 4)],
+            throwers: vec![(4, 0), (6, 0)],
+        }
+    }
+    /// Offensive, catchers and blitzers out on the wings
+    pub fn spread() -> Formation {
+        Formation {
+            name: "spread".to_string(),
+            linemen: vec![(0, 0), (0, -1), (0, 1), (2, -2), (2, 2)],
+            blitzers: vec![(1, -5), (1, 5)],
+            catchers: vec![(3, -6), (3, 6)],
+            throwers: vec![(5, 0), (7, 0)],
+        }
+    }
+    /// Defensive, covers the whole width of the pitch in depth
+    pub fn zone() -> Formation {
+        Formation {
+            name: "zone".to_string(),
+            linemen: vec![(0, 0), (0, -2), (0, 2), (2, -4), (2, 4)],
+            blitzers: vec![(3, -1), (3, 1)],
+            catchers: vec![(6, -5), (6, 5)],
+            throwers: vec![(8, -2), (8, 2)],
+        }
+    }
+    /// Defensive, blitzers right behind the line ready to go for the ball
+    pub fn kick_to_blitzers() -> Formation {
+        Formation {
+            name: "kick to blitzers".to_string(),
+            linemen: vec![(0, 0), (0, -1), (0, 1), (0, -3), (0, 3)],
+            blitzers: vec![(1, -2), (1, 2)],
+            catchers: vec![(4, -4), (4, 4)],
+            throwers: vec![(8, -1), (8, 1)],
+        }
+    }
+
+    /// Reads a json list of formations, fails if any of them isn't a legal setup
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Vec<Formation>> {
+        let text = std::fs::read_to_string(path)?;
+        let formations: Vec<Formation> = serde_json::from_str(&text)?;
+        if let Some(formation) = formations.iter().find(|f| !f.is_legal()) {
+            return Err(Box::new(InvalidFormation {
+                name: formation.name.clone(),
+            }));
+        }
+        Ok(formations)
+    }
+
+    fn offsets(&self, role: PlayerRole) -> &[Offset] {
+        match role {
+            PlayerRole::Lineman => &self.linemen,
+            PlayerRole::Blitzer => &self.blitzers,
+            PlayerRole::Catcher => &self.catchers,
+            PlayerRole::Thrower => &self.throwers,
+        }
+    }
+
+    fn all_offsets(&self) -> impl Iterator<Item = &Offset> {
+        self.linemen
+            .iter()
+            .chain(&self.blitzers)
+            .chain(&self.catchers)
+            .chain(&self.throwers)
+    }
+
+    fn is_on_scrimmage((dx, dy): Offset) -> bool {
+        dx == 0 && LINE_OF_SCRIMMAGE_Y_RANGE.contains(&(HEIGHT_ / 2 + dy))
+    }
+
+    /// Position for the home team, the away half is its mirror image
+    fn home_position((dx, dy): Offset) -> Position {
+        Position::new((WIDTH_ / 2 + dx, HEIGHT_ / 2 + dy))
+    }
+
+    /// Legal with a full team of eleven, then it stays legal with fewer players since
+    /// `setup` fills the scrimmage first
+    pub fn is_legal(&self) -> bool {
+        let offsets: Vec<Offset> = self.all_offsets().copied().collect();
+        let positions: Vec<Position> = offsets.iter().map(|o| Self::home_position(*o)).collect();
+        let num_on_wing = |range: std::ops::RangeInclusive<Coord>| {
+            positions
+                .iter()
+                .filter(|pos| range.contains(&pos.y))
+                .count()
+        };
+        offsets.len() == 11
+            && offsets.iter().all(|(dx, _)| *dx >= 0)
+            && positions.iter().all(|pos| !pos.is_out())
+            && positions
+                .iter()
+                .enumerate()
+                .all(|(i, pos)| !positions[..i].contains(pos))
+            && offsets
+                .iter()
+                .filter(|o| Self::is_on_scrimmage(**o))
+                .count()
+                >= 3
+            && num_on_wing(NORTH_WING_Y_RANGE) <= 2
+            && num_on_wing(SOUTH_WING_Y_RANGE) <= 2
+    }
+
+    pub fn get_position(game_state: &GameState, team: TeamType, (dx, dy): Offset) -> Position {
+        let x_delta_sign = if team == TeamType::Home { 1 } else { -1 };
+        let middle_x = game_state.get_line_of_scrimage_x(team);
+        let middle_y = HEIGHT_ / 2;
+        Position::new((middle_x + dx * x_delta_sign, middle_y + dy))
+    }
+
+    /// Fields the reserves of the team in the formation. Squares of roles missing in the
+    /// dugout are taken by the left over players, filling the line of scrimmage first.
+    pub fn setup(&self, game_state: &mut GameState, team: TeamType) -> Result<()> {
+        let player_ids = game_state
+            .get_players_on_pitch_in_team(team)
+            .map(|p| p.id)
+            .collect::<Vec<_>>();
+        for id in player_ids {
+            game_state.unfield_player(id, DugoutPlace::Reserves)?;
+        }
+
+        let reserves: Vec<(DugoutPlayerID, PlayerRole)> = game_state
+            .get_dugout()
+            .filter(|dplayer| dplayer.stats.team == team)
+            .filter(|dplayer| dplayer.place == DugoutPlace::Reserves)
+            .map(|p| (p.id, p.stats.role))
+            .collect();
+
+        let mut used: Vec<Offset> = Vec::new();
+        let mut placements: Vec<(DugoutPlayerID, Offset)> = Vec::new();
+        let mut left_over: Vec<DugoutPlayerID> = Vec::new();
+        for (id, role) in reserves {
+            match self.offsets(role).iter().find(|o| !used.contains(o)) {
+                Some(offset) => {
+                    used.push(*offset);
+                    placements.push((id, *offset));
+                }
+                None => left_over.push(id),
+            }
+        }
+
+        let mut free: Vec<Offset> = self
+            .all_offsets()
+            .filter(|o| !used.contains(o))
+            .copied()
+            .collect();
+        free.sort_by_key(|o| !Self::is_on_scrimmage(*o));
+        let mut free = free.into_iter();
+        placements.extend(left_over.into_iter().zip(free.by_ref()));
+
+        // with the line of scrimmage roles missing, move players up from the back
+        let min_on_scrimmage = 3.min(placements.len());
+        for offset in free.filter(|o| Self::is_on_scrimmage(*o)) {
+            let on_scrimmage = placements
+                .iter()
+                .filter(|(_, o)| Self::is_on_scrimmage(*o))
+                .count();
+            if on_scrimmage >= min_on_scrimmage {
+                break;
+            }
+            let last_behind = placements
+                .iter_mut()
+                .rev()
+                .find(|(_, o)| !Self::is_on_scrimmage(*o))
+                .unwrap();
+            last_behind.1 = offset;
+        }
+
+        for (id, offset) in placements {
+            let position = Self::get_position(game_state, team, offset);
+            game_state.log(format!(
+                "fielding {:?} {:?} at {:?} ({})",
+                game_state.get_dugout_player(id).unwrap().stats.role,
+                team,
+                position,
+                self.name
+            ));
+            game_state.field_dugout_player(id, position);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::dices::Coin;
+    use crate::core::formations::Formation;
+    use crate::core::gamestate::{GameState, GameStateBuilder};
+    use crate::core::model::*;
+    use crate::core::table::*;
+
+    fn builtin() -> Vec<Formation> {
+        vec![
+            Formation::line(),
+            Formation::wedge(),
+            Formation::spread(),
+            Formation::zone(),
+            Formation::kick_to_blitzers(),
+        ]
+    }
+
+    #[test]
+    fn builtin_formations_are_legal() {
+        for formation in builtin() {
+            assert!(formation.is_legal(), "{}", formation.name);
+            for team in [TeamType::Home, TeamType::Away] {
+                let mut state: GameState = GameStateBuilder::new_at_setup();
+                formation.setup(&mut state, team).unwrap();
+                assert_eq!(state.get_players_on_pitch_in_team(team).count(), 11);
+                assert!(state.is_setup_legal(team), "{} {:?}", formation.name, team);
+            }
+        }
+    }
+
+    #[test]
+    fn formation_with_missing_roles() {
+        let mut state: GameState = GameStateBuilder::new_at_setup();
+        let team = TeamType::Home;
+        //no linemen or blitzers left to put on the line of scrimmage
+        let ids: Vec<DugoutPlayerID> = state
+            .get_dugout()
+            .filter(|p| p.stats.team == team)
+            .filter(|p| matches!(p.stats.role, PlayerRole::Lineman | PlayerRole::Blitzer))
+            .map(|p| p.id)
+            .collect();
+        for id in ids {
+            state.get_dugout_player_mut(id).unwrap().place = DugoutPlace::Injuried;
+        }
+
+        for formation in builtin() {
+            formation.setup(&mut state, team).unwrap();
+            assert_eq!(state.get_players_on_pitch_in_team(team).count(), 4);
+            assert!(state.is_setup_legal(team), "{}", formation.name);
+        }
+    }
+
+    #[test]
+    fn load_formations_from_file() {
+        let path = std::env::temp_dir().join("botbowl_formations_test.json");
+        let mut custom = Formation::zone();
+        custom.name = "custom".to_string();
+        custom.throwers = vec![(9, 0), (10, 0)];
+        std::fs::write(&path, serde_json::to_string(&vec![custom.clone()]).unwrap()).unwrap();
+        assert_eq!(
+            Formation::load_from_file(&path).unwrap(),
+            vec![custom.clone()]
+        );
+
+        custom.linemen = vec![(1, 0), (1, -1), (1, 1), (1, -3), (1, 3)];
+        std::fs::write(&path, serde_json::to_string(&vec![custom]).unwrap()).unwrap();
+        assert!(Formation::load_from_file(&path).is_err());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn setup_custom_formation() {
+        let mut state: GameState = GameStateBuilder::new_start_of_game();
+        let mut custom = Formation::spread();
+        custom.name = "custom".to_string();
+        custom.throwers = vec![(9, 0), (10, 0)];
+        state.formations.push(custom);
+        state.fixes.fix_coin(Coin::Heads);
+        state.step_simple(SimpleAT::Heads);
+        state.step_simple(SimpleAT::Kick);
+
+        state.step_simple(SimpleAT::SetupCustom(0));
+        let thrower_pos = Position::new((14 + 9, HEIGHT_ / 2));
+        let player = state.get_player_at(thrower_pos).unwrap();
+        assert_eq!(player.stats.role, PlayerRole::Thrower);
+        state.step_simple(SimpleAT::EndSetup);
+
+        state.step_simple(SimpleAT::SetupZone);
+        state.step_simple(SimpleAT::EndSetup);
+        let player = state.get_player_at(Position::new((13 - 8, HEIGHT_ / 2 - 2)));
+        assert_eq!(player.unwrap().stats.role, PlayerRole::Thrower);
+    }
+}
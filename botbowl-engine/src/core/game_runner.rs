@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use crate::bots::Bot;
 
 use super::{
-    gamestate::{BuilderState, GameState, GameStateBuilder},
+    gamestate::{GameState, GameStateBuilder},
     model::TeamType,
 };
 
@@ -55,12 +55,17 @@ impl BotGameRunner {
         "hey".to_string()
     }
     fn step_state(&mut self) {
-        let action = match self.state.available_actions.team {
-            Some(TeamType::Home) => Some(self.home_bot.get_action(&self.state)),
-            Some(TeamType::Away) => Some(self.away_bot.get_action(&self.state)),
-            None => None,
+        let bot = match self.state.available_actions.team {
+            Some(TeamType::Home) => &mut self.home_bot,
+            Some(TeamType::Away) => &mut self.away_bot,
+            None => return self.state.micro_step(None).unwrap(),
         };
-        self.state.micro_step(action).unwrap();
+        let action = if self.state.available_actions.is_reaction() {
+            bot.get_reaction(&self.state)
+        } else {
+            bot.get_action(&self.state)
+        };
+        self.state.micro_step(Some(action)).unwrap();
     }
     pub fn save_to_file(&self) {
         let Some(file) = &self.save_file else {
@@ -139,9 +144,10 @@ impl BotGameRunnerBuilder {
         self
     }
     fn default_state() -> GameState {
-        let mut s = GameStateBuilder::new()
-            .set_state(BuilderState::CoinToss)
-            .build();
+        let mut s = GameStateBuilder::new_pregame();
+        // both teams have hired an apothecary
+        s.home.apothecaries = 1;
+        s.away.apothecaries = 1;
         s.rng_enabled = true;
         s
     }
@@ -166,13 +172,82 @@ mod gamestate_tests {
 
     use std::collections::HashSet;
 
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use crate::bots::Bot;
     use crate::core::{
         gamestate::{GameState, GameStateBuilder},
-        model::{Position, TeamType},
+        model::{Action, Position, TeamType},
+        table::{PosAT, SimpleAT, Skill},
     };
 
     use super::{BotGameRunnerBuilder, GameRunner, Recording};
 
+    struct ScriptedBot {
+        actions: Vec<Action>,
+    }
+    impl Bot for ScriptedBot {
+        fn get_action(&mut self, _state: &GameState) -> Action {
+            self.actions.remove(0)
+        }
+    }
+    struct DecliningBot {
+        reactions: Rc<Cell<u8>>,
+    }
+    impl Bot for DecliningBot {
+        fn get_action(&mut self, _state: &GameState) -> Action {
+            panic!("it's not this bot's turn")
+        }
+        fn get_reaction(&mut self, _state: &GameState) -> Action {
+            self.reactions.set(self.reactions.get() + 1);
+            Action::Simple(SimpleAT::Decline)
+        }
+    }
+
+    #[test]
+    fn opponent_asked_for_reaction() {
+        let start_pos = Position::new((5, 5));
+        let shadow_pos = Position::new((6, 5));
+        let mut state = GameStateBuilder::new()
+            .add_home_player(start_pos)
+            .add_away_player(shadow_pos)
+            .build();
+        let shadow_id = state.get_player_id_at(shadow_pos).unwrap();
+        state
+            .get_mut_player_unsafe(shadow_id)
+            .stats
+            .give_skill(Skill::Shadowing);
+        state.fixes.fix_d6(3); // dodge
+
+        let reactions = Rc::new(Cell::new(0));
+        let home_bot = ScriptedBot {
+            actions: vec![
+                Action::Positional(PosAT::StartMove, start_pos),
+                Action::Positional(PosAT::Move, Position::new((4, 5))),
+            ],
+        };
+        let away_bot = DecliningBot {
+            reactions: reactions.clone(),
+        };
+        let mut runner = BotGameRunnerBuilder::new()
+            .set_state(state)
+            .set_home_bot(Box::new(home_bot))
+            .set_away_bot(Box::new(away_bot))
+            .build();
+        while reactions.get() == 0 {
+            runner.step();
+        }
+        runner.step();
+
+        assert_eq!(reactions.get(), 1);
+        assert!(runner.get_state().home_to_act());
+        assert_eq!(
+            runner.get_state().get_player_unsafe(shadow_id).position,
+            shadow_pos
+        );
+    }
+
     #[test]
     fn build_runner_with_custom_state() {
         let expected_home_positions = &[(1, 2), (2, 2), (3, 1)];
@@ -15,9 +15,12 @@ use model::*;
 
 use super::{
     bb_errors::{IllegalActionError, IllegalMovePosition, InvalidPlayerId, MissingActionError},
-    dices::{BlockDice, Coin, D6Target, RequestedRoll, RollResult, RollTarget, Sum2D6, D3, D6, D8},
-    procedures::{AnyProc, GameOver, Half},
-    table::{NumBlockDices, PosAT, SimpleAT},
+    dices::{
+        BlockDice, Coin, D6Target, RequestedRoll, RollResult, RollTarget, Sum2D6, D16, D3, D6, D8,
+    },
+    formations::Formation,
+    procedures::{AnyProc, GameOver, Half, Pregame},
+    table::{EffectDuration, NumBlockDices, PosAT, PrayerToNuffle, SimpleAT, Skill},
 };
 
 pub enum BuilderState {
@@ -64,10 +67,32 @@ impl GameStateBuilder {
         state.step_simple(SimpleAT::EndSetup); //Home
         state
     }
-    ///creates a gamestate with two human teams at very beginning of a gamestate
-    ///which right now is the coin toss. (but later should be pregame which does weather roll abd
-    ///such)
+    ///creates a gamestate with two human teams at very beginning of a gamestate, skipping the
+    ///pregame so the weather stays nice. Next is the coin toss.
     pub fn new_start_of_game() -> GameState {
+        let mut state = GameStateBuilder::new_with_dugout(vec![
+            GameOver::new(),
+            Half::new(2),
+            Half::new(1),
+            CoinToss::new(),
+        ]);
+        state.step_simple(SimpleAT::EndTurn);
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::Heads)));
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::Tails)));
+        // available_actions: AvailableActions::new_empty(),
+        state
+    }
+    pub fn new_pregame() -> GameState {
+        GameStateBuilder::new_with_dugout(vec![
+            GameOver::new(),
+            Half::new(2),
+            Half::new(1),
+            CoinToss::new(),
+            Pregame::new(),
+        ])
+    }
+    /// Both teams in the reserves, with the procedures of the game ahead
+    fn new_with_dugout(proc_stack: Vec<AnyProc>) -> GameState {
         let mut state = GameStateBuilder::empty_state();
 
         // Dugout
@@ -87,11 +112,7 @@ impl GameStateBuilder {
             }
         }
 
-        state.proc_stack = vec![GameOver::new(), Half::new(2), Half::new(1), CoinToss::new()];
-        state.step_simple(SimpleAT::EndTurn);
-        assert!(state.is_legal_action(&Action::Simple(SimpleAT::Heads)));
-        assert!(state.is_legal_action(&Action::Simple(SimpleAT::Tails)));
-        // available_actions: AvailableActions::new_empty(),
+        state.proc_stack = proc_stack;
         state
     }
     pub fn new() -> GameStateBuilder {
@@ -185,6 +206,7 @@ impl GameStateBuilder {
             rng_enabled: false,
             info: GameInfo::new(),
             fixes: Default::default(),
+            formations: Vec::new(),
             log: Vec::new(),
             print_log: false,
             next_input: None,
@@ -284,6 +306,9 @@ pub struct GameInfo {
     pub turnover: bool,
     pub active_player: Option<PlayerID>,
     pub player_action_type: Option<PosAT>,
+    /// The active player has made the block of a Blitz or the foul of a Foul action and may only
+    /// move on
+    pub player_action_done: bool,
     pub team_turn: TeamType,
     pub game_over: bool,
     pub weather: Weather,
@@ -293,8 +318,11 @@ pub struct GameInfo {
     pub handoff_available: bool,
     pub foul_available: bool,
     pub pass_available: bool,
+    pub throw_team_mate_available: bool,
     pub blitz_available: bool,
     pub handle_td_by: Option<PlayerID>,
+    /// The prayers to Nuffle in effect and the team that prayed for them
+    pub prayers: Vec<(TeamType, PrayerToNuffle)>,
 }
 impl GameInfo {
     fn new() -> GameInfo {
@@ -309,14 +337,17 @@ impl GameInfo {
             home_turn: 0,
             away_turn: 0,
             player_action_type: None,
+            player_action_done: false,
             handoff_available: true,
             pass_available: true,
+            throw_team_mate_available: true,
             foul_available: true,
             blitz_available: true,
             handle_td_by: None,
             kickoff_by_team: None,
             kicking_this_drive: TeamType::Away,
             turnover: false,
+            prayers: Vec::new(),
         }
     }
 }
@@ -326,7 +357,9 @@ pub struct FixedDice {
     d6_fixes: VecDeque<D6>,
     blockdice_fixes: VecDeque<BlockDice>,
     d8_fixes: VecDeque<D8>,
+    d16_fixes: VecDeque<D16>,
     coin_fixes: VecDeque<Coin>,
+    player_fixes: VecDeque<PlayerID>,
 }
 impl FixedDice {
     pub fn fix_coin(&mut self, value: Coin) {
@@ -341,22 +374,36 @@ impl FixedDice {
     pub fn fix_d8(&mut self, value: u8) {
         self.d8_fixes.push_back(D8::try_from(value).unwrap());
     }
+    pub fn fix_d16(&mut self, value: u8) {
+        self.d16_fixes.push_back(D16::try_from(value).unwrap());
+    }
     pub fn fix_d8_direction(&mut self, direction: Direction) {
         self.d8_fixes.push_back(D8::from(direction));
     }
     pub fn fix_blockdice(&mut self, value: BlockDice) {
         self.blockdice_fixes.push_back(value);
     }
+    /// Fixes the outcome of the next random player selection, a dugout id when selecting from the
+    /// reserves
+    pub fn fix_random_player(&mut self, id: PlayerID) {
+        self.player_fixes.push_back(id);
+    }
     pub fn is_empty(&self) -> bool {
-        self.d6_fixes.is_empty() && self.d8_fixes.is_empty() && self.blockdice_fixes.is_empty()
+        self.d6_fixes.is_empty()
+            && self.d8_fixes.is_empty()
+            && self.d16_fixes.is_empty()
+            && self.blockdice_fixes.is_empty()
+            && self.player_fixes.is_empty()
     }
     pub fn assert_is_empty(&self) {
         assert!(
             self.is_empty(),
-            "fixed dices are not empty: d6:{:?}, d8: {:?}, blockdice: {:?}",
+            "fixed dices are not empty: d6:{:?}, d8: {:?}, d16: {:?}, blockdice: {:?}, players: {:?}",
             self.d6_fixes,
             self.d8_fixes,
-            self.blockdice_fixes
+            self.d16_fixes,
+            self.blockdice_fixes,
+            self.player_fixes
         );
     }
 }
@@ -376,6 +423,9 @@ pub struct GameState {
     pub available_actions: Box<AvailableActions>,
     pub rng_enabled: bool,
     pub fixes: FixedDice,
+    /// user defined formations, available in setup as `SimpleAT::SetupCustom`
+    #[serde(default)]
+    pub formations: Vec<Formation>,
 
     #[serde(skip)]
     #[derivative(PartialEq = "ignore")]
@@ -408,7 +458,11 @@ impl GameState {
     pub fn get_dugout_mut(&mut self) -> impl Iterator<Item = &mut DugoutPlayer> {
         self.dugout_players.iter_mut().flatten()
     }
-    pub fn dugout_add_new_player(&mut self, player_stats: PlayerStats, place: DugoutPlace) {
+    pub fn dugout_add_new_player(
+        &mut self,
+        player_stats: PlayerStats,
+        place: DugoutPlace,
+    ) -> DugoutPlayerID {
         let id = match self
             .dugout_players
             .iter()
@@ -422,7 +476,9 @@ impl GameState {
             stats: player_stats,
             place,
             id,
-        })
+            casualty: None,
+        });
+        id
     }
     pub fn get_dugout_player(&self, id: DugoutPlayerID) -> Option<&DugoutPlayer> {
         self.dugout_players[id].as_ref()
@@ -455,6 +511,7 @@ impl GameState {
     pub fn set_active_player(&mut self, id: PlayerID) {
         debug_assert!(self.get_player(id).is_ok());
         self.info.active_player = Some(id);
+        self.info.player_action_done = false;
     }
 
     pub fn get_active_player(&self) -> Option<&FieldedPlayer> {
@@ -509,6 +566,16 @@ impl GameState {
         }
     }
 
+    fn get_d16_roll(&mut self) -> D16 {
+        match self.fixes.d16_fixes.pop_front() {
+            Some(roll) => roll,
+            None => {
+                assert!(self.rng_enabled);
+                self.rng.gen()
+            }
+        }
+    }
+
     fn get_coin_toss(&mut self) -> Coin {
         match self.fixes.coin_fixes.pop_front() {
             Some(fixed_toss) => fixed_toss,
@@ -519,6 +586,44 @@ impl GameState {
         }
     }
 
+    fn get_random_players(&mut self, team: TeamType, num: u8) -> [Option<PlayerID>; 3] {
+        let candidates: Vec<PlayerID> = self
+            .get_players_on_pitch_in_team(team)
+            .map(|p| p.id)
+            .collect();
+        self.pick_random_players(candidates, num)
+    }
+
+    fn get_random_reserves(&mut self, team: TeamType, num: u8) -> [Option<DugoutPlayerID>; 3] {
+        let candidates: Vec<DugoutPlayerID> = self
+            .get_dugout()
+            .filter(|p| p.stats.team == team && p.place == DugoutPlace::Reserves)
+            .map(|p| p.id)
+            .collect();
+        self.pick_random_players(candidates, num)
+    }
+
+    fn pick_random_players(&mut self, mut candidates: Vec<usize>, num: u8) -> [Option<usize>; 3] {
+        let mut players: [Option<usize>; 3] = [None, None, None];
+        for player in players.iter_mut().take(num as usize) {
+            if candidates.is_empty() {
+                break;
+            }
+            let index = match self.fixes.player_fixes.pop_front() {
+                Some(id) => candidates
+                    .iter()
+                    .position(|&candidate| candidate == id)
+                    .expect("fixed player must be a candidate of the team"),
+                None => {
+                    assert!(self.rng_enabled);
+                    self.rng.gen_range(0..candidates.len())
+                }
+            };
+            *player = Some(candidates.swap_remove(index));
+        }
+        players
+    }
+
     pub fn get_best_kickoff_aim_for(&self, team: TeamType) -> Position {
         match team {
             TeamType::Home => Position::new((WIDTH_ / 4, HEIGHT_ / 2 - 1)),
@@ -636,16 +741,21 @@ impl GameState {
         let player = self.get_player(id)?;
         let mut target = player.ag_target();
         let team = player.stats.team;
-        target.add_modifer(
-            -(self
-                .get_adj_players(player.position)
-                .filter(|player_| player_.stats.team != team && player_.has_tackle_zone())
-                .count() as i8),
-        );
+        if !player.has_skill(Skill::NervesOfSteel) {
+            target.add_modifer(
+                -(self
+                    .get_adj_players(player.position)
+                    .filter(|player_| player_.stats.team != team && player_.has_tackle_zone())
+                    .count() as i8),
+            );
+        }
 
         if let Weather::Rain = self.info.weather {
             target.add_modifer(-1);
         }
+        if player.has_skill(Skill::ExtraArms) {
+            target.add_modifer(1);
+        }
         Ok(target)
     }
 
@@ -690,6 +800,36 @@ impl GameState {
         attacker: PlayerID,
         attacker_pos: Position,
         defender: PlayerID,
+    ) -> NumBlockDices {
+        let attr_str = self.get_player_unsafe(attacker).stats.str_;
+        let defr_str = self.get_player_unsafe(defender).stats.str_;
+        self.get_blockdices_with_str(attacker, attacker_pos, defender, attr_str, defr_str)
+    }
+
+    /// Block dices of a block made as part of a blitz, Horns gives +1 strength
+    pub fn get_blitz_blockdices_from(
+        &self,
+        attacker: PlayerID,
+        attacker_pos: Position,
+        defender: PlayerID,
+    ) -> NumBlockDices {
+        let attr = self.get_player_unsafe(attacker);
+        let mut attr_str = attr.stats.str_;
+        if attr.has_skill(Skill::Horns) {
+            attr_str += 1;
+        }
+        let defr_str = self.get_player_unsafe(defender).stats.str_;
+        self.get_blockdices_with_str(attacker, attacker_pos, defender, attr_str, defr_str)
+    }
+
+    /// Block dices with the strengths before assists given, e.g. after Dauntless or Multiple Block
+    pub fn get_blockdices_with_str(
+        &self,
+        attacker: PlayerID,
+        attacker_pos: Position,
+        defender: PlayerID,
+        mut attr_str: u8,
+        mut defr_str: u8,
     ) -> NumBlockDices {
         let attr = self.get_player_unsafe(attacker);
         let defr = self.get_player_unsafe(defender);
@@ -699,17 +839,14 @@ impl GameState {
         // debug_assert!(attr.has_tackle_zone());
         debug_assert_eq!(defr.status, PlayerStatus::Up);
 
-        let mut attr_str = attr.stats.str_;
-        let mut defr_str = defr.stats.str_;
-
         attr_str += self
             .get_adj_players(defr.position)
             .filter(|attr_assister| {
                 attr_assister.id != attr.id
                     && attr_assister.stats.team == attr.stats.team
                     && attr_assister.has_tackle_zone()
-                    && self.get_tz_on_except_from_id(attr_assister.id, defr.id) == 0
-                //what is guard anyway?
+                    && (attr_assister.has_skill(Skill::Guard)
+                        || self.get_tz_on_except_from_id(attr_assister.id, defr.id) == 0)
             })
             .count() as u8;
 
@@ -719,8 +856,8 @@ impl GameState {
                 defr_assister.id != defr.id
                     && defr_assister.stats.team == defr.stats.team
                     && defr_assister.has_tackle_zone()
-                    && self.get_tz_on_except_from_id(defr_assister.id, attr.id) == 0
-                //what is guard anyway?
+                    && (defr_assister.has_skill(Skill::Guard)
+                        || self.get_tz_on_except_from_id(defr_assister.id, attr.id) == 0)
             })
             .count() as u8;
 
@@ -756,6 +893,15 @@ impl GameState {
         self.board[new_pos] = Some(id);
         Ok(())
     }
+    pub fn swap_players(&mut self, id_a: PlayerID, id_b: PlayerID) -> Result<()> {
+        let pos_a = self.get_player(id_a)?.position;
+        let pos_b = self.get_player(id_b)?.position;
+        self.get_mut_player(id_a)?.position = pos_b;
+        self.get_mut_player(id_b)?.position = pos_a;
+        self.board[pos_a] = Some(id_b);
+        self.board[pos_b] = Some(id_a);
+        Ok(())
+    }
     pub fn get_players_on_pitch(&self) -> impl Iterator<Item = &FieldedPlayer> {
         self.fielded_players.iter().filter_map(|x| x.as_ref())
     }
@@ -769,6 +915,31 @@ impl GameState {
         self.get_players_on_pitch()
             .filter(move |p| p.stats.team == team)
     }
+    /// Ends the prayers to Nuffle that last no longer than `duration`
+    pub fn end_prayers(&mut self, duration: EffectDuration) {
+        self.info
+            .prayers
+            .retain(|(_, prayer)| prayer.duration() > duration);
+        self.get_players_on_pitch_mut()
+            .for_each(|p| p.stats.end_prayers(duration));
+        self.get_dugout_mut()
+            .for_each(|p| p.stats.end_prayers(duration));
+    }
+    /// Target of a rush, -1 in a blizzard and for each Moles under the Pitch in effect
+    pub fn get_rush_target(&self) -> D6Target {
+        let mut target = D6Target::TwoPlus;
+        if self.info.weather == Weather::Blizzard {
+            target.add_modifer(-1);
+        }
+        let moles = self
+            .info
+            .prayers
+            .iter()
+            .filter(|(_, prayer)| *prayer == PrayerToNuffle::MolesUnderThePitch)
+            .count();
+        target.add_modifer(-(moles as i8));
+        target
+    }
     pub fn add_new_player_to_field(
         &mut self,
         player_stats: PlayerStats,
@@ -797,11 +968,12 @@ impl GameState {
             used: false,
             moves: 0,
             used_skills: HashSet::new(),
+            lost_tackle_zones: false,
         });
         Ok(id)
     }
 
-    pub fn unfield_player(&mut self, id: PlayerID, place: DugoutPlace) -> Result<()> {
+    pub fn unfield_player(&mut self, id: PlayerID, place: DugoutPlace) -> Result<DugoutPlayerID> {
         if let BallState::Carried(carrier_id) = self.ball {
             assert_ne!(carrier_id, id);
         }
@@ -813,10 +985,10 @@ impl GameState {
             stats, position, ..
         } = self.fielded_players[id].take().unwrap();
 
-        self.dugout_add_new_player(stats, place);
+        let dugout_id = self.dugout_add_new_player(stats, place);
 
         self.board[position] = None;
-        Ok(())
+        Ok(dugout_id)
     }
 
     pub fn unfield_all_players(&mut self) -> Result<()> {
@@ -826,9 +998,9 @@ impl GameState {
             .map(|player| player.id)
             .collect();
 
-        player_id_on_pitch
-            .into_iter()
-            .for_each(|id| self.unfield_player(id, DugoutPlace::Reserves).unwrap());
+        player_id_on_pitch.into_iter().for_each(|id| {
+            self.unfield_player(id, DugoutPlace::Reserves).unwrap();
+        });
         Ok(())
     }
     pub fn clear_all_players(&mut self) -> Result<()> {
@@ -847,7 +1019,7 @@ impl GameState {
                     Some(action) if !self.is_legal_action(&action) => {
                         return Err(Box::new(IllegalActionError { action }))
                     }
-                    Some(action) => ProcInput::Action(action),
+                    Some(action) => self.available_actions.input_for(action),
                 }
             }
         };
@@ -857,7 +1029,7 @@ impl GameState {
             .ok_or_else(|| Box::new(EmptyProcStackError {}))?;
 
         let proc_return = top_proc.step(self, proc_input);
-        self.available_actions = Default::default();
+        *self.available_actions = Default::default();
         self.next_input = match proc_return {
             ProcState::NotDoneNewProcs(new_procs) => {
                 self.proc_stack.push(top_proc);
@@ -911,7 +1083,7 @@ impl GameState {
                 return Err(Box::new(IllegalActionError { action }));
             } else {
                 self.log(format!("STEPPING: {:?}\n  action={:?}", top_proc, action));
-                ProcInput::Action(action)
+                self.available_actions.input_for(action)
             }
         };
 
@@ -972,6 +1144,7 @@ impl GameState {
 
     fn get_roll_result(&mut self, requested_roll: RequestedRoll) -> RollResult {
         match requested_roll {
+            RequestedRoll::D3 => RollResult::D3(self.get_d3_roll()),
             RequestedRoll::D6 => RollResult::D6(self.get_d6_roll()),
             RequestedRoll::D6PassFail(target) => {
                 if target.is_success(self.get_d6_roll()) {
@@ -1009,28 +1182,24 @@ impl GameState {
                 }
             }
             RequestedRoll::D8 => RollResult::D8(self.get_d8_roll()),
+            RequestedRoll::D16 => RollResult::D16(self.get_d16_roll()),
             RequestedRoll::Coin => RollResult::Coin(self.get_coin_toss()),
             RequestedRoll::Deviate => RollResult::Deviate(self.get_d6_roll(), self.get_d8_roll()),
-            RequestedRoll::FoulArmor(target) => {
+            RequestedRoll::FoulArmor(target, unmodified_target) => {
                 let roll1 = self.get_d6_roll();
                 let roll2 = self.get_d6_roll();
+                let broken = target.is_success(roll1 + roll2);
                 RollResult::FoulArmor {
-                    broken: target.is_success(roll1 + roll2),
+                    broken,
+                    modifier_used: broken && !unmodified_target.is_success(roll1 + roll2),
                     ejected: roll1 == roll2,
                 }
             }
-            RequestedRoll::FoulInjury(ko_target, cas_target) => {
+            RequestedRoll::FoulInjury => {
                 let roll1 = self.get_d6_roll();
                 let roll2 = self.get_d6_roll();
-                let outcome = if cas_target.is_success(roll1 + roll2) {
-                    InjuryOutcome::Casualty
-                } else if ko_target.is_success(roll1 + roll2) {
-                    InjuryOutcome::KO
-                } else {
-                    InjuryOutcome::Stunned
-                };
                 RollResult::FoulInjury {
-                    outcome,
+                    roll: roll1 + roll2,
                     ejected: roll1 == roll2,
                 }
             }
@@ -1045,6 +1214,12 @@ impl GameState {
                 }
                 RollResult::BlockDice(dices)
             }
+            RequestedRoll::RandomPlayers(team, num) => {
+                RollResult::RandomPlayers(self.get_random_players(team, num))
+            }
+            RequestedRoll::RandomReserves(team, num) => {
+                RollResult::RandomReserves(self.get_random_reserves(team, num))
+            }
             RequestedRoll::Scatter => {
                 RollResult::Scatter(self.get_d8_roll(), self.get_d8_roll(), self.get_d8_roll())
             }
@@ -1069,10 +1244,11 @@ impl GameState {
             .filter(|player| player.stats.team == team && player.place == DugoutPlace::Reserves)
             .count();
         let num_available_players = num_players_on_bench + num_players_on_pitch;
-        let min_people_on_pitch = 11.min(num_available_players);
+        let min_people_on_pitch = MAX_PLAYERS_ON_PITCH.min(num_available_players);
         let min_people_on_scrimage = 3.min(num_available_players);
 
-        if num_players_on_pitch < min_people_on_pitch || num_players_on_pitch > 11 {
+        if num_players_on_pitch < min_people_on_pitch || num_players_on_pitch > MAX_PLAYERS_ON_PITCH
+        {
             return false;
         }
         let line_of_scrimage_x = self.get_line_of_scrimage_x(team);
@@ -1146,14 +1322,21 @@ impl GameState {
             .filter_map(|pos| {
                 self.get_player_at(pos)
                     .filter(|p| p.stats.team == team && p.can_catch())
-                    .and_then(|p| self.get_catch_target(p.id).ok())
+                    .and_then(|p| {
+                        let mut target = self.get_catch_target(p.id).ok()?;
+                        if p.has_skill(Skill::VeryLongLegs) {
+                            target.add_modifer(1);
+                        }
+                        Some(target)
+                    })
                     .map(|target| (pos, target))
             })
             .collect::<Vec<(Position, D6Target)>>()
     }
 
-    pub fn get_pass_modifier(&self, id: usize, from: Position, to: Position) -> Option<i8> {
-        // TODO: move this whole function to pathing and cache it there.
+    /// Distance modifier of a pass between the squares: 0 quick pass, 1 short pass, 2 long pass
+    /// and 3 long bomb. None when out of range, which only a Hail Mary Pass can reach.
+    fn pass_distance(from: Position, to: Position) -> Option<i8> {
         const MATRIX: [[i8; 14]; 14] = [
             [8, 0, 0, 0, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3],
             [0, 0, 0, 0, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3],
@@ -1171,11 +1354,7 @@ impl GameState {
             [3, 3, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9],
         ];
         // 8 - passing to oneself, not possible
-        // 9 - hail mary pass - skill not implemented
-        // 0 - quick pass
-        // 1 - short pass
-        // 2 - long pass
-        // 3 - long bomb
+        // 9 - hail mary pass only, see get_hail_mary_target
 
         let delta = to - from;
         let (dx, dy) = (
@@ -1191,19 +1370,119 @@ impl GameState {
         } else if *distance_modifier == 9 {
             return None;
         }
+        Some(*distance_modifier)
+    }
 
-        let Some(team) = self.get_player(id).ok().map(|p| p.stats.team) else {
+    pub fn get_pass_modifier(&self, id: usize, from: Position, to: Position) -> Option<i8> {
+        // TODO: move this whole function to pathing and cache it there.
+        let distance_modifier = &GameState::pass_distance(from, to)?;
+
+        let Some(player) = self.get_player(id).ok() else {
             //return None;
             panic!("Player not found");
         };
-        let tackle_zones = self
-            .get_adj_players(from)
-            .filter(|adj_p| adj_p.stats.team != team && adj_p.has_tackle_zone())
-            .count() as i8;
-        // TODO: weather effect
-        let sum_modifiers = -tackle_zones - *distance_modifier;
+        let weather_modifier = match self.info.weather {
+            Weather::Sunny => 1,
+            // only quick and short passes in a blizzard
+            Weather::Blizzard if *distance_modifier >= 2 => return None,
+            _ => 0,
+        };
+        let skill_modifier = match *distance_modifier {
+            0 | 1 if player.has_skill(Skill::Accurate) => 1,
+            2 | 3 if player.has_skill(Skill::StrongArm) => 1,
+            _ => 0,
+        };
+        let sum_modifiers = -self.get_pass_tackle_zones(id, from) - *distance_modifier
+            + skill_modifier
+            - weather_modifier;
         Some(sum_modifiers)
     }
+
+    fn get_pass_tackle_zones(&self, id: PlayerID, from: Position) -> i8 {
+        let player = self.get_player_unsafe(id);
+        if player.has_skill(Skill::NervesOfSteel) {
+            return 0;
+        }
+        self.get_adj_players(from)
+            .filter(|adj_p| adj_p.stats.team != player.stats.team && adj_p.has_tackle_zone())
+            .count() as i8
+    }
+
+    /// Target to not fumble a Hail Mary Pass, which can go anywhere but is never accurate
+    pub fn get_hail_mary_target(&self, id: PlayerID, from: Position) -> Option<D6Target> {
+        let player = self.get_player_unsafe(id);
+        if !player.has_skill(Skill::HailMaryPass) || self.info.weather == Weather::Blizzard {
+            return None;
+        }
+        let mut target = D6Target::TwoPlus;
+        target.add_modifer(-self.get_pass_tackle_zones(id, from));
+        Some(target)
+    }
+
+    /// Squares a player may be thrown to from `from`, a team-mate is only thrown a quick or
+    /// short pass
+    pub fn get_throw_team_mate_squares(&self, from: Position) -> Vec<Position> {
+        Position::all_positions()
+            .filter(|to| !to.is_out() && *to != from)
+            .filter(|to| GameState::pass_distance(from, *to).is_some_and(|d| d <= 1))
+            .collect()
+    }
+
+    /// Standing team-mates with Right Stuff next to the thrower
+    pub fn get_throwable_team_mates(&self, thrower_id: PlayerID) -> Vec<Position> {
+        let thrower = self.get_player_unsafe(thrower_id);
+        if thrower.status != PlayerStatus::Up || !thrower.has_skill(Skill::ThrowTeamMate) {
+            return Vec::new();
+        }
+        self.get_adj_players(thrower.position)
+            .filter(|p| {
+                p.stats.team == thrower.stats.team
+                    && p.status == PlayerStatus::Up
+                    && p.has_skill(Skill::RightStuff)
+            })
+            .map(|p| p.position)
+            .collect()
+    }
+
+    /// Squares the opposing Pass Block players may move to before a pass from `from` to `to`.
+    /// They move up to three empty squares and must end up next to the thrower or the target
+    /// square, or somewhere they can interfere with the pass.
+    pub fn get_pass_block_moves(
+        &self,
+        team: TeamType,
+        from: Position,
+        to: Position,
+    ) -> Vec<(PlayerID, Vec<Position>)> {
+        let lane: Vec<Position> = GameState::get_interception_positions(from, to).collect();
+        self.get_players_on_pitch_in_team(team)
+            .filter(|p| p.has_tackle_zone() && p.has_skill(Skill::PassBlock))
+            .map(|p| {
+                let mut reached = vec![p.position];
+                let mut frontier = vec![p.position];
+                for _ in 0..3 {
+                    frontier = frontier
+                        .iter()
+                        .flat_map(|pos| self.get_adj_positions(*pos))
+                        .filter(|pos| !pos.is_out() && self.get_player_at(*pos).is_none())
+                        .unique()
+                        .filter(|pos| !reached.contains(pos))
+                        .collect();
+                    reached.extend(frontier.iter().copied());
+                }
+                let moves = reached
+                    .into_iter()
+                    .skip(1)
+                    .filter(|pos| {
+                        lane.contains(pos)
+                            || pos.distance_to(&from) == 1
+                            || pos.distance_to(&to) == 1
+                    })
+                    .collect();
+                (p.id, moves)
+            })
+            .filter(|(_, moves): &(PlayerID, Vec<Position>)| !moves.is_empty())
+            .collect()
+    }
     pub fn get_pass_target(&self, id: usize, from: Position, to: Position) -> Option<D6Target> {
         let Some(player) = self.get_player(id).ok() else {
             //return None;
@@ -1214,6 +1493,15 @@ impl GameState {
         target.add_modifer(modifiers);
         Some(target)
     }
+
+    /// Team-mates that can be reached with a quick pass from the player at `from`
+    pub fn get_dump_off_positions(&self, id: PlayerID, from: Position) -> Vec<Position> {
+        let team = self.get_player_unsafe(id).stats.team;
+        self.get_players_on_pitch_in_team(team)
+            .filter(|p| p.id != id && GameState::pass_distance(from, p.position) == Some(0))
+            .map(|p| p.position)
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -1223,12 +1511,14 @@ mod gamestate_tests {
 
     use crate::{
         core::{
-            dices::D6,
+            dices::{D6Target, Sum2D6Target, D6},
             gamestate::{BuilderState, GameState},
             model::{
-                BallState, DugoutPlace, PlayerStats, Position, Result, TeamType, HEIGHT_, WIDTH,
-                WIDTH_,
+                Action, AvailableActions, BallState, DugoutPlace, PlayerStats, Position, Result,
+                TeamType, HEIGHT_, WIDTH, WIDTH_,
             },
+            procedures::{CoinToss, PrayerBlessings, PrayersToNuffle},
+            table::{EffectDuration, NumBlockDices, PosAT, PrayerToNuffle, SimpleAT, Skill},
         },
         standard_state,
     };
@@ -1517,6 +1807,61 @@ mod gamestate_tests {
         Ok(())
     }
     #[test]
+    fn extra_arms_very_long_legs_and_horns() {
+        let mut state = GameStateBuilder::new()
+            .add_home_players(&[(2, 5), (8, 5)])
+            .add_away_players(&[(5, 5), (9, 5)])
+            .build();
+        let catcher_id = state.get_player_id_at_coord(8, 5).unwrap();
+        let intercepter_id = state.get_player_id_at_coord(5, 5).unwrap();
+        let from = Position::new((2, 5));
+        let to = Position::new((8, 5));
+        assert_eq!(
+            state.get_catch_target(catcher_id).unwrap(),
+            D6Target::FivePlus
+        );
+        assert_eq!(
+            state.get_intercepters(TeamType::Away, from, to),
+            vec![(Position::new((5, 5)), D6Target::FourPlus)]
+        );
+
+        state
+            .get_mut_player_unsafe(catcher_id)
+            .stats
+            .give_skill(Skill::ExtraArms);
+        state
+            .get_mut_player_unsafe(intercepter_id)
+            .stats
+            .give_skill(Skill::VeryLongLegs);
+        assert_eq!(
+            state.get_catch_target(catcher_id).unwrap(),
+            D6Target::FourPlus
+        );
+        assert_eq!(
+            state.get_intercepters(TeamType::Away, from, to),
+            vec![(Position::new((5, 5)), D6Target::ThreePlus)]
+        );
+
+        let blocker_pos = Position::new((8, 5));
+        let defender_id = state.get_player_id_at_coord(9, 5).unwrap();
+        assert_eq!(
+            state.get_blitz_blockdices_from(catcher_id, blocker_pos, defender_id),
+            NumBlockDices::One
+        );
+        state
+            .get_mut_player_unsafe(catcher_id)
+            .stats
+            .give_skill(Skill::Horns);
+        assert_eq!(
+            state.get_blitz_blockdices_from(catcher_id, blocker_pos, defender_id),
+            NumBlockDices::Two
+        );
+        assert_eq!(
+            state.get_blockdices(catcher_id, defender_id),
+            NumBlockDices::One
+        );
+    }
+    #[test]
     fn rng_seed_in_gamestate() -> Result<()> {
         let mut state = standard_state();
         state.rng_enabled = true;
@@ -1551,4 +1896,55 @@ mod gamestate_tests {
         assert_eq!(state, deserialized);
         std::fs::remove_file("serialized_test.json").unwrap();
     }
+
+    #[test]
+    fn underdog_prays_to_nuffle() {
+        let mut state = GameStateBuilder::empty_state();
+        let home_id = state.dugout_add_new_player(
+            PlayerStats::new_lineman(TeamType::Home),
+            DugoutPlace::Reserves,
+        );
+        let away_id = state.dugout_add_new_player(
+            PlayerStats::new_lineman(TeamType::Away),
+            DugoutPlace::Reserves,
+        );
+        // two prayers for the 110k difference
+        state.away.team_value = 1110;
+        state.proc_stack = vec![CoinToss::new(), PrayersToNuffle::new_pregame()];
+        state.fixes.fix_d16(4); // the player is chosen once set up
+        state.fixes.fix_d16(4); // already in effect, rolled again
+        state.fixes.fix_d16(7);
+        state.fixes.fix_random_player(away_id);
+        state.step_simple(SimpleAT::EndTurn);
+
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::Heads)));
+        assert_eq!(
+            state.info.prayers,
+            vec![
+                (TeamType::Home, PrayerToNuffle::IronMan),
+                (TeamType::Home, PrayerToNuffle::GreasyCleats)
+            ]
+        );
+        let away_stats = &state.get_dugout_player(away_id).unwrap().stats;
+        assert_eq!(away_stats.movement_allowance(), 5);
+        assert_eq!(away_stats.ma, 6);
+
+        state.end_prayers(EffectDuration::Drive);
+        assert_eq!(
+            state.info.prayers,
+            vec![(TeamType::Home, PrayerToNuffle::IronMan)]
+        );
+        let away_stats = &state.get_dugout_player(away_id).unwrap().stats;
+        assert_eq!(away_stats.movement_allowance(), 6);
+
+        state.field_dugout_player(home_id, Position::new((5, 5)));
+        state.proc_stack = vec![CoinToss::new(), PrayerBlessings::new()];
+        state.available_actions = AvailableActions::new_empty();
+        state.step_simple(SimpleAT::EndTurn);
+        assert!(state.home_to_act());
+        state.step_positional(PosAT::SelectPosition, Position::new((5, 5)));
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::Heads)));
+        let player = state.get_player_at(Position::new((5, 5))).unwrap();
+        assert_eq!(player.armor_target(), Sum2D6Target::TenPlus);
+    }
 }
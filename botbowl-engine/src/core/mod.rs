@@ -1,5 +1,6 @@
 pub mod bb_errors;
 pub mod dices;
+pub mod formations;
 pub mod game_runner;
 pub mod gamestate;
 pub mod model;
@@ -11,7 +11,10 @@ use super::dices::{D6Target, RequestedRoll, RollResult, Sum2D6Target};
 use super::gamestate::GameState;
 use super::pathing::Node;
 use super::procedures::AnyProc;
-use super::table::{NumBlockDices, PlayerRole, PosAT, SimpleAT, Skill};
+use super::table::{
+    CasualtyOutcome, EffectDuration, NumBlockDices, PlayerRole, PosAT, PrayerToNuffle, SimpleAT,
+    Skill,
+};
 use crate::core::table;
 
 pub type PlayerID = usize;
@@ -88,6 +91,7 @@ pub const LINE_OF_SCRIMMAGE_AWAY_X: Coord = 13;
 pub const LINE_OF_SCRIMMAGE_Y_RANGE: std::ops::RangeInclusive<Coord> = 5..=11;
 pub const NORTH_WING_Y_RANGE: std::ops::RangeInclusive<Coord> = 1..=4;
 pub const SOUTH_WING_Y_RANGE: std::ops::RangeInclusive<Coord> = 12..=15;
+pub const MAX_PLAYERS_ON_PITCH: usize = 11;
 
 // Change the alias to `Box<error::Error>`.
 pub type Result<T> = std::result::Result<T, Box<dyn error::Error>>;
@@ -326,6 +330,8 @@ pub struct PlayerStats {
     pub team: TeamType,
     skills: HashSet<Skill>,
     pub role: PlayerRole,
+    /// The prayers to Nuffle the player is affected by
+    prayers: Vec<PrayerToNuffle>,
     //skills: [Option<table::Skill>; 3],
     //injuries
     //spp
@@ -341,6 +347,7 @@ impl PlayerStats {
             skills: HashSet::new(),
             role: PlayerRole::Lineman,
             pass: D6Target::FourPlus,
+            prayers: Vec::new(),
         }
     }
     pub fn new_blitzer(team: TeamType) -> PlayerStats {
@@ -353,6 +360,7 @@ impl PlayerStats {
             skills: HashSet::from_iter([Skill::Block]),
             role: PlayerRole::Blitzer,
             pass: D6Target::FourPlus,
+            prayers: Vec::new(),
         }
     }
     pub fn new_catcher(team: TeamType) -> PlayerStats {
@@ -365,6 +373,7 @@ impl PlayerStats {
             skills: HashSet::from_iter([Skill::Dodge, Skill::Catch]),
             role: PlayerRole::Catcher,
             pass: D6Target::FivePlus,
+            prayers: Vec::new(),
         }
     }
     pub fn new_thrower(team: TeamType) -> PlayerStats {
@@ -377,11 +386,40 @@ impl PlayerStats {
             skills: HashSet::from_iter([Skill::SureHands, Skill::Throw]),
             role: PlayerRole::Thrower,
             pass: D6Target::TwoPlus,
+            prayers: Vec::new(),
         }
     }
     pub fn give_skill(&mut self, skill: Skill) {
         self.skills.insert(skill);
     }
+    pub fn has_skill(&self, skill: Skill) -> bool {
+        self.skills.contains(&skill) || self.prayers.iter().any(|p| p.skill() == Some(skill))
+    }
+    pub fn add_prayer(&mut self, prayer: PrayerToNuffle) {
+        self.prayers.push(prayer);
+    }
+    pub fn has_prayer(&self, prayer: PrayerToNuffle) -> bool {
+        self.prayers.contains(&prayer)
+    }
+    /// Removes the prayers that last no longer than `duration`
+    pub fn end_prayers(&mut self, duration: EffectDuration) {
+        self.prayers.retain(|prayer| prayer.duration() > duration);
+    }
+    /// MA with Greasy Cleats
+    pub fn movement_allowance(&self) -> u8 {
+        let greasy_cleats = self.has_prayer(PrayerToNuffle::GreasyCleats);
+        self.ma.saturating_sub(u8::from(greasy_cleats))
+    }
+    /// The roll a player with Loner needs to use a team re-roll, Loner (2+) from Bad Habits
+    pub fn loner_target(&self) -> Option<D6Target> {
+        if self.skills.contains(&Skill::Loner) {
+            Some(D6Target::FourPlus)
+        } else if self.has_prayer(PrayerToNuffle::BadHabits) {
+            Some(D6Target::TwoPlus)
+        } else {
+            None
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -398,6 +436,7 @@ pub struct DugoutPlayer {
     pub stats: PlayerStats,
     pub place: DugoutPlace,
     pub id: DugoutPlayerID,
+    pub casualty: Option<CasualtyOutcome>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
@@ -409,10 +448,27 @@ pub struct FieldedPlayer {
     pub used: bool,
     pub moves: u8,
     pub used_skills: HashSet<Skill>,
+    /// Set by a failed Bone Head or Really Stupid roll, until the player is activated again
+    pub lost_tackle_zones: bool,
 }
 impl FieldedPlayer {
     pub fn armor_target(&self) -> Sum2D6Target {
-        Sum2D6Target::try_from(self.stats.av + 1).unwrap()
+        let av = self.stats.av;
+        let iron_man = self.stats.has_prayer(PrayerToNuffle::IronMan) && av < 10;
+        Sum2D6Target::try_from(av + 1 + u8::from(iron_man)).unwrap()
+    }
+
+    /// Outcome on the injury table of a modified injury roll. A Stunty player is Badly Hurt on
+    /// exactly 9, without rolling on the casualty table.
+    pub fn injury_outcome(&self, roll: i8) -> InjuryOutcome {
+        let stunty = self.has_skill(Skill::Stunty);
+        let ko = if stunty { 7 } else { 8 } + i8::from(self.has_skill(Skill::ThickSkull));
+        match roll {
+            9 if stunty => InjuryOutcome::BadlyHurt,
+            10.. => InjuryOutcome::Casualty,
+            _ if roll >= ko => InjuryOutcome::KO,
+            _ => InjuryOutcome::Stunned,
+        }
     }
 
     pub fn ag_target(&self) -> D6Target {
@@ -424,6 +480,9 @@ impl FieldedPlayer {
     }
 
     pub fn can_catch(&self) -> bool {
+        if self.lost_tackle_zones {
+            return false;
+        }
         match self.status {
             PlayerStatus::Up => true,
             PlayerStatus::Down => false,
@@ -431,6 +490,9 @@ impl FieldedPlayer {
         }
     }
     pub fn has_tackle_zone(&self) -> bool {
+        if self.lost_tackle_zones {
+            return false;
+        }
         match self.status {
             PlayerStatus::Up => true,
             PlayerStatus::Down => false,
@@ -440,26 +502,38 @@ impl FieldedPlayer {
     /// Returns how many normal moves the player has left. Before activating the player this is
     /// equal to MA (movement allowence)
     pub fn moves_left(&self) -> u8 {
-        if self.moves <= self.stats.ma {
-            self.stats.ma - self.moves
+        self.stats.movement_allowance().saturating_sub(self.moves)
+    }
+    /// Returns how many gfis the player may do in a turn, 2 or 3 with Sprint
+    pub fn max_gfis(&self) -> u8 {
+        if self.has_skill(Skill::Sprint) {
+            3
         } else {
-            0
+            2
         }
     }
     /// Returns how many gfis the player has left. Before exhausting the normal moves,
-    /// it's equal to 2
+    /// it's equal to `max_gfis`
     pub fn gfis_left(&self) -> u8 {
-        if self.moves <= self.stats.ma {
-            2
+        if self.moves <= self.stats.movement_allowance() {
+            self.max_gfis()
         } else {
-            2 + self.stats.ma - self.moves
+            self.max_gfis() + self.stats.movement_allowance() - self.moves
         }
     }
     /// Ruturns the total number of mover the player has left, normal moves + gfis. Before
-    /// activating the player, it's equal to MA + 2
+    /// activating the player, it's equal to MA + `max_gfis`
     pub fn total_movement_left(&self) -> u8 {
-        debug_assert!(self.moves <= self.stats.ma + 2);
-        self.stats.ma + 2 - self.moves
+        debug_assert!(self.moves <= self.stats.movement_allowance() + self.max_gfis());
+        self.stats.movement_allowance() + self.max_gfis() - self.moves
+    }
+    /// Returns how many moves standing up costs, nothing with Jump Up
+    pub fn standup_cost(&self) -> u8 {
+        if self.has_skill(Skill::JumpUp) {
+            0
+        } else {
+            3
+        }
     }
     pub fn add_move(&mut self, num_moves: u8) {
         assert!(self.total_movement_left() >= num_moves);
@@ -469,7 +543,7 @@ impl FieldedPlayer {
         self.has_skill(skill) && !self.used_skills.contains(&skill)
     }
     pub fn has_skill(&self, skill: Skill) -> bool {
-        self.stats.skills.contains(&skill)
+        self.stats.has_skill(skill)
     }
     pub fn use_skill(&mut self, skill: Skill) {
         let not_present_before = self.used_skills.insert(skill);
@@ -485,30 +559,58 @@ impl FieldedPlayer {
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub struct TeamState {
     pub bribes: u8,
+    pub coach_ejected: bool,
     //babes: u8,
-    //apothecaries: u8,
+    pub apothecaries: u8,
     //wizard_available: bool,
     //masterchef: bool,
     pub score: u8,
     //turn: u8,
-    //rerolls_start: u8,
+    pub rerolls_start: u8,
     pub rerolls: u8,
-    //ass_coaches: u8,
-    //cheerleaders: u8,
-    //fame: u8,
+    pub ass_coaches: u8,
+    pub cheerleaders: u8,
+    pub fan_factor: u8,
+    pub dedicated_fans: u8,
+    /// In thousands of gold pieces
+    pub team_value: u32,
+    /// The extra team re-roll of the half from having a player with Leader
+    pub leader_reroll: bool,
     reroll_used: bool,
+    drive_rerolls: u8,
     //time_violation: u8,
 }
 impl TeamState {
     #[allow(clippy::new_without_default)]
     pub fn new() -> TeamState {
         TeamState {
+            rerolls_start: 3,
             rerolls: 3,
+            leader_reroll: false,
             reroll_used: false,
+            drive_rerolls: 0,
             score: 0,
             bribes: 0,
+            coach_ejected: false,
+            apothecaries: 0,
+            ass_coaches: 0,
+            cheerleaders: 0,
+            fan_factor: 0,
+            dedicated_fans: 1,
+            team_value: 1000,
         }
-        //TeamState { bribes: 0, score: 0, turn: 0, rerolls_start: 3, rerolls: 3, fame: 3, reroll_used: false }
+    }
+    /// Only one team re-roll may be used per turn
+    pub fn start_turn(&mut self) {
+        self.reroll_used = false;
+    }
+    /// Restores the re-rolls the team started the game with, plus the Leader re-roll if the team
+    /// has a player with Leader
+    pub fn start_half(&mut self, leader_reroll: bool) {
+        self.rerolls = self.rerolls_start;
+        self.leader_reroll = leader_reroll;
+        self.drive_rerolls = 0;
+        self.reroll_used = false;
     }
     pub fn can_use_reroll(&self) -> bool {
         !self.reroll_used && self.rerolls > 0
@@ -517,6 +619,26 @@ impl TeamState {
         assert!(self.can_use_reroll());
         self.reroll_used = true;
         self.rerolls -= 1;
+        self.drive_rerolls = self.drive_rerolls.saturating_sub(1);
+    }
+    /// The Leader re-roll counts as the team re-roll of the turn
+    pub fn can_use_leader_reroll(&self) -> bool {
+        !self.reroll_used && self.leader_reroll
+    }
+    pub fn use_leader_reroll(&mut self) {
+        assert!(self.can_use_leader_reroll());
+        self.reroll_used = true;
+        self.leader_reroll = false;
+    }
+    /// Gives an extra team re-roll that is lost if it's not used before the end of the drive
+    pub fn add_drive_reroll(&mut self) {
+        self.rerolls += 1;
+        self.drive_rerolls += 1;
+    }
+    /// Removes the unused re-rolls that only lasted for the drive
+    pub fn end_drive(&mut self) {
+        self.rerolls -= self.drive_rerolls;
+        self.drive_rerolls = 0;
     }
 }
 
@@ -550,10 +672,26 @@ pub enum Weather {
     Sweltering,
 }
 
+/// Something the coach of the other team may react to during the active team's turn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Reaction {
+    DivingTackle,
+    DumpOff,
+    Fend,
+    PassBlock,
+    Shadowing,
+    SideStep,
+    StandFirm,
+    Tentacles,
+    Wrestle,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ProcInput {
     Nothing,
     Action(Action),
+    /// The answer to a reaction window, `Decline` if the reaction is passed up
+    Reaction(Reaction, Action),
     Roll(RollResult),
 }
 #[derive(Debug, Serialize, Deserialize)]
@@ -581,6 +719,7 @@ pub struct AvailableActions {
     simple: HashSet<SimpleAT>,
     positional: Option<FullPitch<SmallVecPosAT>>,
     paths: Option<FullPitch<Option<Rc<Node>>>>,
+    reaction: Option<Reaction>,
 }
 
 impl std::fmt::Debug for AvailableActions {
@@ -589,6 +728,9 @@ impl std::fmt::Debug for AvailableActions {
         if let Some(team) = self.team {
             info.field("team", &team);
         }
+        if let Some(reaction) = self.reaction {
+            info.field("reaction", &reaction);
+        }
         if !self.simple.is_empty() {
             info.field("simple", &self.simple);
         }
@@ -635,6 +777,27 @@ impl AvailableActions {
         aa.team = Some(team);
         aa
     }
+    /// A reaction window for the team in the other team's turn. The team may always decline, and
+    /// the waiting procedure resumes with the answer as `ProcInput::Reaction`.
+    pub fn new_reaction(team: TeamType, reaction: Reaction) -> Box<Self> {
+        let mut aa = AvailableActions::new(team);
+        aa.reaction = Some(reaction);
+        aa.insert_simple(SimpleAT::Decline);
+        aa
+    }
+    pub fn is_reaction(&self) -> bool {
+        self.reaction.is_some()
+    }
+    pub fn get_reaction(&self) -> Option<Reaction> {
+        self.reaction
+    }
+    /// The input of the waiting procedure for a chosen action
+    pub fn input_for(&self, action: Action) -> ProcInput {
+        match self.reaction {
+            Some(reaction) => ProcInput::Reaction(reaction, action),
+            None => ProcInput::Action(action),
+        }
+    }
     pub fn is_empty(&self) -> bool {
         self.simple.is_empty() && self.paths.is_none() && self.positional.is_none()
     }
@@ -739,5 +902,7 @@ pub struct BlockActionChoice {
 pub enum InjuryOutcome {
     Stunned,
     KO,
+    /// A casualty that is Badly Hurt without a roll on the casualty table
+    BadlyHurt,
     Casualty,
 }
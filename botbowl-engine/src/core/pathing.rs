@@ -1,13 +1,15 @@
+use std::cmp::max;
 use std::fmt::Debug;
 use std::{collections::HashMap, hash, iter::zip, rc::Rc};
 
 use crate::core::model;
+use itertools::Itertools;
 use model::*;
 use serde::{Deserialize, Serialize};
 
 use super::dices::{D6Target, RollTarget, Sum2D6Target};
 use super::gamestate::GameState;
-use super::table::{NumBlockDices, PosAT};
+use super::table::{NumBlockDices, PosAT, Skill};
 
 type OptRcNode = Option<Rc<Node>>;
 
@@ -15,6 +17,7 @@ type OptRcNode = Option<Rc<Node>>;
 pub enum PathingEvent {
     Dodge(D6Target),
     GFI(D6Target),
+    Jump(D6Target),
     Pickup(D6Target),
     Block(PlayerID, NumBlockDices),
     Handoff(PlayerID, D6Target),
@@ -22,12 +25,25 @@ pub enum PathingEvent {
         to: Position,
         pass: D6Target,
         modifer: i8,
+        hail_mary: bool,
     },
     Touchdown(PlayerID),
     Foul(PlayerID, Sum2D6Target),
     StandUp,
 }
 
+/// Probability that three scatters end up in the square they started from
+fn scatter_back_prob() -> f32 {
+    let directions: Vec<Direction> = Direction::all_directions_iter().copied().collect();
+    let num_back = directions
+        .iter()
+        .cartesian_product(directions.iter())
+        .cartesian_product(directions.iter())
+        .filter(|((a, b), c)| a.dx + b.dx + c.dx == 0 && a.dy + b.dy + c.dy == 0)
+        .count();
+    num_back as f32 / 512.0
+}
+
 pub fn event_ends_player_action(event: &PathingEvent) -> bool {
     match event {
         PathingEvent::Handoff(_, _) => true,
@@ -35,6 +51,7 @@ pub fn event_ends_player_action(event: &PathingEvent) -> bool {
         PathingEvent::Touchdown(_) => true,
         PathingEvent::Dodge(_) => false,
         PathingEvent::GFI(_) => false,
+        PathingEvent::Jump(_) => false,
         PathingEvent::Pickup(_) => false,
         PathingEvent::Block(_, _) => false,
         PathingEvent::StandUp => false,
@@ -168,6 +185,7 @@ pub struct Node {
     moves_left: u8,
     gfis_left: u8,
     block_dice: Option<NumBlockDices>,
+    break_tackle: i8,
     // foul_roll, handoff_roll, block_dice
     //euclidiean_distance: f32,
     pub prob: f32,
@@ -215,6 +233,7 @@ impl Node {
                 PathingEvent::StandUp => false,
                 PathingEvent::Dodge(_) => true,
                 PathingEvent::GFI(_) => true,
+                PathingEvent::Jump(_) => true,
                 PathingEvent::Pickup(_) => true,
                 PathingEvent::Touchdown(_) => true,
                 PathingEvent::Pass { .. } => false,
@@ -231,6 +250,7 @@ impl Node {
             moves_left: 0,
             gfis_left: 0,
             block_dice: Some(block_dice),
+            break_tackle: 0,
             prob: 1.0,
             events: Default::default(),
         }
@@ -253,6 +273,7 @@ impl Node {
     fn new(parent: OptRcNode, position: Position, moves_left: u8, gfis_left: u8) -> Node {
         Node {
             prob: parent.as_ref().map(|node| node.prob).unwrap_or(1.0),
+            break_tackle: parent.as_ref().map(|node| node.break_tackle).unwrap_or(0),
             parent,
             position,
             moves_left,
@@ -272,6 +293,10 @@ impl Node {
         self.prob *= target.success_prob();
         self.events.push_back(PathingEvent::Dodge(target));
     }
+    fn apply_jump(&mut self, target: D6Target) {
+        self.prob *= target.success_prob();
+        self.events.push_back(PathingEvent::Jump(target));
+    }
     fn apply_pickup(&mut self, target: D6Target) {
         self.prob *= target.success_prob();
         self.events.push_back(PathingEvent::Pickup(target));
@@ -301,6 +326,19 @@ impl Node {
             to,
             pass: pass_target,
             modifer: pass_modifer,
+            hail_mary: false,
+        })
+    }
+    /// The ball scatters three times from the target square, it can't be intercepted
+    fn apply_hail_mary(&mut self, to: Position, catch_target: D6Target, pass_target: D6Target) {
+        self.prob *= catch_target.success_prob();
+        self.prob *= pass_target.success_prob();
+        self.prob *= scatter_back_prob();
+        self.events.push_back(PathingEvent::Pass {
+            to,
+            pass: pass_target,
+            modifer: 0,
+            hail_mary: true,
         })
     }
     fn apply_block(&mut self, vicitm_id: PlayerID, target: NumBlockDices) {
@@ -314,9 +352,9 @@ impl Node {
     fn apply_touchdown(&mut self, id: PlayerID) {
         self.events.push_back(PathingEvent::Touchdown(id));
     }
-    fn apply_standup(&mut self) {
+    fn apply_standup(&mut self, cost: u8) {
         self.events.push_back(PathingEvent::StandUp);
-        self.moves_left -= 3;
+        self.moves_left -= cost;
     }
 
     fn is_dominant_over(&self, othr: &Node) -> bool {
@@ -409,6 +447,11 @@ struct GameInfo<'a> {
     dodge_target: D6Target,
     gfi_target: D6Target,
     pickup_target: D6Target,
+    jump_target: D6Target,
+    can_leap: bool,
+    big_hand: bool,
+    break_tackle: i8,
+    prehensile_tails: FullPitch<i8>,
 
     id: PlayerID,
 }
@@ -417,15 +460,36 @@ impl<'a> GameInfo<'a> {
         self.tzones[position]
     }
 
+    /// Big Hand ignores the tackle zones on the ball
+    fn pickup_target_at(&self, position: Position) -> D6Target {
+        if self.big_hand {
+            self.pickup_target
+        } else {
+            *self
+                .pickup_target
+                .clone()
+                .add_modifer(-self.tzones[position])
+        }
+    }
+
     fn new(game_state: &'a GameState, player: &FieldedPlayer) -> GameInfo<'a> {
-        let dodge_target = *player.ag_target().add_modifer(1);
-        let mut gfi_target = D6Target::TwoPlus;
+        let mut dodge_target = *player.ag_target().add_modifer(1);
+        let gfi_target = game_state.get_rush_target();
         let mut pickup_target = *player.ag_target().add_modifer(1);
+        // unlike a dodge, a jump gets no +1, only the marking players
+        let mut jump_target = player.ag_target();
+        let big_hand = player.has_skill(Skill::BigHand);
 
-        if game_state.info.weather == Weather::Blizzard {
-            gfi_target.add_modifer(-1);
+        if player.has_skill(Skill::TwoHeads) {
+            dodge_target.add_modifer(1);
+        }
+        if player.has_skill(Skill::ExtraArms) {
+            pickup_target.add_modifer(1);
         }
-        if game_state.info.weather == Weather::Rain {
+        if player.has_skill(Skill::VeryLongLegs) {
+            jump_target.add_modifer(1);
+        }
+        if game_state.info.weather == Weather::Rain && !big_hand {
             pickup_target.add_modifer(-1);
         }
 
@@ -437,6 +501,18 @@ impl<'a> GameInfo<'a> {
             .filter(|player| player.has_tackle_zone())
             .flat_map(|player| game_state.get_adj_positions(player.position))
             .for_each(|position| tzones[position] += 1);
+        let mut prehensile_tails: FullPitch<i8> = Default::default();
+        game_state
+            .get_players_on_pitch()
+            .filter(|player| player.stats.team != team)
+            .filter(|player| player.has_tackle_zone() && player.has_skill(Skill::PrehensileTail))
+            .flat_map(|player| game_state.get_adj_positions(player.position))
+            .for_each(|position| prehensile_tails[position] += 1);
+        let break_tackle = match player.can_use_skill(Skill::BreakTackle) {
+            true if player.stats.str_ >= 5 => 2,
+            true => 1,
+            false => 0,
+        };
         let ball = match game_state.ball {
             BallState::OnGround(position) => PathingBallState::OnGround(position),
             BallState::Carried(id) if id == player.id => {
@@ -444,10 +520,10 @@ impl<'a> GameInfo<'a> {
             }
             _ => PathingBallState::NotRelevant,
         };
-        let mut player_action = game_state
-            .info
-            .player_action_type
-            .unwrap_or(PosAT::StartMove);
+        let mut player_action = match game_state.info.player_action_type {
+            Some(at) if !game_state.info.player_action_done => at,
+            _ => PosAT::StartMove,
+        };
         let mut catch_mods: FullPitch<Option<D6Target>> = Default::default();
         if player_action == PosAT::StartHandoff || player_action == PosAT::StartPass {
             if matches!(ball, PathingBallState::IsCarrier(_)) {
@@ -457,7 +533,11 @@ impl<'a> GameInfo<'a> {
                     .filter(|p| p.stats.team == team)
                     .filter(|p| p.can_catch())
                     .for_each(|p| {
-                        catch_mods[p.position] = Some(game_state.get_catch_target(p.id).unwrap())
+                        let mut target = game_state.get_catch_target(p.id).unwrap();
+                        if player_action == PosAT::StartPass && p.has_skill(Skill::DivingCatch) {
+                            target.add_modifer(1);
+                        }
+                        catch_mods[p.position] = Some(target)
                     });
                 catch_mods[player.position] = None; // can't handoff or pass to self
             } else {
@@ -473,6 +553,11 @@ impl<'a> GameInfo<'a> {
             dodge_target,
             gfi_target,
             pickup_target,
+            jump_target,
+            can_leap: player.has_skill(Skill::Leap),
+            big_hand,
+            break_tackle,
+            prehensile_tails,
             game_state,
             team: player.stats.team,
             player_action,
@@ -548,11 +633,63 @@ impl<'a> GameInfo<'a> {
             _ => return NodeType::NoNode,
         };
 
-        let new_node: Rc<Node> = match new_node {
-            Some(node) => Rc::new(node),
-            None => return NodeType::NoNode,
-        };
+        match new_node {
+            Some(node) => self.evaluate_new_node(Rc::new(node), parent_node, prev, best),
+            None => NodeType::NoNode,
+        }
+    }
+
+    fn expand_jump_to(
+        &self,
+        to: Position,
+        parent_node: &Rc<Node>,
+        prev: &mut OptRcNode,
+        best: &OptRcNode,
+    ) -> NodeType {
+        debug_assert!(self.can_continue_expanding(parent_node));
+        if parent_node.remaining_movement() < 2 {
+            return NodeType::NoNode;
+        }
+        if let Some(current_best) = &prev {
+            if parent_node.remaining_movement() - 2 <= current_best.remaining_movement() {
+                return NodeType::NoNode;
+            }
+        }
+
+        let num_gfis = 2_u8.saturating_sub(parent_node.moves_left);
+        let moves_left = parent_node.moves_left.saturating_sub(2);
+        let gfis_left = parent_node.gfis_left - num_gfis;
+        let mut next_node = Node::new(Some(parent_node.clone()), to, moves_left, gfis_left);
+
+        for _ in 0..num_gfis {
+            next_node.apply_gfi(self.gfi_target);
+        }
+        let mut tackle_zones = max(self.tzones[parent_node.position], self.tzones[to]);
+        if self.can_leap && tackle_zones > 1 {
+            tackle_zones -= 1;
+        }
+        let tails = self.prehensile_tails[parent_node.position];
+        next_node.apply_jump(*self.jump_target.clone().add_modifer(-tackle_zones - tails));
+        match self.ball {
+            PathingBallState::OnGround(ball_pos) if ball_pos == to => {
+                next_node.apply_pickup(self.pickup_target_at(to));
+            }
+            PathingBallState::IsCarrier(endzone_x) if to.x == endzone_x => {
+                next_node.apply_touchdown(self.id);
+            }
+            _ => (),
+        }
+
+        self.evaluate_new_node(Rc::new(next_node), parent_node, prev, best)
+    }
 
+    fn evaluate_new_node(
+        &self,
+        new_node: Rc<Node>,
+        parent_node: &Rc<Node>,
+        prev: &mut OptRcNode,
+        best: &OptRcNode,
+    ) -> NodeType {
         if let Some(best_before) = &best {
             debug_assert!(best_before.prob > new_node.prob); //this is only here to remind us of this fact
             if !best_before.is_dominant_over(&new_node) {
@@ -641,7 +778,7 @@ impl<'a> GameInfo<'a> {
         next_node.apply_block(
             victim_id,
             self.game_state
-                .get_blockdices_from(self.id, parent_node.position, victim_id),
+                .get_blitz_blockdices_from(self.id, parent_node.position, victim_id),
         );
         if let Some(current_best) = prev {
             if !next_node.is_better_than(current_best) {
@@ -696,31 +833,28 @@ impl<'a> GameInfo<'a> {
             );
         }
 
-        let Some(pass_target) = self
-            .game_state
-            .get_pass_target(id, parent_node.position, to)
-        else {
-            return None;
-        };
-
-        let catch_target = self.teammate_catch_mod[to].unwrap();
-        let best_intercept = self
+        match self
             .game_state
-            .get_intercepters(other_team(self.team), parent_node.position, to)
-            .iter()
-            .map(|(_, target)| *target)
-            .max_by(|target_a, target_b| {
-                target_a
-                    .success_prob()
-                    .partial_cmp(&target_b.success_prob())
-                    .unwrap_or(std::cmp::Ordering::Equal)
-            });
-
-        let modifier = self
-            .game_state
-            .get_pass_modifier(id, parent_node.position, to)
-            .unwrap();
-        next_node.apply_pass(to, catch_target, pass_target, best_intercept, modifier);
+            .get_pass_target(self.id, parent_node.position, to)
+        {
+            Some(pass_target) => {
+                let catch_target = self.teammate_catch_mod[to].unwrap();
+                let modifier = self
+                    .game_state
+                    .get_pass_modifier(self.id, parent_node.position, to)
+                    .unwrap();
+                let best_intercept = self.best_intercept(parent_node.position, to);
+                next_node.apply_pass(to, catch_target, pass_target, best_intercept, modifier);
+            }
+            None => {
+                let pass_target = self
+                    .game_state
+                    .get_hail_mary_target(self.id, parent_node.position)?;
+                let mut catch_target = self.game_state.get_catch_target(id).unwrap();
+                catch_target.add_modifer(-1);
+                next_node.apply_hail_mary(to, catch_target, pass_target);
+            }
+        }
         // the Catch procedure will check fo touchdown
 
         if let Some(current_best) = prev {
@@ -732,6 +866,27 @@ impl<'a> GameInfo<'a> {
         }
         Some(next_node)
     }
+    /// The most likely intercepter, counting pass blockers that can step into the lane
+    fn best_intercept(&self, from: Position, to: Position) -> Option<D6Target> {
+        let lane: Vec<Position> = GameState::get_interception_positions(from, to).collect();
+        let pass_blockers = self
+            .game_state
+            .get_pass_block_moves(other_team(self.team), from, to)
+            .into_iter()
+            .filter(|(_, moves)| moves.iter().any(|pos| lane.contains(pos)))
+            .filter_map(|(blocker_id, _)| self.game_state.get_catch_target(blocker_id).ok());
+        self.game_state
+            .get_intercepters(other_team(self.team), from, to)
+            .iter()
+            .map(|(_, target)| *target)
+            .chain(pass_blockers)
+            .max_by(|target_a, target_b| {
+                target_a
+                    .success_prob()
+                    .partial_cmp(&target_b.success_prob())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+    }
     fn expand_move_to(
         &self,
         to: Position,
@@ -757,12 +912,20 @@ impl<'a> GameInfo<'a> {
             next_node.apply_gfi(self.gfi_target);
         }
         if self.tackles_zones_at(parent_node.position) > 0 {
-            next_node.apply_dodge(*self.dodge_target.clone().add_modifer(-self.tzones[to]));
+            // break tackle is used on the first dodge of the activation
+            let mut target = self.dodge_target;
+            target.add_modifer(
+                next_node.break_tackle
+                    - self.tzones[to]
+                    - self.prehensile_tails[parent_node.position],
+            );
+            next_node.break_tackle = 0;
+            next_node.apply_dodge(target);
         }
         match self.ball {
             PathingBallState::OnGround(ball_pos) if ball_pos == to => {
                 // touchdown by pickup is handled by the pickup procedure
-                next_node.apply_pickup(*self.pickup_target.clone().add_modifer(-self.tzones[to]));
+                next_node.apply_pickup(self.pickup_target_at(to));
             }
             PathingBallState::IsCarrier(endzone_x) if to.x == endzone_x => {
                 next_node.apply_touchdown(self.id);
@@ -793,9 +956,10 @@ impl<'a> PathFinder<'a> {
             player.moves_left(),
             player.gfis_left(),
         );
+        root_node.break_tackle = info.break_tackle;
         if player.status != PlayerStatus::Up {
-            assert!(player.moves_left() == player.stats.ma);
-            root_node.apply_standup();
+            assert!(player.moves_left() == player.stats.movement_allowance());
+            root_node.apply_standup(player.standup_cost());
         }
 
         let root_node = Rc::new(root_node);
@@ -880,7 +1044,8 @@ impl<'a> PathFinder<'a> {
             .filter(|to_pos| {
                 parent_pos_and_in_tz
                     .map(|(parent_pos, parent_in_tz)| {
-                        parent_pos.distance_to(to_pos) == 2
+                        // more than 2 away if the node was reached by a jump
+                        parent_pos.distance_to(to_pos) >= 2
                             || (parent_in_tz && 0 < self.info.tzones[*to_pos])
                     })
                     .unwrap_or(true)
@@ -902,6 +1067,43 @@ impl<'a> PathFinder<'a> {
                 NodeType::NoNode => (),
             });
 
+        //handle jumping over prone players, or any square with leap
+        if node.remaining_movement() >= 2 {
+            Direction::all_directions_iter()
+                .map(|direction| node.position + *direction)
+                .filter(
+                    |over_pos| match self.info.game_state.get_player_at(*over_pos) {
+                        Some(player) => self.info.can_leap || player.status != PlayerStatus::Up,
+                        None => self.info.can_leap,
+                    },
+                )
+                .flat_map(|over_pos| {
+                    Direction::all_directions_iter().map(move |direction| over_pos + *direction)
+                })
+                .filter(|to_pos| node.position.distance_to(to_pos) == 2)
+                .filter(|to_pos| !to_pos.is_out())
+                .filter(|to_pos| self.info.game_state.get_player_id_at(*to_pos).is_none())
+                .unique()
+                .collect::<Vec<_>>()
+                .into_iter()
+                .map(|to_pos| {
+                    self.info.expand_jump_to(
+                        to_pos,
+                        &node,
+                        &mut self.nodes[to_pos],
+                        &self.locked_nodes[to_pos],
+                    )
+                })
+                .for_each(|node_type| match node_type {
+                    NodeType::Risky(node) => self.risky_sets.insert_node(node),
+                    NodeType::ContinueExpanding(node) => {
+                        debug_assert!(self.info.can_continue_expanding(&node));
+                        self.open_set.push(node);
+                    }
+                    NodeType::NoNode => (),
+                });
+        }
+
         //handle passing
         if self.info.player_action == PosAT::StartPass
             && matches!(self.info.ball, PathingBallState::IsCarrier(_))
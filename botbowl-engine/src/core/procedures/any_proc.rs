@@ -2,68 +2,108 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::model::Procedure;
 use crate::core::procedures::ball_procs::{
-    Bounce, Catch, Deflect, DeflectOrResolve, Pass, PickupProc, ThrowIn, Touchback, Touchdown,
+    Bounce, Catch, Deflect, DeflectOrResolve, DumpOff, Landing, Pass, PassBlock, PickupProc,
+    ThrowIn, ThrowTeamMate, Touchback, Touchdown,
 };
 
 use crate::core::procedures::block_procs::{Block, BlockAction, FollowUp, KnockDown, Push};
-use crate::core::procedures::casualty_procs::{Armor, Ejection, Injury};
+use crate::core::procedures::casualty_procs::{Armor, Casualty, Ejection, Injury, KnockOut};
 use crate::core::procedures::game_procs::{
-    ChooseKickReceive, CoinToss, GameOver, Half, KOWakeUp, Turn, TurnStunned,
-    TurnoverIfPossessionLost,
+    ActivationRoll, ChooseKickReceive, CoinToss, FanFactor, GameOver, Half, Inducements, KOWakeUp,
+    LashOut, PrayerBlessings, PrayersToNuffle, Pregame, SwelteringHeat, Turn, TurnStunned,
+    TurnoverIfPossessionLost, WeatherRoll,
 };
 use crate::core::procedures::kickoff_procs::{
-    ChangingWeather, Kickoff, KickoffTable, LandKickoff, Setup,
+    BrilliantCoaching, ChangingWeather, CheeringFans, HighKick, Kickoff, KickoffBlitz,
+    KickoffReturn, KickoffTable, LandKickoff, OfficiousRef, PitchInvasion, QuickSnap, Setup,
+    SolidDefence,
+};
+use crate::core::procedures::movement_procs::{
+    DodgeProc, GfiProc, JumpProc, JumpUpProc, MoveAction, Shadowing, StandUp, Tentacles,
 };
-use crate::core::procedures::movement_procs::{DodgeProc, GfiProc, MoveAction, StandUp};
 
 use crate::core::procedures::procedure_tools::SimpleProcContainer;
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub enum AnyProc {
+    ActivationRoll(SimpleProcContainer<ActivationRoll>),
     Armor(Armor),
     Block(Block),
     BlockAction(BlockAction),
     Bounce(Bounce),
+    BrilliantCoaching(BrilliantCoaching),
+    Casualty(Casualty),
     Catch(SimpleProcContainer<Catch>),
     ChangingWeather(ChangingWeather),
+    CheeringFans(CheeringFans),
     ChooseKickReceive(ChooseKickReceive),
     CoinToss(CoinToss),
     Deflect(SimpleProcContainer<Deflect>),
     DeflectOrResolve(DeflectOrResolve),
     DodgeProc(SimpleProcContainer<DodgeProc>),
+    DumpOff(DumpOff),
     Ejection(Ejection),
+    FanFactor(FanFactor),
     FollowUp(FollowUp),
     GameOver(GameOver),
     GfiProc(SimpleProcContainer<GfiProc>),
     Half(Half),
+    HighKick(HighKick),
+    Inducements(Inducements),
     Injury(Injury),
+    JumpProc(SimpleProcContainer<JumpProc>),
+    JumpUpProc(SimpleProcContainer<JumpUpProc>),
     KOWakeUp(KOWakeUp),
     Kickoff(Kickoff),
+    KnockOut(KnockOut),
+    KickoffBlitz(KickoffBlitz),
+    KickoffReturn(KickoffReturn),
     KickoffTable(KickoffTable),
     KnockDown(KnockDown),
     LandKickoff(LandKickoff),
+    Landing(SimpleProcContainer<Landing>),
+    LashOut(LashOut),
     MoveAction(MoveAction),
+    OfficiousRef(OfficiousRef),
     Pass(Pass),
+    PassBlock(PassBlock),
     PickupProc(SimpleProcContainer<PickupProc>),
+    PitchInvasion(PitchInvasion),
+    PrayerBlessings(PrayerBlessings),
+    PrayersToNuffle(PrayersToNuffle),
+    Pregame(Pregame),
     Push(Push),
+    QuickSnap(QuickSnap),
     Setup(Setup),
+    Shadowing(Shadowing),
+    SolidDefence(SolidDefence),
     StandUp(StandUp),
+    SwelteringHeat(SwelteringHeat),
+    Tentacles(Tentacles),
     ThrowIn(ThrowIn),
+    ThrowTeamMate(ThrowTeamMate),
     Touchback(Touchback),
     Touchdown(Touchdown),
     Turn(Turn),
     TurnStunned(TurnStunned),
     TurnoverIfPossessionLost(TurnoverIfPossessionLost),
+    WeatherRoll(WeatherRoll),
 }
 
 impl std::fmt::Debug for AnyProc {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::ActivationRoll(arg0) => f.debug_tuple("ActivationRoll").field(arg0).finish(),
             Self::Armor(arg0) => f.debug_tuple("Armor").field(arg0).finish(),
             Self::Block(arg0) => f.debug_tuple("Block").field(arg0).finish(),
             Self::BlockAction(arg0) => f.debug_tuple("BlockAction").field(arg0).finish(),
             Self::Bounce(arg0) => f.debug_tuple("Bounce").field(arg0).finish(),
+            Self::BrilliantCoaching(arg0) => {
+                f.debug_tuple("BrilliantCoaching").field(arg0).finish()
+            }
+            Self::Casualty(arg0) => f.debug_tuple("Casualty").field(arg0).finish(),
             Self::Catch(arg0) => f.debug_tuple("Catch").field(arg0).finish(),
             Self::ChangingWeather(arg0) => f.debug_tuple("ChangingWeather").field(arg0).finish(),
+            Self::CheeringFans(arg0) => f.debug_tuple("CheeringFans").field(arg0).finish(),
             Self::ChooseKickReceive(arg0) => {
                 f.debug_tuple("ChooseKickReceive").field(arg0).finish()
             }
@@ -71,24 +111,47 @@ impl std::fmt::Debug for AnyProc {
             Self::Deflect(arg0) => f.debug_tuple("Deflect").field(arg0).finish(),
             Self::DeflectOrResolve(arg0) => f.debug_tuple("DeflectOrResolve").field(arg0).finish(),
             Self::DodgeProc(arg0) => f.debug_tuple("DodgeProc").field(arg0).finish(),
+            Self::DumpOff(arg0) => f.debug_tuple("DumpOff").field(arg0).finish(),
             Self::Ejection(arg0) => f.debug_tuple("Ejection").field(arg0).finish(),
+            Self::FanFactor(arg0) => f.debug_tuple("FanFactor").field(arg0).finish(),
             Self::FollowUp(arg0) => f.debug_tuple("FollowUp").field(arg0).finish(),
             Self::GameOver(arg0) => f.debug_tuple("GameOver").field(arg0).finish(),
             Self::GfiProc(arg0) => f.debug_tuple("GfiProc").field(arg0).finish(),
             Self::Half(arg0) => f.debug_tuple("Half").field(arg0).finish(),
+            Self::HighKick(arg0) => f.debug_tuple("HighKick").field(arg0).finish(),
+            Self::Inducements(arg0) => f.debug_tuple("Inducements").field(arg0).finish(),
             Self::Injury(arg0) => f.debug_tuple("Injury").field(arg0).finish(),
+            Self::JumpProc(arg0) => f.debug_tuple("JumpProc").field(arg0).finish(),
+            Self::JumpUpProc(arg0) => f.debug_tuple("JumpUpProc").field(arg0).finish(),
             Self::KOWakeUp(arg0) => f.debug_tuple("KOWakeUp").field(arg0).finish(),
             Self::Kickoff(arg0) => f.debug_tuple("Kickoff").field(arg0).finish(),
+            Self::KnockOut(arg0) => f.debug_tuple("KnockOut").field(arg0).finish(),
+            Self::KickoffBlitz(arg0) => f.debug_tuple("KickoffBlitz").field(arg0).finish(),
+            Self::KickoffReturn(arg0) => f.debug_tuple("KickoffReturn").field(arg0).finish(),
             Self::KickoffTable(arg0) => f.debug_tuple("KickoffTable").field(arg0).finish(),
             Self::KnockDown(arg0) => f.debug_tuple("KnockDown").field(arg0).finish(),
             Self::LandKickoff(arg0) => f.debug_tuple("LandKickoff").field(arg0).finish(),
+            Self::Landing(arg0) => f.debug_tuple("Landing").field(arg0).finish(),
+            Self::LashOut(arg0) => f.debug_tuple("LashOut").field(arg0).finish(),
             Self::MoveAction(arg0) => f.debug_tuple("MoveAction").field(arg0).finish(),
+            Self::OfficiousRef(arg0) => f.debug_tuple("OfficiousRef").field(arg0).finish(),
             Self::Pass(arg0) => f.debug_tuple("Pass").field(arg0).finish(),
+            Self::PassBlock(arg0) => f.debug_tuple("PassBlock").field(arg0).finish(),
             Self::PickupProc(arg0) => f.debug_tuple("PickupProc").field(arg0).finish(),
+            Self::PitchInvasion(arg0) => f.debug_tuple("PitchInvasion").field(arg0).finish(),
+            Self::PrayerBlessings(arg0) => f.debug_tuple("PrayerBlessings").field(arg0).finish(),
+            Self::PrayersToNuffle(arg0) => f.debug_tuple("PrayersToNuffle").field(arg0).finish(),
+            Self::Pregame(arg0) => f.debug_tuple("Pregame").field(arg0).finish(),
             Self::Push(arg0) => f.debug_tuple("Push").field(arg0).finish(),
+            Self::QuickSnap(arg0) => f.debug_tuple("QuickSnap").field(arg0).finish(),
             Self::Setup(arg0) => f.debug_tuple("Setup").field(arg0).finish(),
+            Self::Shadowing(arg0) => f.debug_tuple("Shadowing").field(arg0).finish(),
+            Self::SolidDefence(arg0) => f.debug_tuple("SolidDefence").field(arg0).finish(),
             Self::StandUp(arg0) => f.debug_tuple("StandUp").field(arg0).finish(),
+            Self::SwelteringHeat(arg0) => f.debug_tuple("SwelteringHeat").field(arg0).finish(),
+            Self::Tentacles(arg0) => f.debug_tuple("Tentacles").field(arg0).finish(),
             Self::ThrowIn(arg0) => f.debug_tuple("ThrowIn").field(arg0).finish(),
+            Self::ThrowTeamMate(arg0) => f.debug_tuple("ThrowTeamMate").field(arg0).finish(),
             Self::Touchback(arg0) => f.debug_tuple("Touchback").field(arg0).finish(),
             Self::Touchdown(arg0) => f.debug_tuple("Touchdown").field(arg0).finish(),
             Self::Turn(arg0) => f.debug_tuple("Turn").field(arg0).finish(),
@@ -97,6 +160,7 @@ impl std::fmt::Debug for AnyProc {
                 .debug_tuple("TurnoverIfPossessionLost")
                 .field(arg0)
                 .finish(),
+            Self::WeatherRoll(arg0) => f.debug_tuple("WeatherRoll").field(arg0).finish(),
         }
     }
 }
@@ -108,40 +172,68 @@ impl Procedure for AnyProc {
         input: crate::core::model::ProcInput,
     ) -> crate::core::model::ProcState {
         match self {
+            AnyProc::ActivationRoll(arg) => arg.step(game_state, input),
             AnyProc::Armor(arg) => arg.step(game_state, input),
             AnyProc::Block(arg) => arg.step(game_state, input),
             AnyProc::BlockAction(arg) => arg.step(game_state, input),
             AnyProc::Bounce(arg) => arg.step(game_state, input),
+            AnyProc::BrilliantCoaching(arg) => arg.step(game_state, input),
+            AnyProc::Casualty(arg) => arg.step(game_state, input),
             AnyProc::Catch(arg) => arg.step(game_state, input),
             AnyProc::ChangingWeather(arg) => arg.step(game_state, input),
+            AnyProc::CheeringFans(arg) => arg.step(game_state, input),
             AnyProc::ChooseKickReceive(arg) => arg.step(game_state, input),
             AnyProc::CoinToss(arg) => arg.step(game_state, input),
             AnyProc::Deflect(arg) => arg.step(game_state, input),
             AnyProc::DeflectOrResolve(arg) => arg.step(game_state, input),
             AnyProc::DodgeProc(arg) => arg.step(game_state, input),
+            AnyProc::DumpOff(arg) => arg.step(game_state, input),
             AnyProc::Ejection(arg) => arg.step(game_state, input),
+            AnyProc::FanFactor(arg) => arg.step(game_state, input),
             AnyProc::FollowUp(arg) => arg.step(game_state, input),
             AnyProc::GameOver(arg) => arg.step(game_state, input),
             AnyProc::GfiProc(arg) => arg.step(game_state, input),
             AnyProc::Half(arg) => arg.step(game_state, input),
+            AnyProc::HighKick(arg) => arg.step(game_state, input),
+            AnyProc::Inducements(arg) => arg.step(game_state, input),
             AnyProc::Injury(arg) => arg.step(game_state, input),
+            AnyProc::JumpProc(arg) => arg.step(game_state, input),
+            AnyProc::JumpUpProc(arg) => arg.step(game_state, input),
             AnyProc::KOWakeUp(arg) => arg.step(game_state, input),
             AnyProc::Kickoff(arg) => arg.step(game_state, input),
+            AnyProc::KnockOut(arg) => arg.step(game_state, input),
+            AnyProc::KickoffBlitz(arg) => arg.step(game_state, input),
+            AnyProc::KickoffReturn(arg) => arg.step(game_state, input),
             AnyProc::KickoffTable(arg) => arg.step(game_state, input),
             AnyProc::KnockDown(arg) => arg.step(game_state, input),
             AnyProc::LandKickoff(arg) => arg.step(game_state, input),
+            AnyProc::Landing(arg) => arg.step(game_state, input),
+            AnyProc::LashOut(arg) => arg.step(game_state, input),
             AnyProc::MoveAction(arg) => arg.step(game_state, input),
+            AnyProc::OfficiousRef(arg) => arg.step(game_state, input),
             AnyProc::Pass(arg) => arg.step(game_state, input),
+            AnyProc::PassBlock(arg) => arg.step(game_state, input),
             AnyProc::PickupProc(arg) => arg.step(game_state, input),
+            AnyProc::PitchInvasion(arg) => arg.step(game_state, input),
+            AnyProc::PrayerBlessings(arg) => arg.step(game_state, input),
+            AnyProc::PrayersToNuffle(arg) => arg.step(game_state, input),
+            AnyProc::Pregame(arg) => arg.step(game_state, input),
             AnyProc::Push(arg) => arg.step(game_state, input),
+            AnyProc::QuickSnap(arg) => arg.step(game_state, input),
             AnyProc::Setup(arg) => arg.step(game_state, input),
+            AnyProc::Shadowing(arg) => arg.step(game_state, input),
+            AnyProc::SolidDefence(arg) => arg.step(game_state, input),
             AnyProc::StandUp(arg) => arg.step(game_state, input),
+            AnyProc::SwelteringHeat(arg) => arg.step(game_state, input),
+            AnyProc::Tentacles(arg) => arg.step(game_state, input),
             AnyProc::ThrowIn(arg) => arg.step(game_state, input),
+            AnyProc::ThrowTeamMate(arg) => arg.step(game_state, input),
             AnyProc::Touchback(arg) => arg.step(game_state, input),
             AnyProc::Touchdown(arg) => arg.step(game_state, input),
             AnyProc::Turn(arg) => arg.step(game_state, input),
             AnyProc::TurnStunned(arg) => arg.step(game_state, input),
             AnyProc::TurnoverIfPossessionLost(arg) => arg.step(game_state, input),
+            AnyProc::WeatherRoll(arg) => arg.step(game_state, input),
         }
     }
 }
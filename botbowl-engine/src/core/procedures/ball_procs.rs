@@ -2,13 +2,15 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::dices::{D6Target, RequestedRoll, RollResult, RollTarget, D3, D6};
 use crate::core::gamestate::GameState;
-use crate::core::model::ProcInput;
 use crate::core::model::{
     other_team, Action, AvailableActions, Coord, Direction, Position, ProcState, Procedure,
     HEIGHT_, WIDTH_,
 };
-use crate::core::model::{BallState, PlayerID};
-use crate::core::table::{PosAT, Skill};
+use crate::core::model::{BallState, DugoutPlace, PlayerID, PlayerStatus};
+use crate::core::model::{ProcInput, Reaction};
+use crate::core::procedures::block_procs::KnockDown;
+use crate::core::procedures::casualty_procs::Injury;
+use crate::core::table::{CasualtyOutcome, PosAT, SimpleAT, Skill};
 
 use crate::core::procedures::any_proc::AnyProc;
 
@@ -267,11 +269,110 @@ pub enum PassResult {
     Fumble,
 }
 
+/// Opposing players with Pass Block may move before the pass
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PassBlock {
+    from: Position,
+    to: Position,
+    moved: Vec<PlayerID>,
+    blocker: Option<PlayerID>,
+}
+impl PassBlock {
+    pub fn new(from: Position, to: Position) -> AnyProc {
+        AnyProc::PassBlock(PassBlock {
+            from,
+            to,
+            moved: Vec::new(),
+            blocker: None,
+        })
+    }
+    fn blockers(&self, game_state: &GameState) -> Vec<(PlayerID, Vec<Position>)> {
+        let team = other_team(game_state.get_active_player().unwrap().stats.team);
+        game_state
+            .get_pass_block_moves(team, self.from, self.to)
+            .into_iter()
+            .filter(|(id, _)| !self.moved.contains(id))
+            .collect()
+    }
+}
+impl Procedure for PassBlock {
+    fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
+        let team = other_team(game_state.get_active_player().unwrap().stats.team);
+        let mut aa = AvailableActions::new_reaction(team, Reaction::PassBlock);
+        match input {
+            ProcInput::Nothing => {
+                let blockers = self.blockers(game_state);
+                if blockers.is_empty() {
+                    return ProcState::Done;
+                }
+                aa.insert_positional(
+                    PosAT::SelectPosition,
+                    blockers
+                        .iter()
+                        .map(|(id, _)| game_state.get_player_unsafe(*id).position)
+                        .collect(),
+                );
+            }
+            ProcInput::Reaction(
+                Reaction::PassBlock,
+                Action::Positional(PosAT::SelectPosition, position),
+            ) => match self.blocker.take() {
+                Some(id) => {
+                    game_state.move_player(id, position).unwrap();
+                    self.moved.push(id);
+                    return ProcState::NotDone;
+                }
+                None => {
+                    let id = game_state.get_player_id_at(position).unwrap();
+                    let (_, moves) = self
+                        .blockers(game_state)
+                        .into_iter()
+                        .find(|(blocker_id, _)| *blocker_id == id)
+                        .unwrap();
+                    self.blocker = Some(id);
+                    aa.insert_positional(PosAT::SelectPosition, moves);
+                }
+            },
+            ProcInput::Reaction(Reaction::PassBlock, Action::Simple(SimpleAT::Decline)) => {
+                match self.blocker.take() {
+                    Some(id) => {
+                        self.moved.push(id);
+                        return ProcState::NotDone;
+                    }
+                    None => return ProcState::Done,
+                }
+            }
+            _ => panic!("Unexpected input {:?} for PassBlock", input),
+        }
+        ProcState::NeedAction(aa)
+    }
+}
+
+/// Moves one square in each direction from `from`. Returns where it ends up and, if it left the
+/// pitch, the last square it was on
+fn fly(
+    from: Position,
+    directions: impl IntoIterator<Item = Direction>,
+) -> (Position, Option<Position>) {
+    let mut target = from;
+    for d in directions {
+        let new_target = target + d;
+        if new_target.is_out() {
+            return (target, Some(target));
+        }
+        target = new_target;
+    }
+    (target, None)
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Pass {
     pos: Position,
     pass: D6Target,
     modifier: i8,
+    hail_mary: bool,
+    pass_block: bool,
+    dump_off: bool,
 }
 impl Pass {
     pub fn new(pos: Position, pass: D6Target, modifier: i8) -> AnyProc {
@@ -279,25 +380,110 @@ impl Pass {
             pos,
             pass,
             modifier,
+            hail_mary: false,
+            pass_block: false,
+            dump_off: false,
         })
     }
+    /// A quick pass by the ball carrier out of turn, it's never blocked and never a turnover
+    pub fn new_dump_off(pos: Position, pass: D6Target, modifier: i8) -> AnyProc {
+        AnyProc::Pass(Pass {
+            pos,
+            pass,
+            modifier,
+            hail_mary: false,
+            pass_block: true,
+            dump_off: true,
+        })
+    }
+    /// Target is for not fumbling, the pass is never accurate
+    pub fn new_hail_mary(pos: Position, pass: D6Target) -> AnyProc {
+        AnyProc::Pass(Pass {
+            pos,
+            pass,
+            modifier: 0,
+            hail_mary: true,
+            pass_block: false,
+            dump_off: false,
+        })
+    }
+    fn thrower_id(&self, game_state: &GameState) -> PlayerID {
+        match game_state.ball {
+            BallState::Carried(id) => id,
+            _ => panic!("Unexpected ball state for Pass: {:?}", game_state.ball),
+        }
+    }
+    fn fumble(&self, game_state: &mut GameState) -> ProcState {
+        let id = self.thrower_id(game_state);
+        let thrower = game_state.get_mut_player_unsafe(id);
+        if thrower.has_skill(Skill::SafePass) {
+            // keeps the ball, but the action is over
+            if !self.dump_off {
+                thrower.used = true;
+            }
+            return ProcState::Done;
+        }
+        if !self.dump_off {
+            game_state.info.turnover = true;
+        }
+        ProcState::DoneNew(Bounce::new())
+    }
+    fn resolve(&self, resolve: AnyProc) -> ProcState {
+        if self.dump_off {
+            ProcState::DoneNew(resolve)
+        } else {
+            ProcState::DoneNewProcs(vec![TurnoverIfPossessionLost::new(), resolve])
+        }
+    }
 }
 impl Procedure for Pass {
     fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
         match input {
-            ProcInput::Nothing => ProcState::NeedRoll(RequestedRoll::D6),
+            ProcInput::Nothing if !self.pass_block => {
+                self.pass_block = true;
+                let from = game_state.get_ball_position().unwrap();
+                let team = other_team(game_state.get_active_player().unwrap().stats.team);
+                if game_state
+                    .get_pass_block_moves(team, from, self.pos)
+                    .is_empty()
+                {
+                    ProcState::NeedRoll(RequestedRoll::D6)
+                } else {
+                    ProcState::NotDoneNew(PassBlock::new(from, self.pos))
+                }
+            }
+            ProcInput::Nothing => {
+                // the pass blockers may have changed the modifiers
+                let id = self.thrower_id(game_state);
+                let from = game_state.get_player_unsafe(id).position;
+                if self.hail_mary {
+                    self.pass = game_state.get_hail_mary_target(id, from).unwrap();
+                } else {
+                    self.pass = game_state.get_pass_target(id, from, self.pos).unwrap();
+                    self.modifier = game_state.get_pass_modifier(id, from, self.pos).unwrap();
+                }
+                ProcState::NeedRoll(RequestedRoll::D6)
+            }
+            ProcInput::Roll(RollResult::D6(roll)) if self.hail_mary => {
+                if self.pass.is_success(roll) {
+                    ProcState::NeedRoll(RequestedRoll::Scatter)
+                } else {
+                    self.fumble(game_state)
+                }
+            }
             ProcInput::Roll(RollResult::D6(roll)) if self.pass.is_success(roll) => {
                 // ACCURATE PASS
                 let from = game_state.get_ball_position().unwrap();
-                ProcState::DoneNewProcs(vec![
-                    TurnoverIfPossessionLost::new(),
-                    DeflectOrResolve::new(from, self.pos, PassResult::Accurate, None),
-                ])
+                self.resolve(DeflectOrResolve::new(
+                    from,
+                    self.pos,
+                    PassResult::Accurate,
+                    None,
+                ))
             }
             ProcInput::Roll(RollResult::D6(D6::One)) => {
                 // FUMBLE
-                game_state.info.turnover = true;
-                ProcState::DoneNew(Bounce::new())
+                self.fumble(game_state)
             }
             ProcInput::Roll(RollResult::D6(roll)) if roll + self.modifier == D6::One => {
                 // WILDLY INACCURATE PASSES
@@ -311,45 +497,297 @@ impl Procedure for Pass {
             }
             ProcInput::Roll(RollResult::Scatter(r1, r2, r3)) => {
                 let from = game_state.get_ball_position().unwrap(); //or just acive plater...
-                let mut target = self.pos;
-                let mut throwin_pos = None;
-                for d in [r1, r2, r3].iter().map(|r| Direction::from(*r)) {
-                    let new_target = target + d;
-                    if new_target.is_out() {
-                        throwin_pos = Some(target);
-                        break;
-                    }
-                    target = new_target;
-                }
-                ProcState::DoneNewProcs(vec![
-                    TurnoverIfPossessionLost::new(),
-                    DeflectOrResolve::new(from, target, PassResult::Inaccurate, throwin_pos),
-                ])
+                let (target, throwin_pos) = fly(self.pos, [r1, r2, r3].map(Direction::from));
+                let resolve = if self.hail_mary {
+                    DeflectOrResolve::new_hail_mary(from, target, throwin_pos)
+                } else {
+                    DeflectOrResolve::new(from, target, PassResult::Inaccurate, throwin_pos)
+                };
+                self.resolve(resolve)
             }
             ProcInput::Roll(RollResult::Deviate(distance, direction)) => {
                 let from = game_state.get_ball_position().unwrap();
-                let mut target = from; // + Direction::from(direction) * distance as i8;
-                let mut throwin_pos = None;
                 let dir = Direction::from(direction);
-                for _ in 0..(distance as i8) {
-                    let new_target = target + dir;
-                    if new_target.is_out() {
-                        throwin_pos = Some(target);
-                        break;
-                    }
-                    target = new_target;
-                }
+                let (target, throwin_pos) = fly(from, (0..distance as u8).map(|_| dir));
 
-                ProcState::DoneNewProcs(vec![
-                    TurnoverIfPossessionLost::new(),
-                    DeflectOrResolve::new(from, target, PassResult::WildlyInaccurate, throwin_pos),
-                ])
+                self.resolve(DeflectOrResolve::new(
+                    from,
+                    target,
+                    PassResult::WildlyInaccurate,
+                    throwin_pos,
+                ))
             }
             ProcInput::Action(_) => todo!(),
             _ => panic!("Unexpected input {:?} for Pass", input),
         }
     }
 }
+
+/// A ball carrier with Dump-off who is about to be blocked may throw a quick pass to a team-mate
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DumpOff {
+    id: PlayerID,
+}
+impl DumpOff {
+    pub fn new(id: PlayerID) -> AnyProc {
+        AnyProc::DumpOff(DumpOff { id })
+    }
+}
+impl Procedure for DumpOff {
+    fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
+        let thrower = game_state.get_player_unsafe(self.id);
+        let (from, team) = (thrower.position, thrower.stats.team);
+        match input {
+            ProcInput::Nothing => {
+                let positions = game_state.get_dump_off_positions(self.id, from);
+                if positions.is_empty() {
+                    return ProcState::Done;
+                }
+                let mut aa = AvailableActions::new_reaction(team, Reaction::DumpOff);
+                aa.insert_positional(PosAT::SelectPosition, positions);
+                ProcState::NeedAction(aa)
+            }
+            ProcInput::Reaction(
+                Reaction::DumpOff,
+                Action::Positional(PosAT::SelectPosition, pos),
+            ) => {
+                game_state.log("the ball is dumped off".to_string());
+                let pass = game_state.get_pass_target(self.id, from, pos).unwrap();
+                let modifier = game_state.get_pass_modifier(self.id, from, pos).unwrap();
+                ProcState::DoneNew(Pass::new_dump_off(pos, pass, modifier))
+            }
+            ProcInput::Reaction(Reaction::DumpOff, Action::Simple(SimpleAT::Decline)) => {
+                ProcState::Done
+            }
+            _ => panic!("Unexpected input {:?} for DumpOff", input),
+        }
+    }
+}
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+enum ThrowTeamMateState {
+    SelectTarget,
+    AlwaysHungry,
+    Eating,
+    Throw,
+    InFlight,
+}
+
+/// The active player throws a team-mate with Right Stuff a quick or short pass. A superb throw
+/// scatters three times from the target square, a subpar throw deviates from it and makes the
+/// landing harder, and a fumbled throw leaves the team-mate landing where they stand. A thrown
+/// player landing on someone knocks them over and bounces on until an empty square, where they
+/// crash land prone.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ThrowTeamMate {
+    thrown: PlayerID,
+    target: Position,
+    state: ThrowTeamMateState,
+    subpar: bool,
+    landed_on: Vec<PlayerID>,
+}
+impl ThrowTeamMate {
+    pub fn new(thrown: PlayerID) -> AnyProc {
+        AnyProc::ThrowTeamMate(ThrowTeamMate {
+            thrown,
+            target: Position::new((0, 0)),
+            state: ThrowTeamMateState::SelectTarget,
+            subpar: false,
+            landed_on: Vec::new(),
+        })
+    }
+
+    fn fumble(&mut self, game_state: &mut GameState) -> ProcState {
+        game_state.log("the throw is fumbled".to_string());
+        let from = game_state.get_player_unsafe(self.thrown).position;
+        self.land(game_state, from, None)
+    }
+
+    fn eaten(&self, game_state: &mut GameState) -> ProcState {
+        game_state.log("the team-mate is eaten".to_string());
+        game_state.info.turnover = true;
+        let position = game_state.get_player_unsafe(self.thrown).position;
+        let bounce = matches!(game_state.ball, BallState::Carried(id) if id == self.thrown);
+        if bounce {
+            game_state.ball = BallState::InAir(position);
+        }
+        let dugout_id = game_state
+            .unfield_player(self.thrown, DugoutPlace::Injuried)
+            .unwrap();
+        game_state
+            .get_dugout_player_mut(dugout_id)
+            .unwrap()
+            .casualty = Some(CasualtyOutcome::Dead);
+        if bounce {
+            ProcState::DoneNew(Bounce::new())
+        } else {
+            ProcState::Done
+        }
+    }
+
+    /// Resolves the thrown player coming down on `to`, or in the crowd after leaving the pitch
+    /// from `last_on_pitch`
+    fn land(
+        &mut self,
+        game_state: &mut GameState,
+        to: Position,
+        last_on_pitch: Option<Position>,
+    ) -> ProcState {
+        let carrier = matches!(game_state.ball, BallState::Carried(id) if id == self.thrown);
+        let mut procs: Vec<AnyProc> = Vec::new();
+        if let Some(throw_in_pos) = last_on_pitch {
+            if carrier {
+                game_state.info.turnover = true;
+                game_state.ball = BallState::InAir(throw_in_pos);
+                procs.push(ThrowIn::new(throw_in_pos));
+            }
+            procs.push(Injury::new_crowd(self.thrown));
+        } else {
+            match game_state.get_player_at(to) {
+                Some(player) if player.id != self.thrown => {
+                    if player.status == PlayerStatus::Up {
+                        self.landed_on.push(player.id);
+                    }
+                    self.target = to;
+                    self.state = ThrowTeamMateState::InFlight;
+                    return ProcState::NeedRoll(RequestedRoll::D8);
+                }
+                Some(_) => (),
+                None => game_state.move_player(self.thrown, to).unwrap(),
+            }
+            if !self.landed_on.is_empty() {
+                procs.push(KnockDown::new_placed_prone(self.thrown));
+            } else {
+                let mut target = game_state.get_player_unsafe(self.thrown).ag_target();
+                target.add_modifer(-(game_state.get_tz_on(self.thrown) as i8));
+                if self.subpar {
+                    target.add_modifer(-1);
+                }
+                procs.push(Landing::new(self.thrown, target));
+            }
+        }
+        procs.extend(self.landed_on.drain(..).map(KnockDown::new_placed_prone));
+        ProcState::from(procs)
+    }
+}
+impl Procedure for ThrowTeamMate {
+    fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
+        let thrower = game_state.get_active_player().unwrap();
+        let (thrower_id, from) = (thrower.id, thrower.position);
+        match (input, &self.state) {
+            (ProcInput::Nothing, ThrowTeamMateState::SelectTarget) => {
+                let mut aa = AvailableActions::new(thrower.stats.team);
+                aa.insert_positional(
+                    PosAT::ThrowTeamMate,
+                    game_state.get_throw_team_mate_squares(from),
+                );
+                ProcState::NeedAction(aa)
+            }
+            (
+                ProcInput::Action(Action::Positional(PosAT::ThrowTeamMate, target)),
+                ThrowTeamMateState::SelectTarget,
+            ) => {
+                self.target = target;
+                let always_hungry = thrower.has_skill(Skill::AlwaysHungry);
+                game_state.get_active_player_mut().unwrap().used = true;
+                self.state = if always_hungry {
+                    ThrowTeamMateState::AlwaysHungry
+                } else {
+                    ThrowTeamMateState::Throw
+                };
+                ProcState::NeedRoll(RequestedRoll::D6)
+            }
+            (ProcInput::Roll(RollResult::D6(D6::One)), ThrowTeamMateState::AlwaysHungry) => {
+                self.state = ThrowTeamMateState::Eating;
+                ProcState::NeedRoll(RequestedRoll::D6)
+            }
+            (ProcInput::Roll(RollResult::D6(_)), ThrowTeamMateState::AlwaysHungry) => {
+                self.state = ThrowTeamMateState::Throw;
+                ProcState::NeedRoll(RequestedRoll::D6)
+            }
+            (ProcInput::Roll(RollResult::D6(D6::One)), ThrowTeamMateState::Eating) => {
+                self.eaten(game_state)
+            }
+            (ProcInput::Roll(RollResult::D6(_)), ThrowTeamMateState::Eating) => {
+                // the team-mate squirms free
+                self.fumble(game_state)
+            }
+            (ProcInput::Roll(RollResult::D6(roll)), ThrowTeamMateState::Throw) => {
+                let target = game_state
+                    .get_pass_target(thrower_id, from, self.target)
+                    .unwrap();
+                let modifier = game_state
+                    .get_pass_modifier(thrower_id, from, self.target)
+                    .unwrap();
+                self.state = ThrowTeamMateState::InFlight;
+                if roll == D6::One || roll + modifier == D6::One {
+                    self.fumble(game_state)
+                } else if target.is_success(roll) {
+                    ProcState::NeedRoll(RequestedRoll::Scatter)
+                } else {
+                    self.subpar = true;
+                    ProcState::NeedRoll(RequestedRoll::Deviate)
+                }
+            }
+            (ProcInput::Roll(RollResult::Scatter(r1, r2, r3)), ThrowTeamMateState::InFlight) => {
+                let (to, out) = fly(self.target, [r1, r2, r3].map(Direction::from));
+                self.land(game_state, to, out)
+            }
+            (
+                ProcInput::Roll(RollResult::Deviate(distance, direction)),
+                ThrowTeamMateState::InFlight,
+            ) => {
+                let dir = Direction::from(direction);
+                let (to, out) = fly(self.target, (0..distance as u8).map(|_| dir));
+                self.land(game_state, to, out)
+            }
+            (ProcInput::Roll(RollResult::D8(direction)), ThrowTeamMateState::InFlight) => {
+                let (to, out) = fly(self.target, [Direction::from(direction)]);
+                self.land(game_state, to, out)
+            }
+            (input, _) => panic!("Unexpected input {:?} for ThrowTeamMate", input),
+        }
+    }
+}
+
+/// Landing roll of a thrown player, a player failing it falls over
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Landing {
+    id: PlayerID,
+    target: D6Target,
+}
+impl Landing {
+    pub fn new(id: PlayerID, target: D6Target) -> AnyProc {
+        AnyProc::Landing(SimpleProcContainer::new(Landing { id, target }))
+    }
+}
+impl SimpleProc for Landing {
+    fn d6_target(&self) -> D6Target {
+        self.target
+    }
+
+    fn reroll_skill(&self) -> Option<Skill> {
+        None
+    }
+
+    fn apply_success(&self, game_state: &mut GameState) -> Vec<AnyProc> {
+        let player = game_state.get_player_unsafe(self.id);
+        if matches!(game_state.ball, BallState::Carried(id) if id == self.id)
+            && player.position.x == game_state.get_endzone_x(player.stats.team)
+        {
+            game_state.info.handle_td_by = Some(self.id);
+        }
+        Vec::new()
+    }
+
+    fn apply_failure(&mut self, _game_state: &mut GameState) -> Vec<AnyProc> {
+        vec![KnockDown::new_placed_prone(self.id)]
+    }
+
+    fn player_id(&self) -> PlayerID {
+        self.id
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct DeflectOrResolve {
     from: Position,
@@ -357,6 +795,7 @@ pub struct DeflectOrResolve {
     throw_in_pos: Option<Position>,
     result: PassResult,
     intercepters: Vec<(Position, D6Target)>,
+    interference: bool,
 }
 impl DeflectOrResolve {
     pub fn new(
@@ -371,14 +810,48 @@ impl DeflectOrResolve {
             throw_in_pos,
             result,
             intercepters: Vec::new(),
+            interference: true,
+        })
+    }
+    /// A hail mary pass can't be deflected or intercepted
+    pub fn new_hail_mary(from: Position, to: Position, throw_in_pos: Option<Position>) -> AnyProc {
+        AnyProc::DeflectOrResolve(DeflectOrResolve {
+            from,
+            to,
+            throw_in_pos,
+            result: PassResult::Inaccurate,
+            intercepters: Vec::new(),
+            interference: false,
         })
     }
+    fn catch_modifier(&self) -> i8 {
+        match self.result {
+            PassResult::Accurate => 0,
+            PassResult::Inaccurate => -1,
+            PassResult::WildlyInaccurate => -2,
+            PassResult::Fumble => -3,
+        }
+    }
+    /// A single player with Diving Catch next to an empty landing square may try to catch it,
+    /// if there are more they are in each others way
+    fn diving_catcher(&self, game_state: &GameState) -> Option<PlayerID> {
+        let divers: Vec<PlayerID> = game_state
+            .get_adj_players(self.to)
+            .filter(|p| p.can_catch() && p.has_skill(Skill::DivingCatch))
+            .map(|p| p.id)
+            .collect();
+        match divers[..] {
+            [id] => Some(id),
+            _ => None,
+        }
+    }
 }
 impl Procedure for DeflectOrResolve {
     fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
-        let active_team = game_state.get_active_player().unwrap().stats.team;
-        let deflect_team = other_team(active_team);
+        let thrower_team = game_state.get_player_at(self.from).unwrap().stats.team;
+        let deflect_team = other_team(thrower_team);
         let interceptor: Option<(Position, D6Target)> = match input {
+            ProcInput::Nothing if !self.interference => None,
             ProcInput::Nothing => {
                 self.intercepters = game_state.get_intercepters(deflect_team, self.from, self.to);
                 if self.intercepters.is_empty() {
@@ -411,25 +884,27 @@ impl Procedure for DeflectOrResolve {
                 match game_state.get_player_at(self.to) {
                     Some(player) => {
                         let mut target = game_state.get_catch_target(player.id).unwrap();
-                        target.add_modifer(match self.result {
-                            PassResult::Accurate => 0,
-                            PassResult::Inaccurate => -1,
-                            PassResult::WildlyInaccurate => -2,
-                            PassResult::Fumble => -3,
-                        });
+                        target.add_modifer(self.catch_modifier());
+                        if self.result == PassResult::Accurate
+                            && player.has_skill(Skill::DivingCatch)
+                        {
+                            target.add_modifer(1);
+                        }
                         Catch::new(player.id, target)
                     }
-                    None => Bounce::new(),
+                    None => match self.diving_catcher(game_state) {
+                        Some(id) => {
+                            let mut target = game_state.get_catch_target(id).unwrap();
+                            target.add_modifer(self.catch_modifier());
+                            Catch::new(id, target)
+                        }
+                        None => Bounce::new(),
+                    },
                 }
             }
         };
         if let Some((pos, mut target)) = interceptor {
-            target.add_modifer(match self.result {
-                PassResult::Accurate => 0,
-                PassResult::Inaccurate => -1,
-                PassResult::WildlyInaccurate => -2,
-                PassResult::Fumble => -3,
-            });
+            target.add_modifer(self.catch_modifier());
             let id = game_state.get_player_id_at(pos).unwrap();
             ProcState::DoneNew(Deflect::new(id, target, failed_deflect_proc))
         } else {
@@ -497,6 +972,7 @@ mod tests {
 
     use crate::core::dices::BlockDice;
     use crate::core::dices::D8;
+    use crate::core::gamestate::GameState;
     use crate::core::model::*;
     use crate::core::table::*;
     use crate::core::{gamestate::GameStateBuilder, model::Position, table::PosAT};
@@ -519,7 +995,7 @@ mod tests {
         state.fixes.fix_d6(2); //fail pickup (3+)
         state.step_positional(PosAT::Move, ball_pos);
         state.fixes.fix_d8(d8_fix as u8);
-        state.step_simple(SimpleAT::DontUseReroll);
+        state.step_simple(SimpleAT::Decline);
 
         let player = state.get_player(id).unwrap();
         assert!(player.used);
@@ -642,4 +1118,103 @@ mod tests {
         state.step_positional(PosAT::StartHandoff, start_pos);
         assert!(!state.is_legal_action(&Action::Positional(PosAT::Handoff, target_pos)));
     }
+
+    /// A thrower with Throw Team-mate next to a team-mate with Right Stuff, ready to throw
+    fn throw_team_mate_state(
+        extra_skill: Option<Skill>,
+        away_pos: Position,
+    ) -> (GameState, PlayerID, PlayerID) {
+        let thrower_pos = Position::new((5, 5));
+        let thrown_pos = Position::new((5, 6));
+        let mut state = GameStateBuilder::new()
+            .add_home_player(thrower_pos)
+            .add_home_player(thrown_pos)
+            .add_away_player(away_pos)
+            .build();
+        let thrower_id = state.get_player_id_at(thrower_pos).unwrap();
+        let thrown_id = state.get_player_id_at(thrown_pos).unwrap();
+        let thrower = &mut state.get_mut_player_unsafe(thrower_id).stats;
+        thrower.give_skill(Skill::ThrowTeamMate);
+        if let Some(skill) = extra_skill {
+            thrower.give_skill(skill);
+        }
+        state
+            .get_mut_player_unsafe(thrown_id)
+            .stats
+            .give_skill(Skill::RightStuff);
+        // the next home turn offers the throw
+        state.step_simple(SimpleAT::EndTurn);
+        state.step_simple(SimpleAT::EndTurn);
+
+        state.step_positional(PosAT::StartThrowTeamMate, thrower_pos);
+        state.step_positional(PosAT::SelectPosition, thrown_pos);
+        (state, thrower_id, thrown_id)
+    }
+
+    #[test]
+    fn throw_team_mate() {
+        let (mut state, thrower_id, thrown_id) =
+            throw_team_mate_state(None, Position::new((20, 10)));
+        assert!(!state.is_legal_action(&Action::Positional(
+            PosAT::ThrowTeamMate,
+            Position::new((13, 5))
+        )));
+
+        state.fixes.fix_d6(5); //short pass, 5+
+        state.fixes.fix_d8_direction(Direction::right());
+        state.fixes.fix_d8_direction(Direction::left());
+        state.fixes.fix_d8_direction(Direction::up());
+        state.fixes.fix_d6(4); //landing
+        state.step_positional(PosAT::ThrowTeamMate, Position::new((9, 5)));
+
+        let thrown = state.get_player_unsafe(thrown_id);
+        assert_eq!(thrown.position, Position::new((9, 4)));
+        assert_eq!(thrown.status, PlayerStatus::Up);
+        assert!(state.get_player_unsafe(thrower_id).used);
+        assert!(state.home_to_act());
+        assert!(!state.is_legal_action(&Action::Positional(
+            PosAT::StartThrowTeamMate,
+            Position::new((5, 5))
+        )));
+    }
+
+    #[test]
+    fn throw_team_mate_crash_landing() {
+        let away_pos = Position::new((10, 5));
+        let (mut state, _, thrown_id) = throw_team_mate_state(None, away_pos);
+
+        state.fixes.fix_d6(3); //subpar throw
+        state.fixes.fix_d6(1); //deviate distance
+        state.fixes.fix_d8_direction(Direction::right());
+        state.fixes.fix_d8_direction(Direction::right()); //bounce off the away player
+        state.fixes.fix_d6(1); //away armor
+        state.fixes.fix_d6(1);
+        state.fixes.fix_d6(1); //thrown armor
+        state.fixes.fix_d6(1);
+        state.step_positional(PosAT::ThrowTeamMate, Position::new((9, 5)));
+
+        assert_eq!(
+            state.get_player_at(away_pos).unwrap().status,
+            PlayerStatus::Down
+        );
+        let thrown = state.get_player_unsafe(thrown_id);
+        assert_eq!(thrown.position, Position::new((11, 5)));
+        assert_eq!(thrown.status, PlayerStatus::Down);
+        assert!(state.home_to_act());
+    }
+
+    #[test]
+    fn always_hungry() {
+        let (mut state, _, _) =
+            throw_team_mate_state(Some(Skill::AlwaysHungry), Position::new((20, 10)));
+
+        state.fixes.fix_d6(1); //always hungry
+        state.fixes.fix_d6(1); //eaten
+        state.step_positional(PosAT::ThrowTeamMate, Position::new((9, 5)));
+
+        let dplayer = state.get_dugout().next().unwrap();
+        assert_eq!(dplayer.place, DugoutPlace::Injuried);
+        assert_eq!(dplayer.casualty, Some(CasualtyOutcome::Dead));
+        assert!(state.away_to_act());
+    }
 }
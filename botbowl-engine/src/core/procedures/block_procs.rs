@@ -5,9 +5,12 @@ use crate::core::gamestate::GameState;
 use crate::core::model::{
     other_team, Action, AvailableActions, Direction, PlayerStatus, Position, ProcState, Procedure,
 };
-use crate::core::model::{BallState, PlayerID, ProcInput};
+use crate::core::model::{BallState, PlayerID, ProcInput, Reaction};
 use crate::core::procedures::ball_procs;
-use crate::core::procedures::casualty_procs;
+use crate::core::procedures::procedure_tools::{
+    insert_reroll_actions, reroll_actions, reroll_sources, use_reroll, RerollSource, RollProcState,
+};
+use crate::core::procedures::{casualty_procs, movement_procs};
 use crate::core::table::{NumBlockDices, PosAT, SimpleAT, Skill};
 
 use super::AnyProc;
@@ -15,9 +18,16 @@ use super::AnyProc;
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 enum PushSquares {
     Crowd(Position),
+    SideStep(Vec<Position>),
     ChainPush(Vec<Position>),
     FreeSquares(Vec<Position>),
 }
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum FollowUpRule {
+    Optional,
+    Forced,
+    Never,
+}
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Push {
     from: Position,
@@ -25,17 +35,16 @@ pub struct Push {
     knockdown_proc: Option<KnockDown>,
     moves_to_make: Vec<(Position, Position)>,
     follow_up_pos: Position,
+    follow_up: FollowUpRule,
+    juggernaut: bool,
+    strip_ball: bool,
+    /// Squares of chain pushed players who stood firm, or couldn't be pushed anywhere else
+    stood_firm: Vec<Position>,
 }
 
 impl Push {
     pub fn new(from: Position, on: Position) -> AnyProc {
-        AnyProc::Push(Push {
-            from,
-            on,
-            moves_to_make: Vec::with_capacity(1),
-            knockdown_proc: None,
-            follow_up_pos: on,
-        })
+        AnyProc::Push(Push::new_pure(from, on))
     }
     pub fn new_pure(from: Position, on: Position) -> Push {
         Push {
@@ -44,10 +53,46 @@ impl Push {
             moves_to_make: Vec::with_capacity(1),
             knockdown_proc: None,
             follow_up_pos: on,
+            follow_up: FollowUpRule::Optional,
+            juggernaut: false,
+            strip_ball: false,
+            stood_firm: Vec::new(),
         }
     }
 
-    fn get_push_squares(on: Position, from: Position, game_state: &GameState) -> PushSquares {
+    fn follow_up(&self, game_state: &GameState) -> ProcState {
+        let fend = self.can_fend(game_state);
+        match self.follow_up {
+            FollowUpRule::Optional => {
+                ProcState::NotDoneNew(FollowUp::new(self.follow_up_pos, fend))
+            }
+            FollowUpRule::Forced => {
+                ProcState::NotDoneNew(FollowUp::new_forced(self.follow_up_pos, fend))
+            }
+            FollowUpRule::Never => ProcState::NotDone,
+        }
+    }
+
+    /// Without `side_step` the squares are chosen as if the pushed player had declined Side Step.
+    /// Nobody is chain pushed into the squares in `stood_firm`.
+    fn get_push_squares(
+        on: Position,
+        from: Position,
+        game_state: &GameState,
+        side_step: bool,
+        stood_firm: &[Position],
+    ) -> PushSquares {
+        let pushed_player = game_state.get_player_at(on).unwrap();
+        if side_step && pushed_player.has_skill(Skill::SideStep) {
+            let side_step_squares: Vec<Position> = game_state
+                .get_adj_positions(on)
+                .filter(|pos| !pos.is_out() && game_state.get_player_at(*pos).is_none())
+                .collect();
+            if !side_step_squares.is_empty() {
+                return PushSquares::SideStep(side_step_squares);
+            }
+        }
+
         let direction = on - from;
         let opposite_pos = on + direction;
         let mut push_squares = match direction {
@@ -67,6 +112,7 @@ impl Push {
         } else if push_squares.iter().any(|&pos| pos.is_out()) {
             PushSquares::Crowd(push_squares.pop().unwrap())
         } else {
+            push_squares.retain(|pos| !stood_firm.contains(pos));
             PushSquares::ChainPush(push_squares)
         }
     }
@@ -80,8 +126,47 @@ impl Push {
         });
     }
 
+    /// The target of the block, where it was pushed to
+    fn pushed_defender(&self, game_state: &GameState) -> Option<PlayerID> {
+        let (_, defender_to) = self.moves_to_make.first()?;
+        game_state.get_player_id_at(*defender_to)
+    }
+
+    /// A pushed back target with Fend may keep the attacker from following up, unless it's a
+    /// juggernaut
+    fn can_fend(&self, game_state: &GameState) -> bool {
+        !self.juggernaut
+            && self
+                .pushed_defender(game_state)
+                .is_some_and(|id| game_state.get_player_unsafe(id).has_skill(Skill::Fend))
+    }
+
+    /// Strip Ball makes a target pushed back without being knocked down drop the ball where it
+    /// ends up, unless it has Sure Hands. Players further down a chain push keep the ball.
+    fn strip_ball(&self, game_state: &mut GameState) -> Option<AnyProc> {
+        if !self.strip_ball || self.knockdown_proc.is_some() {
+            return None;
+        }
+        let id = self.pushed_defender(game_state)?;
+        let defender = game_state.get_player_unsafe(id);
+        let position = defender.position;
+        if position.is_out()
+            || defender.has_skill(Skill::SureHands)
+            || game_state.ball != BallState::Carried(id)
+        {
+            return None;
+        }
+        game_state.log("the ball is stripped".to_string());
+        game_state.ball = BallState::InAir(position);
+        if game_state.info.handle_td_by == Some(id) {
+            game_state.info.handle_td_by = None;
+        }
+        Some(ball_procs::Bounce::new())
+    }
+
     fn handle_aftermath(&mut self, game_state: &mut GameState) -> ProcState {
         let mut procs: Vec<AnyProc> = Vec::with_capacity(2);
+        procs.extend(self.strip_ball(game_state));
         let (last_push_from, last_push_to) = self.moves_to_make.pop().unwrap();
         if last_push_to.is_out() {
             let id = game_state.get_player_id_at(last_push_to).unwrap();
@@ -101,41 +186,102 @@ impl Push {
         ProcState::from(procs)
     }
 
-    fn calculate_next_state(&mut self, game_state: &mut GameState) -> ProcState {
+    fn calculate_next_state(&mut self, game_state: &mut GameState, side_step: bool) -> ProcState {
         let mut aa = AvailableActions::new(game_state.info.team_turn);
-        match Push::get_push_squares(self.on, self.from, game_state) {
+        match Push::get_push_squares(self.on, self.from, game_state, side_step, &self.stood_firm) {
             PushSquares::Crowd(position_in_crowd) => {
                 self.moves_to_make.push((self.on, position_in_crowd));
                 self.do_moves(game_state);
-                ProcState::NotDoneNew(FollowUp::new(self.follow_up_pos))
+                self.follow_up(game_state)
+            }
+            PushSquares::ChainPush(positions) if positions.is_empty() => {
+                self.stand_still(game_state)
             }
             PushSquares::ChainPush(positions) | PushSquares::FreeSquares(positions) => {
                 aa.insert_positional(PosAT::Push, positions);
                 ProcState::NeedAction(aa)
             }
+            PushSquares::SideStep(positions) => {
+                let team = game_state.get_player_at(self.on).unwrap().stats.team;
+                let mut aa = AvailableActions::new_reaction(team, Reaction::SideStep);
+                aa.insert_positional(PosAT::Push, positions);
+                ProcState::NeedAction(aa)
+            }
         }
     }
+
+    /// The pushed player may choose to stand firm, unless it's the target blitzed by a juggernaut
+    fn can_stand_firm(&self, game_state: &GameState) -> bool {
+        (!self.juggernaut || !self.moves_to_make.is_empty())
+            && game_state
+                .get_player_at(self.on)
+                .unwrap()
+                .has_skill(Skill::StandFirm)
+    }
+
+    fn ask_stand_firm(&self, game_state: &GameState) -> ProcState {
+        let team = game_state.get_player_at(self.on).unwrap().stats.team;
+        let mut aa = AvailableActions::new_reaction(team, Reaction::StandFirm);
+        aa.insert_simple(SimpleAT::UseStandFirm);
+        ProcState::NeedAction(aa)
+    }
+
+    /// The pushed player can't be moved. The target of the block is knocked down where it stands,
+    /// if at all, and a player pushing into a chain pushed player's square must go elsewhere.
+    fn stand_still(&mut self, game_state: &mut GameState) -> ProcState {
+        let Some((pusher_pos, _)) = self.moves_to_make.pop() else {
+            let procs = self.knockdown_proc.take().map(AnyProc::KnockDown);
+            return ProcState::from(procs.into_iter().collect::<Vec<_>>());
+        };
+        self.stood_firm.push(self.on);
+        self.on = pusher_pos;
+        self.from = match self.moves_to_make.last() {
+            Some((from, _)) => *from,
+            None => game_state.get_active_player().unwrap().position,
+        };
+        self.calculate_next_state(game_state, false)
+    }
 }
 
 impl Procedure for Push {
     fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
         match input {
+            ProcInput::Nothing
+                if self.moves_to_make.is_empty() && self.can_stand_firm(game_state) =>
+            {
+                self.ask_stand_firm(game_state)
+            }
             ProcInput::Nothing if self.moves_to_make.is_empty() => {
-                self.calculate_next_state(game_state)
+                self.calculate_next_state(game_state, true)
             }
             ProcInput::Nothing => self.handle_aftermath(game_state),
+            ProcInput::Reaction(Reaction::StandFirm, Action::Simple(SimpleAT::UseStandFirm)) => {
+                self.stand_still(game_state)
+            }
+            ProcInput::Reaction(Reaction::StandFirm, Action::Simple(SimpleAT::Decline)) => {
+                self.calculate_next_state(game_state, true)
+            }
+            ProcInput::Reaction(Reaction::SideStep, Action::Simple(SimpleAT::Decline)) => {
+                // the pushing coach chooses as usual
+                self.calculate_next_state(game_state, false)
+            }
             ProcInput::Action(Action::Positional(PosAT::Push, position_to))
                 if game_state.get_player_at(position_to).is_some() =>
             {
                 self.moves_to_make.push((self.on, position_to));
                 self.from = self.on;
                 self.on = position_to;
-                self.calculate_next_state(game_state)
+                if self.can_stand_firm(game_state) {
+                    self.ask_stand_firm(game_state)
+                } else {
+                    self.calculate_next_state(game_state, true)
+                }
             }
-            ProcInput::Action(Action::Positional(PosAT::Push, position)) => {
+            ProcInput::Action(Action::Positional(PosAT::Push, position))
+            | ProcInput::Reaction(Reaction::SideStep, Action::Positional(PosAT::Push, position)) => {
                 self.moves_to_make.push((self.on, position));
                 self.do_moves(game_state);
-                ProcState::NotDoneNew(FollowUp::new(self.follow_up_pos))
+                self.follow_up(game_state)
             }
             _ => panic!("very wrong!"),
         }
@@ -145,16 +291,49 @@ impl Procedure for Push {
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct FollowUp {
     to: Position,
+    forced: bool,
+    /// The pushed target's coach may still use Fend
+    fend: bool,
     //from is active player,
 }
 impl FollowUp {
-    pub fn new(to: Position) -> AnyProc {
-        AnyProc::FollowUp(FollowUp { to })
+    pub fn new(to: Position, fend: bool) -> AnyProc {
+        AnyProc::FollowUp(FollowUp {
+            to,
+            forced: false,
+            fend,
+        })
+    }
+    pub fn new_forced(to: Position, fend: bool) -> AnyProc {
+        AnyProc::FollowUp(FollowUp {
+            to,
+            forced: true,
+            fend,
+        })
     }
 }
 impl Procedure for FollowUp {
-    fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
+    fn step(&mut self, game_state: &mut GameState, mut input: ProcInput) -> ProcState {
         let player = game_state.get_active_player().unwrap();
+        match input {
+            ProcInput::Nothing if self.fend => {
+                let mut aa =
+                    AvailableActions::new_reaction(other_team(player.stats.team), Reaction::Fend);
+                aa.insert_simple(SimpleAT::UseFend);
+                return ProcState::NeedAction(aa);
+            }
+            ProcInput::Reaction(Reaction::Fend, Action::Simple(SimpleAT::UseFend)) => {
+                return ProcState::Done;
+            }
+            ProcInput::Reaction(Reaction::Fend, Action::Simple(SimpleAT::Decline)) => {
+                self.fend = false;
+                input = ProcInput::Nothing;
+            }
+            _ => (),
+        }
+        if self.forced {
+            input = ProcInput::Action(Action::Positional(PosAT::FollowUp, self.to));
+        }
         match input {
             ProcInput::Nothing => {
                 let mut aa = AvailableActions::new(player.stats.team);
@@ -184,13 +363,31 @@ impl Procedure for FollowUp {
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct KnockDown {
     id: PlayerID,
+    blocker: Option<PlayerID>,
+    turnover: bool,
 }
 impl KnockDown {
     pub fn new(id: PlayerID) -> AnyProc {
-        AnyProc::KnockDown(KnockDown { id })
+        AnyProc::KnockDown(KnockDown {
+            id,
+            blocker: None,
+            turnover: true,
+        })
     }
-    pub fn new_pure(id: PlayerID) -> KnockDown {
-        KnockDown { id }
+    pub fn new_blocked(id: PlayerID, blocker: PlayerID) -> KnockDown {
+        KnockDown {
+            id,
+            blocker: Some(blocker),
+            turnover: true,
+        }
+    }
+    /// Placed prone by a team-mate, only a turnover if the player drops the ball
+    pub fn new_placed_prone(id: PlayerID) -> AnyProc {
+        AnyProc::KnockDown(KnockDown {
+            id,
+            blocker: None,
+            turnover: false,
+        })
     }
 }
 impl Procedure for KnockDown {
@@ -203,9 +400,18 @@ impl Procedure for KnockDown {
         player.status = PlayerStatus::Down;
         player.used = true;
         let player_position = player.position;
-        let armor_proc = casualty_procs::Armor::new(self.id);
+        let player_team = player.stats.team;
+        let carrier =
+            matches!(game_state.ball, BallState::Carried(carrier_id) if carrier_id == self.id);
+        if player_team == game_state.info.team_turn && (self.turnover || carrier) {
+            game_state.info.turnover = true;
+        }
+        let armor_proc = match self.blocker {
+            Some(blocker) => casualty_procs::Armor::new_block(self.id, blocker),
+            None => casualty_procs::Armor::new(self.id),
+        };
 
-        if matches!(game_state.ball, BallState::Carried(carrier_id) if carrier_id == self.id) {
+        if carrier {
             game_state.ball = BallState::InAir(player_position);
             ProcState::DoneNewProcs(vec![ball_procs::Bounce::new(), armor_proc])
         } else {
@@ -215,44 +421,105 @@ impl Procedure for KnockDown {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
-pub struct BlockAction {}
+pub struct BlockAction {
+    multiple_block: bool,
+    blocked: Vec<PlayerID>,
+}
 
 impl BlockAction {
     pub fn new() -> AnyProc {
-        AnyProc::BlockAction(BlockAction {})
+        AnyProc::BlockAction(BlockAction {
+            multiple_block: false,
+            blocked: Vec::new(),
+        })
     }
-    fn available_actions(&mut self, game_state: &GameState) -> Box<AvailableActions> {
+    fn targets<'a>(&'a self, game_state: &'a GameState) -> impl Iterator<Item = PlayerID> + 'a {
         let player = game_state.get_active_player().unwrap();
-        let mut aa = AvailableActions::new(player.stats.team);
-
         game_state
             .get_adj_players(player.position)
-            .filter(|adj_player| {
-                !adj_player.used
-                    && adj_player.stats.team != player.stats.team
-                    && adj_player.status == PlayerStatus::Up
+            .filter(move |adj_player| {
+                adj_player.stats.team != player.stats.team && adj_player.status == PlayerStatus::Up
             })
-            .for_each(|block_victim| {
-                aa.insert_block(
-                    block_victim.position,
-                    game_state.get_blockdices(player.id, block_victim.id),
+            .map(|adj_player| adj_player.id)
+            .filter(|id| !self.blocked.contains(id))
+    }
+    fn available_actions(&mut self, game_state: &GameState) -> Box<AvailableActions> {
+        let player = game_state.get_active_player().unwrap();
+        let mut aa = AvailableActions::new(player.stats.team);
+
+        self.targets(game_state).for_each(|victim_id| {
+            let victim = game_state.get_player_unsafe(victim_id);
+            let dices = if self.multiple_block {
+                game_state.get_blockdices_with_str(
+                    player.id,
+                    player.position,
+                    victim_id,
+                    player.stats.str_,
+                    victim.stats.str_ + 2,
                 )
-            });
+            } else {
+                game_state.get_blockdices(player.id, victim_id)
+            };
+            aa.insert_block(victim.position, dices)
+        });
 
+        if !self.multiple_block
+            && player.has_skill(Skill::MultipleBlock)
+            && !player.has_skill(Skill::Frenzy)
+            && self.targets(game_state).count() >= 2
+        {
+            aa.insert_simple(SimpleAT::UseMultipleBlock);
+        }
         aa.insert_simple(SimpleAT::EndPlayerTurn);
         aa
     }
+    fn can_block_again(&self, game_state: &GameState) -> bool {
+        !game_state.info.turnover
+            && game_state.info.handle_td_by.is_none()
+            && matches!(game_state.get_active_player(), Some(player) if player.status == PlayerStatus::Up)
+            && self.targets(game_state).next().is_some()
+    }
 }
 impl Procedure for BlockAction {
     fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
         match input {
+            ProcInput::Nothing
+                if game_state.get_active_player().unwrap().status != PlayerStatus::Up =>
+            {
+                let player = game_state.get_active_player().unwrap();
+                if player.used {
+                    // failed to jump up
+                    ProcState::Done
+                } else {
+                    ProcState::NotDoneNew(movement_procs::JumpUpProc::new(
+                        player.id,
+                        player.ag_target(),
+                    ))
+                }
+            }
+            ProcInput::Nothing if !self.blocked.is_empty() => {
+                if self.blocked.len() < 2 && self.can_block_again(game_state) {
+                    ProcState::NeedAction(self.available_actions(game_state))
+                } else {
+                    ProcState::Done
+                }
+            }
             ProcInput::Nothing => ProcState::NeedAction(self.available_actions(game_state)),
+            ProcInput::Action(Action::Simple(SimpleAT::UseMultipleBlock)) => {
+                self.multiple_block = true;
+                ProcState::NeedAction(self.available_actions(game_state))
+            }
             ProcInput::Action(Action::Positional(PosAT::Block, position)) => {
                 let block_path = game_state.available_actions.take_path(position).unwrap();
                 let num_dice = block_path.get_block_dice().unwrap();
                 let defender_id = game_state.get_player_id_at(position).unwrap();
                 game_state.get_active_player_mut().unwrap().used = true;
-                ProcState::DoneNew(Block::new(num_dice, defender_id))
+                if self.multiple_block {
+                    self.blocked.push(defender_id);
+                    ProcState::NotDoneNew(Block::new_multiple(num_dice, defender_id))
+                } else {
+                    ProcState::DoneNew(Block::new(num_dice, defender_id))
+                }
             }
             ProcInput::Action(Action::Simple(SimpleAT::EndPlayerTurn)) => {
                 game_state.get_active_player_mut().unwrap().used = true;
@@ -270,25 +537,328 @@ pub struct Block {
     state: BlockProcState,
     roll: [Option<BlockDice>; 3],
     is_uphill: bool,
+    blitz: bool,
+    multiple: bool,
+    second_block: bool,
+    reroll: RollProcState,
+    reroll_source: Option<RerollSource>,
+    pro_dice: Option<usize>, // the dice Pro re-rolls
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 enum BlockProcState {
-    Init,               //step shall roll first dice
-    SelectDice,         //attacker (or defender if uphill) to choose dice
-    SelectDiceOrReroll, // Attacker may choose dice or reroll
-    UphillSelectReroll, // In uphill, attacker may choose to reroll
+    Init,                    //step shall roll first dice
+    DumpOff,                 //step shall roll first dice after the defender's dump-off
+    Dauntless,               //step shall roll for dauntless before the dices
+    Frenzy,                  //step shall start the second block if possible
+    FrenzyRush,              //step shall start the second block after the rush
+    SelectDice,              //attacker (or defender if uphill) to choose dice
+    SelectDiceOrReroll,      // Attacker may choose dice or reroll
+    UphillSelectReroll,      // In uphill, attacker may choose to reroll
+    ProSelectDice,           // attacker to choose the dice Pro re-rolls
+    BothDown(BothDownSkill), // a player may use the skill on the both down
+}
+/// The skills that may replace a both down, in the order they're offered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+enum BothDownSkill {
+    /// The blitzing attacker may treat it as a push
+    Juggernaut,
+    /// The attacker may place both players prone
+    Wrestle,
+    /// The defender may place both players prone, unless blitzed by a juggernaut
+    DefenderWrestle,
 }
 
 impl Block {
     pub fn new(dices: NumBlockDices, defender: PlayerID) -> AnyProc {
+        AnyProc::Block(Block::new_pure(dices, defender))
+    }
+    pub fn new_blitz(dices: NumBlockDices, defender: PlayerID) -> AnyProc {
+        let mut block = Block::new_pure(dices, defender);
+        block.blitz = true;
+        AnyProc::Block(block)
+    }
+    /// One of the two blocks of Multiple Block, the dices shall count the defender's extra strength
+    pub fn new_multiple(dices: NumBlockDices, defender: PlayerID) -> AnyProc {
+        let mut block = Block::new_pure(dices, defender);
+        block.multiple = true;
+        AnyProc::Block(block)
+    }
+    fn new_pure(dices: NumBlockDices, defender: PlayerID) -> Block {
         // the point is that number of dices has already been calculated, so this proc doesn't need to redo it.
-        AnyProc::Block(Block {
+        Block {
             dices,
             defender,
             state: BlockProcState::Init,
             roll: Default::default(),
             is_uphill: matches!(dices, NumBlockDices::TwoUphill | NumBlockDices::ThreeUphill),
-        })
+            blitz: false,
+            multiple: false,
+            second_block: false,
+            reroll: RollProcState::Init,
+            reroll_source: None,
+            pro_dice: None,
+        }
+    }
+
+    fn defender_str(&self, game_state: &GameState) -> u8 {
+        let str_ = game_state.get_player_unsafe(self.defender).stats.str_;
+        if self.multiple {
+            str_ + 2
+        } else {
+            str_
+        }
+    }
+
+    /// Horns adds 1 to the attacker's strength in a blitz
+    fn attacker_str(&self, game_state: &GameState) -> u8 {
+        let attacker = game_state.get_active_player().unwrap();
+        let horns = self.blitz && attacker.has_skill(Skill::Horns);
+        attacker.stats.str_ + u8::from(horns)
+    }
+
+    fn set_dices(&mut self, dices: NumBlockDices) {
+        self.dices = dices;
+        self.is_uphill = matches!(dices, NumBlockDices::TwoUphill | NumBlockDices::ThreeUphill);
+    }
+
+    fn needs_dauntless_roll(&self, game_state: &GameState) -> bool {
+        let attacker = game_state.get_active_player().unwrap();
+        attacker.has_skill(Skill::Dauntless)
+            && self.defender_str(game_state) > self.attacker_str(game_state)
+    }
+
+    /// Dump-off is only offered before the first block on a standing ball carrier
+    fn can_dump_off(&self, game_state: &GameState) -> bool {
+        let defender = game_state.get_player_unsafe(self.defender);
+        !self.second_block
+            && defender.status == PlayerStatus::Up
+            && defender.has_skill(Skill::DumpOff)
+            && matches!(game_state.ball, BallState::Carried(id) if id == self.defender)
+    }
+
+    fn start_block(&mut self, game_state: &GameState) -> ProcState {
+        if self.needs_dauntless_roll(game_state) {
+            self.state = BlockProcState::Dauntless;
+            ProcState::NeedRoll(RequestedRoll::D6)
+        } else {
+            ProcState::NeedRoll(RequestedRoll::BlockDice(self.dices))
+        }
+    }
+
+    /// Frenzy's second block needs both players standing next to each other
+    fn can_frenzy(&self, game_state: &GameState) -> bool {
+        let attacker = game_state.get_active_player().unwrap();
+        !game_state.info.turnover
+            && game_state.info.handle_td_by.is_none()
+            && attacker.status == PlayerStatus::Up
+            && matches!(game_state.get_player(self.defender),
+                Ok(defender) if defender.status == PlayerStatus::Up
+                    && defender.position.distance_to(&attacker.position) == 1)
+    }
+
+    fn start_frenzy_block(&mut self, game_state: &mut GameState) -> ProcState {
+        self.second_block = true;
+        self.reroll = RollProcState::Init;
+        self.reroll_source = None;
+        self.roll = Default::default();
+        self.state = BlockProcState::Init;
+        let attacker = game_state.get_active_player().unwrap();
+        self.set_dices(if self.blitz {
+            game_state.get_blitz_blockdices_from(attacker.id, attacker.position, self.defender)
+        } else {
+            game_state.get_blockdices(attacker.id, self.defender)
+        });
+        self.start_block(game_state)
+    }
+
+    fn frenzy(&mut self, game_state: &mut GameState) -> ProcState {
+        if !self.can_frenzy(game_state) {
+            return ProcState::Done;
+        }
+        if !self.blitz {
+            return self.start_frenzy_block(game_state);
+        }
+        let attacker = game_state.get_active_player_mut().unwrap();
+        if attacker.total_movement_left() == 0 {
+            ProcState::Done
+        } else if attacker.moves_left() == 0 {
+            attacker.add_move(1);
+            let id = attacker.id;
+            self.state = BlockProcState::FrenzyRush;
+            ProcState::NotDoneNew(movement_procs::GfiProc::new(
+                id,
+                game_state.get_rush_target(),
+            ))
+        } else {
+            attacker.add_move(1);
+            self.start_frenzy_block(game_state)
+        }
+    }
+
+    fn is_juggernaut(&self, game_state: &GameState) -> bool {
+        self.blitz
+            && game_state
+                .get_active_player()
+                .unwrap()
+                .has_skill(Skill::Juggernaut)
+    }
+
+    /// Both players are placed prone without armor rolls, only a turnover if the attacker had the ball
+    fn wrestle(&self, game_state: &mut GameState) -> ProcState {
+        let attacker_id = game_state.info.active_player.unwrap();
+        let mut procs: Vec<AnyProc> = Vec::new();
+        for id in [attacker_id, self.defender] {
+            let player = game_state.get_mut_player_unsafe(id);
+            player.status = PlayerStatus::Down;
+            let position = player.position;
+            if matches!(game_state.ball, BallState::Carried(carrier_id) if carrier_id == id) {
+                game_state.ball = BallState::InAir(position);
+                procs.push(ball_procs::Bounce::new());
+                if id == attacker_id {
+                    game_state.info.turnover = true;
+                }
+            }
+        }
+        game_state.get_mut_player_unsafe(attacker_id).used = true;
+        ProcState::from(procs)
+    }
+
+    /// Offers the next skill that may replace the both down, from `skill` on. Without any taker
+    /// the players without Block are knocked down.
+    fn both_down(&mut self, game_state: &GameState, skill: BothDownSkill) -> ProcState {
+        let attacker = game_state.get_active_player().unwrap();
+        let defender = game_state.get_player_unsafe(self.defender);
+        let juggernaut = self.is_juggernaut(game_state);
+        let offered = [
+            (BothDownSkill::Juggernaut, juggernaut),
+            (BothDownSkill::Wrestle, attacker.has_skill(Skill::Wrestle)),
+            (
+                BothDownSkill::DefenderWrestle,
+                !juggernaut && defender.has_skill(Skill::Wrestle),
+            ),
+        ]
+        .into_iter()
+        .find(|&(s, has_skill)| s >= skill && has_skill);
+
+        let team = attacker.stats.team;
+        let aa = match offered {
+            Some((BothDownSkill::Juggernaut, _)) => {
+                let mut aa = AvailableActions::new(team);
+                aa.insert_simple(SimpleAT::UseJuggernaut);
+                aa.insert_simple(SimpleAT::Decline);
+                aa
+            }
+            Some((BothDownSkill::Wrestle, _)) => {
+                let mut aa = AvailableActions::new(team);
+                aa.insert_simple(SimpleAT::UseWrestle);
+                aa.insert_simple(SimpleAT::Decline);
+                aa
+            }
+            Some((BothDownSkill::DefenderWrestle, _)) => {
+                let mut aa = AvailableActions::new_reaction(other_team(team), Reaction::Wrestle);
+                aa.insert_simple(SimpleAT::UseWrestle);
+                aa
+            }
+            None => return self.both_down_without_skills(game_state),
+        };
+        self.state = BlockProcState::BothDown(offered.unwrap().0);
+        ProcState::NeedAction(aa)
+    }
+
+    /// Asks the next skill after the one declined
+    fn decline_both_down_skill(&mut self, game_state: &GameState) -> ProcState {
+        let next = match self.state {
+            BlockProcState::BothDown(BothDownSkill::Juggernaut) => BothDownSkill::Wrestle,
+            BlockProcState::BothDown(BothDownSkill::Wrestle) => BothDownSkill::DefenderWrestle,
+            BlockProcState::BothDown(BothDownSkill::DefenderWrestle) => {
+                return self.both_down_without_skills(game_state)
+            }
+            _ => unreachable!(),
+        };
+        self.both_down(game_state, next)
+    }
+
+    fn both_down_without_skills(&mut self, game_state: &GameState) -> ProcState {
+        let attacker = game_state.get_active_player().unwrap();
+        let defender = game_state.get_player_unsafe(self.defender);
+        let knockdown_attacker = !attacker.has_skill(Skill::Block);
+        let knockdown_defender = !defender.has_skill(Skill::Block);
+        self.resolve(game_state, knockdown_attacker, knockdown_defender, false)
+    }
+
+    /// Knocks down and pushes the players as the chosen dice says
+    fn resolve(
+        &mut self,
+        game_state: &GameState,
+        knockdown_attacker: bool,
+        knockdown_defender: bool,
+        push: bool,
+    ) -> ProcState {
+        let attacker_id = game_state.info.active_player.unwrap();
+        let mut procs: Vec<AnyProc> = Vec::with_capacity(3);
+        if knockdown_attacker {
+            procs.push(KnockDown::new(attacker_id));
+        }
+        if push {
+            let mut push_proc = Push::new_pure(
+                game_state.get_player_unsafe(attacker_id).position,
+                game_state.get_player_unsafe(self.defender).position,
+            );
+            if knockdown_defender {
+                push_proc.knockdown_proc = Some(KnockDown::new_blocked(self.defender, attacker_id));
+            }
+            let attacker = game_state.get_player_unsafe(attacker_id);
+            push_proc.juggernaut = self.is_juggernaut(game_state);
+            push_proc.strip_ball = attacker.has_skill(Skill::StripBall);
+            let frenzy = attacker.has_skill(Skill::Frenzy);
+            if self.multiple {
+                push_proc.follow_up = FollowUpRule::Never;
+            } else if frenzy {
+                push_proc.follow_up = FollowUpRule::Forced;
+            }
+            procs.push(AnyProc::Push(push_proc));
+            if frenzy && !self.second_block && !knockdown_attacker && !knockdown_defender {
+                self.state = BlockProcState::Frenzy;
+                return ProcState::NotDoneNewProcs(procs);
+            }
+        } else if knockdown_defender {
+            procs.push(AnyProc::KnockDown(KnockDown::new_blocked(
+                self.defender,
+                attacker_id,
+            )));
+        }
+        ProcState::from(procs)
+    }
+
+    fn reroll_sources(&self, game_state: &GameState) -> Vec<RerollSource> {
+        if self.reroll != RollProcState::Init {
+            return Vec::new();
+        }
+        let attacker_id = game_state.info.active_player.unwrap();
+        reroll_sources(game_state, attacker_id, None)
+    }
+
+    fn reroll_block_dice(&mut self, game_state: &GameState) -> ProcState {
+        self.reroll = RollProcState::RerollUsed;
+        if self.reroll_source == Some(RerollSource::Pro) {
+            self.pro_reroll(game_state)
+        } else {
+            ProcState::NeedRoll(RequestedRoll::BlockDice(self.dices))
+        }
+    }
+
+    /// Pro re-rolls a single dice, the attacker chooses which one unless there's only one
+    fn pro_reroll(&mut self, game_state: &GameState) -> ProcState {
+        if self.roll.iter().flatten().count() > 1 {
+            self.state = BlockProcState::ProSelectDice;
+            return ProcState::NeedAction(self.available_actions(game_state));
+        }
+        self.pro_reroll_dice(0)
+    }
+
+    fn pro_reroll_dice(&mut self, index: usize) -> ProcState {
+        self.pro_dice = Some(index);
+        ProcState::NeedRoll(RequestedRoll::BlockDice(NumBlockDices::One))
     }
 
     fn add_aa(&self, aa: &mut AvailableActions) {
@@ -312,32 +882,67 @@ impl Block {
             BlockProcState::SelectDiceOrReroll => {
                 aa.team = Some(team);
                 self.add_aa(&mut aa);
-                aa.insert_simple(SimpleAT::UseReroll);
+                insert_reroll_actions(&mut aa, &self.reroll_sources(game_state));
             }
             BlockProcState::UphillSelectReroll => {
+                aa = reroll_actions(team, &self.reroll_sources(game_state));
+            }
+            BlockProcState::ProSelectDice => {
                 aa.team = Some(team);
-                aa.insert_simple(SimpleAT::UseReroll);
-                aa.insert_simple(SimpleAT::DontUseReroll);
+                self.add_aa(&mut aa);
             }
-            BlockProcState::Init => panic!("should not happen!"),
+            BlockProcState::Init
+            | BlockProcState::DumpOff
+            | BlockProcState::Dauntless
+            | BlockProcState::Frenzy
+            | BlockProcState::FrenzyRush
+            | BlockProcState::BothDown(_) => panic!("should not happen!"),
         }
         aa
     }
 }
 impl Procedure for Block {
     fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
-        if game_state.info.player_action_type.unwrap() == PosAT::StartBlitz {
-            game_state.info.player_action_type = Some(PosAT::StartMove); //to preven the player from blitzing again
-            game_state.get_active_player_mut().unwrap().add_move(1);
-        }
         match input {
-            ProcInput::Nothing => ProcState::NeedRoll(RequestedRoll::BlockDice(self.dices)),
+            ProcInput::Nothing if self.state == BlockProcState::Frenzy => self.frenzy(game_state),
+            ProcInput::Nothing if self.state == BlockProcState::FrenzyRush => {
+                if self.can_frenzy(game_state) {
+                    self.start_frenzy_block(game_state)
+                } else {
+                    ProcState::Done
+                }
+            }
+            ProcInput::Nothing
+                if self.state == BlockProcState::Init && self.can_dump_off(game_state) =>
+            {
+                self.state = BlockProcState::DumpOff;
+                ProcState::NotDoneNew(ball_procs::DumpOff::new(self.defender))
+            }
+            ProcInput::Nothing => self.start_block(game_state),
+            ProcInput::Roll(RollResult::D6(roll)) => {
+                debug_assert_eq!(self.state, BlockProcState::Dauntless);
+                let attacker = game_state.get_active_player().unwrap();
+                let defender_str = self.defender_str(game_state);
+                if roll as u8 + self.attacker_str(game_state) > defender_str {
+                    self.set_dices(game_state.get_blockdices_with_str(
+                        attacker.id,
+                        attacker.position,
+                        self.defender,
+                        defender_str,
+                        defender_str,
+                    ));
+                }
+                ProcState::NeedRoll(RequestedRoll::BlockDice(self.dices))
+            }
+            ProcInput::Roll(RollResult::BlockDice(rolls)) if self.pro_dice.is_some() => {
+                let index = self.pro_dice.take().unwrap();
+                self.roll[index] = rolls[0];
+                self.state = BlockProcState::SelectDice;
+                ProcState::NeedAction(self.available_actions(game_state))
+            }
             ProcInput::Roll(RollResult::BlockDice(rolls)) => {
                 self.roll = rolls;
-                let reroll_available = game_state
-                    .get_active_players_team()
-                    .unwrap()
-                    .can_use_reroll();
+                let reroll_available = !self.reroll_sources(game_state).is_empty();
                 self.state = match (reroll_available, self.is_uphill) {
                     (true, true) => BlockProcState::UphillSelectReroll,
                     (true, false) => BlockProcState::SelectDiceOrReroll,
@@ -345,77 +950,74 @@ impl Procedure for Block {
                 };
                 ProcState::NeedAction(self.available_actions(game_state))
             }
-            ProcInput::Action(Action::Simple(SimpleAT::UseReroll)) => {
-                game_state
-                    .get_active_players_team_mut()
-                    .unwrap()
-                    .use_reroll();
-                ProcState::NeedRoll(RequestedRoll::BlockDice(self.dices))
+            ProcInput::Action(Action::Simple(at)) if RerollSource::from_action(at).is_some() => {
+                let attacker_id = game_state.info.active_player.unwrap();
+                let source = RerollSource::from_action(at).unwrap();
+                self.reroll_source = Some(source);
+                match use_reroll(game_state, attacker_id, source) {
+                    Some(target) => {
+                        self.reroll = RollProcState::RerollRoll;
+                        ProcState::NeedRoll(RequestedRoll::D6PassFail(target))
+                    }
+                    None => self.reroll_block_dice(game_state),
+                }
+            }
+            ProcInput::Roll(RollResult::Pass) => {
+                debug_assert_eq!(self.reroll, RollProcState::RerollRoll);
+                self.reroll_block_dice(game_state)
+            }
+            ProcInput::Roll(RollResult::Fail) => {
+                debug_assert_eq!(self.reroll, RollProcState::RerollRoll);
+                self.reroll = RollProcState::RerollUsed;
+                game_state.log("the re-roll is wasted".to_string());
+                self.state = BlockProcState::SelectDice;
+                ProcState::NeedAction(self.available_actions(game_state))
             }
-            ProcInput::Action(Action::Simple(SimpleAT::DontUseReroll)) => {
+            ProcInput::Action(Action::Simple(SimpleAT::UseJuggernaut)) => {
+                self.resolve(game_state, false, false, true)
+            }
+            ProcInput::Action(Action::Simple(SimpleAT::UseWrestle))
+            | ProcInput::Reaction(Reaction::Wrestle, Action::Simple(SimpleAT::UseWrestle)) => {
+                self.wrestle(game_state)
+            }
+            ProcInput::Action(Action::Simple(SimpleAT::Decline))
+            | ProcInput::Reaction(Reaction::Wrestle, Action::Simple(SimpleAT::Decline))
+                if matches!(self.state, BlockProcState::BothDown(_)) =>
+            {
+                self.decline_both_down_skill(game_state)
+            }
+            ProcInput::Action(Action::Simple(SimpleAT::Decline)) => {
                 self.state = BlockProcState::SelectDice;
                 // ProcState::NotDone //I think it should be available_actions here...
                 ProcState::NeedAction(self.available_actions(game_state))
             }
+            ProcInput::Action(Action::Simple(dice_action_type))
+                if self.state == BlockProcState::ProSelectDice =>
+            {
+                let index = self
+                    .roll
+                    .iter()
+                    .position(|&r| r.map(SimpleAT::from) == Some(dice_action_type))
+                    .unwrap();
+                self.pro_reroll_dice(index)
+            }
             ProcInput::Action(Action::Simple(dice_action_type)) => {
-                let attacker_id = game_state.info.active_player.unwrap();
-                let mut knockdown_attacker = false;
-                let mut knockdown_defender = false;
-                let mut push = false;
-
+                let attacker = game_state.get_active_player().unwrap();
+                let defender = game_state.get_player_unsafe(self.defender);
                 match dice_action_type {
                     SimpleAT::SelectBothDown => {
-                        if !game_state
-                            .get_active_player()
-                            .unwrap()
-                            .has_skill(Skill::Block)
-                        {
-                            knockdown_attacker = true;
-                        }
-                        if !game_state
-                            .get_player_unsafe(self.defender)
-                            .has_skill(Skill::Block)
-                        {
-                            knockdown_defender = true;
-                        }
-                    }
-                    SimpleAT::SelectPow => {
-                        knockdown_defender = true;
-                        push = true;
-                    }
-                    SimpleAT::SelectPush => {
-                        push = true;
+                        self.both_down(game_state, BothDownSkill::Juggernaut)
                     }
+                    SimpleAT::SelectPow => self.resolve(game_state, false, true, true),
+                    SimpleAT::SelectPush => self.resolve(game_state, false, false, true),
                     SimpleAT::SelectPowPush => {
-                        if !game_state
-                            .get_player_unsafe(self.defender)
-                            .has_skill(Skill::Dodge)
-                        {
-                            knockdown_defender = true;
-                        }
-                        push = true;
+                        let knockdown_defender =
+                            !defender.has_skill(Skill::Dodge) || attacker.has_skill(Skill::Tackle);
+                        self.resolve(game_state, false, knockdown_defender, true)
                     }
-
-                    SimpleAT::SelectSkull => knockdown_attacker = true,
+                    SimpleAT::SelectSkull => self.resolve(game_state, true, false, false),
                     _ => panic!("very wrong!"),
                 }
-                let mut procs: Vec<AnyProc> = Vec::with_capacity(3);
-                if knockdown_attacker {
-                    procs.push(KnockDown::new(attacker_id));
-                }
-                if push {
-                    let mut push_proc = Push::new_pure(
-                        game_state.get_player_unsafe(attacker_id).position,
-                        game_state.get_player_unsafe(self.defender).position,
-                    );
-                    if knockdown_defender {
-                        push_proc.knockdown_proc = Some(KnockDown::new_pure(self.defender));
-                    }
-                    procs.push(AnyProc::Push(push_proc));
-                } else if knockdown_defender {
-                    procs.push(KnockDown::new(self.defender));
-                }
-                ProcState::from(procs)
             }
             _ => unreachable!(),
         }
@@ -425,6 +1027,7 @@ impl Procedure for Block {
 #[cfg(test)]
 mod tests {
     use crate::core::dices::BlockDice;
+    use crate::core::gamestate::GameState;
     use crate::core::model::*;
     use crate::core::table::*;
     use crate::core::{
@@ -501,6 +1104,7 @@ mod tests {
         state.fixes.fix_d6(6); //home armor
         state.fixes.fix_d6(6); //home injury
         state.fixes.fix_d6(6); //home injury
+        state.fixes.fix_d16(3); //badly hurt
         state.step_simple(SimpleAT::SelectBothDown);
 
         assert!(state.get_player_at(home_pos).is_none());
@@ -594,6 +1198,49 @@ mod tests {
         assert!(aa.is_legal_action(Action::Simple(SimpleAT::EndTurn)));
     }
 
+    #[test]
+    fn block_player_used_in_own_turn() {
+        let home_pos = Position::new((5, 5));
+        let away_pos = Position::new((6, 6));
+        let mut state = GameStateBuilder::new()
+            .add_home_player(home_pos)
+            .add_away_player(away_pos)
+            .build();
+
+        state.step_positional(PosAT::StartMove, home_pos);
+        state.step_simple(SimpleAT::EndPlayerTurn);
+        state.step_simple(SimpleAT::EndTurn);
+
+        // the used flag is only cleared at the start of the player's own turn
+        assert!(state.get_player_at(home_pos).unwrap().used);
+        state.step_positional(PosAT::StartBlock, away_pos);
+        assert!(state.is_legal_action(&Action::Positional(PosAT::Block, home_pos)));
+    }
+
+    #[test]
+    fn skull_is_turnover() {
+        let home_pos = Position::new((5, 5));
+        let away_pos = Position::new((6, 6));
+        let mut state = GameStateBuilder::new()
+            .add_home_player(home_pos)
+            .add_home_player(Position::new((1, 1)))
+            .add_away_player(away_pos)
+            .build();
+
+        state.step_positional(PosAT::StartBlock, home_pos);
+        state.fixes.fix_blockdice(BlockDice::Skull);
+        state.step_positional(PosAT::Block, away_pos);
+        state.fixes.fix_d6(1); //armor
+        state.fixes.fix_d6(1); //armor
+        state.step_simple(SimpleAT::SelectSkull);
+
+        assert_eq!(
+            state.get_player_at(home_pos).unwrap().status,
+            PlayerStatus::Down
+        );
+        assert!(state.away_to_act());
+    }
+
     #[test]
     fn available_block_action_adjescent_to_downed_player() {
         let home_pos = Position::new((5, 5));
@@ -640,4 +1287,600 @@ mod tests {
         state.fixes.fix_blockdice(BlockDice::Skull);
         state.step_positional(PosAT::Block, home_pos);
     }
+
+    fn skill_block_state(skill: Skill, attacker: bool) -> (GameState, PlayerID, PlayerID) {
+        let mut state = GameStateBuilder::new()
+            .add_home_player(Position::new((5, 5)))
+            .add_away_player(Position::new((6, 5)))
+            .build();
+        let attacker_id = state.get_player_id_at_coord(5, 5).unwrap();
+        let defender_id = state.get_player_id_at_coord(6, 5).unwrap();
+        let id = if attacker { attacker_id } else { defender_id };
+        state.get_mut_player_unsafe(id).stats.give_skill(skill);
+        (state, attacker_id, defender_id)
+    }
+
+    #[test]
+    fn guard_assists_while_marked() {
+        let mut state = GameStateBuilder::new()
+            .add_home_player(Position::new((5, 5)))
+            .add_home_player(Position::new((7, 6)))
+            .add_away_player(Position::new((6, 5)))
+            .add_away_player(Position::new((8, 6)))
+            .build();
+        let attacker_id = state.get_player_id_at_coord(5, 5).unwrap();
+        let defender_id = state.get_player_id_at_coord(6, 5).unwrap();
+        let assister_id = state.get_player_id_at_coord(7, 6).unwrap();
+        assert_eq!(
+            state.get_blockdices(attacker_id, defender_id),
+            NumBlockDices::One
+        );
+
+        state
+            .get_mut_player_unsafe(assister_id)
+            .stats
+            .give_skill(Skill::Guard);
+        assert_eq!(
+            state.get_blockdices(attacker_id, defender_id),
+            NumBlockDices::Two
+        );
+    }
+
+    #[test]
+    fn tackle_cancels_dodge() {
+        let (mut state, attacker_id, defender_id) = skill_block_state(Skill::Tackle, true);
+        state
+            .get_mut_player_unsafe(defender_id)
+            .stats
+            .give_skill(Skill::Dodge);
+        let attacker_pos = state.get_player_unsafe(attacker_id).position;
+
+        state.step_positional(PosAT::StartBlock, attacker_pos);
+        state.fixes.fix_blockdice(BlockDice::PowPush);
+        state.step_positional(PosAT::Block, Position::new((6, 5)));
+        state.step_simple(SimpleAT::SelectPowPush);
+        state.step_positional(PosAT::Push, Position::new((7, 5)));
+        state.fixes.fix_d6(1); //armor
+        state.fixes.fix_d6(1); //armor
+        state.step_positional(PosAT::FollowUp, attacker_pos);
+
+        assert_eq!(
+            state.get_player_unsafe(defender_id).status,
+            PlayerStatus::Down
+        );
+        assert!(state.fixes.is_empty());
+    }
+
+    #[test]
+    fn wrestle_places_both_prone() {
+        for use_wrestle in [true, false] {
+            let (mut state, attacker_id, defender_id) = skill_block_state(Skill::Wrestle, false);
+            state.step_positional(PosAT::StartBlock, Position::new((5, 5)));
+            state.fixes.fix_blockdice(BlockDice::BothDown);
+            state.step_positional(PosAT::Block, Position::new((6, 5)));
+            state.step_simple(SimpleAT::SelectBothDown);
+
+            // the defender's coach chooses
+            assert_eq!(state.get_available_actions().team, Some(TeamType::Away));
+            assert_eq!(
+                state.get_available_actions().get_reaction(),
+                Some(Reaction::Wrestle)
+            );
+            if use_wrestle {
+                state.step_simple(SimpleAT::UseWrestle);
+            } else {
+                for _ in 0..4 {
+                    state.fixes.fix_d6(1); // armor of both players
+                }
+                state.step_simple(SimpleAT::Decline);
+            }
+
+            for id in [attacker_id, defender_id] {
+                assert_eq!(state.get_player_unsafe(id).status, PlayerStatus::Down);
+            }
+            // declined, the attacker falls over and it's a turnover
+            assert_eq!(state.info.team_turn == TeamType::Home, use_wrestle);
+        }
+    }
+
+    #[test]
+    fn stand_firm() {
+        let (mut state, _, defender_id) = skill_block_state(Skill::StandFirm, false);
+        state.step_positional(PosAT::StartBlock, Position::new((5, 5)));
+        state.fixes.fix_blockdice(BlockDice::Pow);
+        state.step_positional(PosAT::Block, Position::new((6, 5)));
+        state.step_simple(SimpleAT::SelectPow);
+
+        assert_eq!(state.get_available_actions().team, Some(TeamType::Away));
+        state.fixes.fix_d6(1); //armor
+        state.fixes.fix_d6(1); //armor
+        state.step_simple(SimpleAT::UseStandFirm);
+
+        let defender = state.get_player_unsafe(defender_id);
+        assert_eq!(defender.position, Position::new((6, 5)));
+        assert_eq!(defender.status, PlayerStatus::Down);
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::EndTurn)));
+    }
+
+    /// Home blocks the away player at (6, 5) with a push, the three squares behind it are taken
+    /// by away players and those at `stand_firm` have Stand Firm
+    fn chain_push_state(stand_firm: &[(Coord, Coord)]) -> GameState {
+        let mut state = GameStateBuilder::new()
+            .add_home_player(Position::new((5, 5)))
+            .add_away_player(Position::new((6, 5)))
+            .add_away_player(Position::new((7, 4)))
+            .add_away_player(Position::new((7, 5)))
+            .add_away_player(Position::new((7, 6)))
+            .build();
+        for &coords in stand_firm {
+            let id = state.get_player_id_at(Position::new(coords)).unwrap();
+            state
+                .get_mut_player_unsafe(id)
+                .stats
+                .give_skill(Skill::StandFirm);
+        }
+        state.step_positional(PosAT::StartBlock, Position::new((5, 5)));
+        state.fixes.fix_blockdice(BlockDice::Push);
+        state.step_positional(PosAT::Block, Position::new((6, 5)));
+        state.step_simple(SimpleAT::SelectPush);
+        state
+    }
+
+    #[test]
+    fn stand_firm_in_chain_push() {
+        let mut state = chain_push_state(&[(7, 5)]);
+        let target_id = state.get_player_id_at_coord(6, 5).unwrap();
+        let chained_id = state.get_player_id_at_coord(7, 4).unwrap();
+        let stood_firm_id = state.get_player_id_at_coord(7, 5).unwrap();
+        state.step_positional(PosAT::Push, Position::new((7, 5)));
+        assert_eq!(
+            state.get_available_actions().get_reaction(),
+            Some(Reaction::StandFirm)
+        );
+        assert_eq!(state.get_available_actions().team, Some(TeamType::Away));
+        state.step_simple(SimpleAT::UseStandFirm);
+
+        assert!(state.home_to_act());
+        assert!(!state.is_legal_action(&Action::Positional(PosAT::Push, Position::new((7, 5)))));
+        state.step_positional(PosAT::Push, Position::new((7, 4)));
+        state.step_positional(PosAT::Push, Position::new((8, 4)));
+        state.step_positional(PosAT::FollowUp, Position::new((6, 5)));
+
+        assert_eq!(
+            state.get_player_unsafe(target_id).position,
+            Position::new((7, 4))
+        );
+        assert_eq!(
+            state.get_player_unsafe(chained_id).position,
+            Position::new((8, 4))
+        );
+        assert_eq!(state.get_player_id_at_coord(7, 5), Some(stood_firm_id));
+    }
+
+    #[test]
+    fn stand_firm_everywhere_in_chain_push() {
+        let mut state = chain_push_state(&[(7, 4), (7, 5), (7, 6)]);
+        let target_id = state.get_player_id_at_coord(6, 5).unwrap();
+        for coords in [(7, 4), (7, 5), (7, 6)] {
+            state.step_positional(PosAT::Push, Position::new(coords));
+            state.step_simple(SimpleAT::UseStandFirm);
+        }
+
+        assert_eq!(
+            state.get_player_unsafe(target_id).position,
+            Position::new((6, 5))
+        );
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::EndTurn)));
+    }
+
+    #[test]
+    fn side_step() {
+        let (mut state, _, defender_id) = skill_block_state(Skill::SideStep, false);
+        state.step_positional(PosAT::StartBlock, Position::new((5, 5)));
+        state.fixes.fix_blockdice(BlockDice::Push);
+        state.step_positional(PosAT::Block, Position::new((6, 5)));
+        state.step_simple(SimpleAT::SelectPush);
+
+        assert_eq!(state.get_available_actions().team, Some(TeamType::Away));
+        let side_step_pos = Position::new((5, 4));
+        assert!(state.is_legal_action(&Action::Positional(PosAT::Push, side_step_pos)));
+        state.step_positional(PosAT::Push, side_step_pos);
+        state.step_positional(PosAT::FollowUp, Position::new((6, 5)));
+
+        assert_eq!(state.get_player_unsafe(defender_id).position, side_step_pos);
+    }
+
+    #[test]
+    fn side_step_declined() {
+        let (mut state, _, defender_id) = skill_block_state(Skill::SideStep, false);
+        state.step_positional(PosAT::StartBlock, Position::new((5, 5)));
+        state.fixes.fix_blockdice(BlockDice::Push);
+        state.step_positional(PosAT::Block, Position::new((6, 5)));
+        state.step_simple(SimpleAT::SelectPush);
+        assert!(state.get_available_actions().is_reaction());
+        state.step_simple(SimpleAT::Decline);
+
+        assert!(state.home_to_act());
+        assert!(!state.get_available_actions().is_reaction());
+        let side_step = Action::Positional(PosAT::Push, Position::new((5, 4)));
+        assert!(!state.is_legal_action(&side_step));
+        state.step_positional(PosAT::Push, Position::new((7, 5)));
+        state.step_positional(PosAT::FollowUp, Position::new((6, 5)));
+
+        assert_eq!(
+            state.get_player_unsafe(defender_id).position,
+            Position::new((7, 5))
+        );
+    }
+
+    #[test]
+    fn stand_firm_declined_then_side_step() {
+        let (mut state, _, defender_id) = skill_block_state(Skill::SideStep, false);
+        state
+            .get_mut_player_unsafe(defender_id)
+            .stats
+            .give_skill(Skill::StandFirm);
+        state.step_positional(PosAT::StartBlock, Position::new((5, 5)));
+        state.fixes.fix_blockdice(BlockDice::Push);
+        state.step_positional(PosAT::Block, Position::new((6, 5)));
+        state.step_simple(SimpleAT::SelectPush);
+        assert_eq!(
+            state.get_available_actions().get_reaction(),
+            Some(Reaction::StandFirm)
+        );
+        state.step_simple(SimpleAT::Decline);
+
+        assert_eq!(
+            state.get_available_actions().get_reaction(),
+            Some(Reaction::SideStep)
+        );
+        let side_step_pos = Position::new((5, 4));
+        state.step_positional(PosAT::Push, side_step_pos);
+        state.step_positional(PosAT::FollowUp, Position::new((6, 5)));
+
+        assert_eq!(state.get_player_unsafe(defender_id).position, side_step_pos);
+    }
+
+    #[test]
+    fn juggernaut_blitz() {
+        let (mut state, attacker_id, defender_id) = skill_block_state(Skill::Juggernaut, true);
+        state
+            .get_mut_player_unsafe(defender_id)
+            .stats
+            .give_skill(Skill::StandFirm);
+        state
+            .get_mut_player_unsafe(defender_id)
+            .stats
+            .give_skill(Skill::Fend);
+        state.step_positional(PosAT::StartBlitz, Position::new((5, 5)));
+        state.fixes.fix_blockdice(BlockDice::BothDown);
+        state.step_positional(PosAT::Block, Position::new((6, 5)));
+        state.step_simple(SimpleAT::SelectBothDown);
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::Decline)));
+        state.step_simple(SimpleAT::UseJuggernaut);
+
+        // both down is a push, and stand firm and fend can't be used against a juggernaut
+        assert_eq!(state.get_available_actions().team, Some(TeamType::Home));
+        state.step_positional(PosAT::Push, Position::new((7, 5)));
+        state.step_positional(PosAT::FollowUp, Position::new((6, 5)));
+
+        assert_eq!(
+            state.get_player_unsafe(attacker_id).status,
+            PlayerStatus::Up
+        );
+        let defender = state.get_player_unsafe(defender_id);
+        assert_eq!(defender.position, Position::new((7, 5)));
+        assert_eq!(defender.status, PlayerStatus::Up);
+    }
+
+    #[test]
+    fn dauntless() {
+        for (roll, dices) in [(2, 1), (1, 2)] {
+            let (mut state, _, defender_id) = skill_block_state(Skill::Dauntless, true);
+            state.get_mut_player_unsafe(defender_id).stats.str_ = 4;
+            state.home.rerolls = 0;
+            state.step_positional(PosAT::StartBlock, Position::new((5, 5)));
+            state.fixes.fix_d6(roll);
+            for _ in 0..dices {
+                state.fixes.fix_blockdice(BlockDice::Push);
+            }
+            state.step_positional(PosAT::Block, Position::new((6, 5)));
+
+            assert!(state.fixes.is_empty());
+            let attacker_chooses = dices == 1;
+            assert_eq!(
+                state.get_available_actions().team == Some(TeamType::Home),
+                attacker_chooses
+            );
+        }
+    }
+
+    #[test]
+    fn frenzy_blocks_twice() {
+        let (mut state, attacker_id, defender_id) = skill_block_state(Skill::Frenzy, true);
+        state.step_positional(PosAT::StartBlock, Position::new((5, 5)));
+        state.fixes.fix_blockdice(BlockDice::Push);
+        state.step_positional(PosAT::Block, Position::new((6, 5)));
+        state.step_simple(SimpleAT::SelectPush);
+
+        state.fixes.fix_blockdice(BlockDice::Push);
+        state.step_positional(PosAT::Push, Position::new((7, 5)));
+        assert_eq!(
+            state.get_player_unsafe(attacker_id).position,
+            Position::new((6, 5))
+        );
+        state.step_simple(SimpleAT::SelectPush);
+        state.step_positional(PosAT::Push, Position::new((8, 5)));
+
+        assert!(state.fixes.is_empty());
+        assert_eq!(
+            state.get_player_unsafe(attacker_id).position,
+            Position::new((7, 5))
+        );
+        assert_eq!(
+            state.get_player_unsafe(defender_id).position,
+            Position::new((8, 5))
+        );
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::EndTurn)));
+    }
+
+    #[test]
+    fn frenzy_blitz_rushes() {
+        let start_pos = Position::new((2, 5));
+        let mut state = GameStateBuilder::new()
+            .add_home_player(start_pos)
+            .add_away_player(Position::new((6, 5)))
+            .build();
+        let attacker_id = state.get_player_id_at(start_pos).unwrap();
+        let attacker = state.get_mut_player_unsafe(attacker_id);
+        attacker.stats.ma = 3;
+        attacker.stats.give_skill(Skill::Frenzy);
+
+        state.step_positional(PosAT::StartBlitz, start_pos);
+        state.fixes.fix_d6(2); //rush to block
+        state.fixes.fix_blockdice(BlockDice::Push);
+        state.step_positional(PosAT::Block, Position::new((6, 5)));
+        state.step_simple(SimpleAT::SelectPush);
+
+        state.fixes.fix_d6(2); //rush to block again
+        state.fixes.fix_blockdice(BlockDice::Push);
+        state.step_positional(PosAT::Push, Position::new((7, 5)));
+        state.step_simple(SimpleAT::SelectPush);
+        state.step_positional(PosAT::Push, Position::new((8, 5)));
+
+        assert!(state.fixes.is_empty());
+        let attacker = state.get_player_unsafe(attacker_id);
+        assert_eq!(attacker.position, Position::new((7, 5)));
+        assert_eq!(attacker.total_movement_left(), 0);
+    }
+
+    #[test]
+    fn frenzy_blitz_with_horns() {
+        let start_pos = Position::new((4, 5));
+        let mut state = GameStateBuilder::new()
+            .add_home_player(start_pos)
+            .add_away_player(Position::new((6, 5)))
+            .build();
+        let attacker_id = state.get_player_id_at(start_pos).unwrap();
+        let attacker = state.get_mut_player_unsafe(attacker_id);
+        attacker.stats.give_skill(Skill::Frenzy);
+        attacker.stats.give_skill(Skill::Horns);
+
+        // Horns gives two dices on both blocks of the blitz
+        state.step_positional(PosAT::StartBlitz, start_pos);
+        state.fixes.fix_blockdice(BlockDice::Push);
+        state.fixes.fix_blockdice(BlockDice::Push);
+        state.step_positional(PosAT::Block, Position::new((6, 5)));
+        state.step_simple(SimpleAT::SelectPush);
+
+        state.fixes.fix_blockdice(BlockDice::Push);
+        state.fixes.fix_blockdice(BlockDice::Push);
+        state.step_positional(PosAT::Push, Position::new((7, 5)));
+        state.step_simple(SimpleAT::SelectPush);
+        state.step_positional(PosAT::Push, Position::new((8, 5)));
+
+        assert!(state.fixes.is_empty());
+        assert_eq!(
+            state.get_player_unsafe(attacker_id).position,
+            Position::new((7, 5))
+        );
+    }
+
+    #[test]
+    fn multiple_block() {
+        let mut state = GameStateBuilder::new()
+            .add_home_player(Position::new((5, 5)))
+            .add_away_player(Position::new((6, 5)))
+            .add_away_player(Position::new((6, 6)))
+            .build();
+        let attacker_id = state.get_player_id_at_coord(5, 5).unwrap();
+        let attacker = state.get_mut_player_unsafe(attacker_id);
+        attacker.stats.str_ = 6;
+        attacker.stats.give_skill(Skill::MultipleBlock);
+
+        state.step_positional(PosAT::StartBlock, Position::new((5, 5)));
+        assert_eq!(
+            state.get_blockdices(attacker_id, state.get_player_id_at_coord(6, 5).unwrap()),
+            NumBlockDices::Two
+        );
+        state.step_simple(SimpleAT::UseMultipleBlock);
+        // 6 against 3 + 2, plus the assist from the other target
+        state.fixes.fix_blockdice(BlockDice::Push);
+        state.step_positional(PosAT::Block, Position::new((6, 5)));
+        state.step_simple(SimpleAT::SelectPush);
+        state.step_positional(PosAT::Push, Position::new((7, 5)));
+
+        // no follow up, straight on to the second block
+        assert!(!state.is_legal_action(&Action::Positional(PosAT::Block, Position::new((7, 5)))));
+        state.fixes.fix_blockdice(BlockDice::Push);
+        state.fixes.fix_blockdice(BlockDice::Push);
+        state.step_positional(PosAT::Block, Position::new((6, 6)));
+        state.step_simple(SimpleAT::SelectPush);
+        state.step_positional(PosAT::Push, Position::new((7, 7)));
+
+        assert!(state.fixes.is_empty());
+        assert_eq!(
+            state.get_player_unsafe(attacker_id).position,
+            Position::new((5, 5))
+        );
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::EndTurn)));
+    }
+
+    #[test]
+    fn strip_ball() {
+        for sure_hands in [false, true] {
+            let (mut state, _, defender_id) = skill_block_state(Skill::StripBall, true);
+            state.ball = BallState::Carried(defender_id);
+            if sure_hands {
+                state
+                    .get_mut_player_unsafe(defender_id)
+                    .stats
+                    .give_skill(Skill::SureHands);
+            }
+
+            state.step_positional(PosAT::StartBlock, Position::new((5, 5)));
+            state.fixes.fix_blockdice(BlockDice::Push);
+            state.step_positional(PosAT::Block, Position::new((6, 5)));
+            state.step_simple(SimpleAT::SelectPush);
+            state.step_positional(PosAT::Push, Position::new((7, 5)));
+            if !sure_hands {
+                state.fixes.fix_d8_direction(Direction::down());
+            }
+            state.step_positional(PosAT::FollowUp, Position::new((5, 5)));
+
+            if sure_hands {
+                assert_eq!(state.ball, BallState::Carried(defender_id));
+            } else {
+                assert_eq!(state.ball, BallState::OnGround(Position::new((7, 6))));
+            }
+            assert!(state.home_to_act());
+        }
+    }
+
+    #[test]
+    fn dump_off() {
+        for fumble in [false, true] {
+            let mut state = GameStateBuilder::new()
+                .add_home_player(Position::new((5, 5)))
+                .add_away_player(Position::new((6, 5)))
+                .add_away_player(Position::new((9, 5)))
+                .build();
+            let defender_id = state.get_player_id_at_coord(6, 5).unwrap();
+            let catcher_id = state.get_player_id_at_coord(9, 5).unwrap();
+            state
+                .get_mut_player_unsafe(defender_id)
+                .stats
+                .give_skill(Skill::DumpOff);
+            state.ball = BallState::Carried(defender_id);
+
+            state.step_positional(PosAT::StartBlock, Position::new((5, 5)));
+            state.step_positional(PosAT::Block, Position::new((6, 5)));
+            assert!(state.away_to_act());
+            assert!(state.get_available_actions().is_reaction());
+
+            if fumble {
+                state.fixes.fix_d6(1);
+                state.fixes.fix_d8_direction(Direction::up());
+            } else {
+                state.fixes.fix_d6(6);
+                state.fixes.fix_d6(6);
+            }
+            state.fixes.fix_blockdice(BlockDice::Push);
+            state.step_positional(PosAT::SelectPosition, Position::new((9, 5)));
+
+            if fumble {
+                assert_eq!(state.ball, BallState::OnGround(Position::new((6, 4))));
+            } else {
+                assert_eq!(state.ball, BallState::Carried(catcher_id));
+            }
+            assert!(!state.info.turnover);
+            assert!(state.home_to_act());
+            assert!(state.is_legal_action(&Action::Simple(SimpleAT::SelectPush)));
+        }
+    }
+
+    #[test]
+    fn fend_stops_follow_up() {
+        for use_fend in [true, false] {
+            let (mut state, attacker_id, _) = skill_block_state(Skill::Fend, false);
+
+            state.step_positional(PosAT::StartBlock, Position::new((5, 5)));
+            state.fixes.fix_blockdice(BlockDice::Push);
+            state.step_positional(PosAT::Block, Position::new((6, 5)));
+            state.step_simple(SimpleAT::SelectPush);
+            state.step_positional(PosAT::Push, Position::new((7, 5)));
+            assert!(state.away_to_act());
+            assert!(state.get_available_actions().is_reaction());
+
+            if use_fend {
+                state.step_simple(SimpleAT::UseFend);
+                assert!(!state
+                    .is_legal_action(&Action::Positional(PosAT::FollowUp, Position::new((6, 5)))));
+                assert_eq!(
+                    state.get_player_unsafe(attacker_id).position,
+                    Position::new((5, 5))
+                );
+            } else {
+                state.step_simple(SimpleAT::Decline);
+                state.step_positional(PosAT::FollowUp, Position::new((6, 5)));
+                assert_eq!(
+                    state.get_player_unsafe(attacker_id).position,
+                    Position::new((6, 5))
+                );
+            }
+            assert!(state.home_to_act());
+        }
+    }
+
+    #[test]
+    fn leader_reroll_on_block_dice() {
+        let (mut state, attacker_id, _) = skill_block_state(Skill::Leader, true);
+        state
+            .get_mut_player_unsafe(attacker_id)
+            .stats
+            .give_skill(Skill::Pro);
+        state.home.rerolls = 0;
+        state.home.leader_reroll = true;
+
+        state.step_positional(PosAT::StartBlock, Position::new((5, 5)));
+        state.fixes.fix_blockdice(BlockDice::Skull);
+        state.step_positional(PosAT::Block, Position::new((6, 5)));
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::UseLeaderReroll)));
+        assert!(!state.is_legal_action(&Action::Simple(SimpleAT::UseReroll)));
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::UsePro)));
+
+        state.fixes.fix_blockdice(BlockDice::Pow);
+        state.step_simple(SimpleAT::UseLeaderReroll);
+        assert!(!state.home.leader_reroll);
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::SelectPow)));
+        assert!(!state.is_legal_action(&Action::Simple(SimpleAT::UseLeaderReroll)));
+    }
+
+    #[test]
+    fn pro_rerolls_one_block_dice() {
+        let (mut state, attacker_id, _) = skill_block_state(Skill::Pro, true);
+        state.get_mut_player_unsafe(attacker_id).stats.str_ = 4;
+        state.home.rerolls = 0;
+
+        state.step_positional(PosAT::StartBlock, Position::new((5, 5)));
+        state.fixes.fix_blockdice(BlockDice::Skull);
+        state.fixes.fix_blockdice(BlockDice::Pow);
+        state.step_positional(PosAT::Block, Position::new((6, 5)));
+
+        state.fixes.fix_d6(3);
+        state.step_simple(SimpleAT::UsePro);
+        assert!(!state
+            .get_player_unsafe(attacker_id)
+            .can_use_skill(Skill::Pro));
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::SelectSkull)));
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::SelectPow)));
+
+        // only the chosen dice is re-rolled
+        state.fixes.fix_blockdice(BlockDice::Push);
+        state.step_simple(SimpleAT::SelectSkull);
+        assert!(!state.is_legal_action(&Action::Simple(SimpleAT::SelectSkull)));
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::SelectPush)));
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::SelectPow)));
+        assert!(!state.is_legal_action(&Action::Simple(SimpleAT::UsePro)));
+    }
 }
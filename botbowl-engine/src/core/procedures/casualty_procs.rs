@@ -1,56 +1,127 @@
 use serde::{Deserialize, Serialize};
 
-use crate::core::dices::{RequestedRoll, RollResult, Sum2D6Target};
+use crate::core::dices::{
+    D6Target, RequestedRoll, RollResult, RollTarget, Sum2D6, Sum2D6Target, D6,
+};
 use crate::core::gamestate::GameState;
-use crate::core::model::{BallState, PlayerID};
+use crate::core::model::{Action, AvailableActions, BallState, PlayerID};
 use crate::core::model::{DugoutPlace, PlayerStatus, ProcState, Procedure};
 use crate::core::model::{InjuryOutcome, ProcInput};
 use crate::core::procedures::ball_procs;
+use crate::core::table::{CasualtyOutcome, LastingInjury, SimpleAT, Skill};
 
 use super::AnyProc;
 
+/// Rolls for armour. A blocker with Mighty Blow, or a fouler with Dirty Player, adds +1 to the
+/// armour roll if that breaks the armour, and to the injury roll otherwise. Claws break armour on
+/// an unmodified 8+ regardless of AV.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Armor {
     id: PlayerID,
     foul_target: Option<(PlayerID, Sum2D6Target)>,
+    blocker: Option<PlayerID>,
 }
 impl Armor {
     pub fn new(id: PlayerID) -> AnyProc {
         AnyProc::Armor(Armor {
             id,
             foul_target: None,
+            blocker: None,
+        })
+    }
+    pub fn new_block(id: PlayerID, blocker: PlayerID) -> AnyProc {
+        AnyProc::Armor(Armor {
+            id,
+            foul_target: None,
+            blocker: Some(blocker),
         })
     }
     pub fn new_foul(id: PlayerID, target: Sum2D6Target, fouler_id: PlayerID) -> AnyProc {
         AnyProc::Armor(Armor {
             id,
             foul_target: Some((fouler_id, target)),
+            blocker: None,
         })
     }
+
+    fn target(&self, game_state: &GameState) -> Sum2D6Target {
+        match self.foul_target {
+            Some((_, target)) => target,
+            None => game_state.get_player_unsafe(self.id).armor_target(),
+        }
+    }
+
+    /// Claws break armour on an unmodified 8+
+    fn unmodified_target(&self, game_state: &GameState, target: Sum2D6Target) -> Sum2D6Target {
+        match self.blocker {
+            Some(blocker_id)
+                if target > Sum2D6Target::EightPlus
+                    && game_state
+                        .get_player(blocker_id)
+                        .is_ok_and(|blocker| blocker.has_skill(Skill::Claws)) =>
+            {
+                Sum2D6Target::EightPlus
+            }
+            _ => target,
+        }
+    }
+
+    fn modifier(&self, game_state: &GameState) -> i8 {
+        let skilled = match (self.foul_target, self.blocker) {
+            (Some((fouler_id, _)), _) => game_state
+                .get_player_unsafe(fouler_id)
+                .has_skill(Skill::DirtyPlayer),
+            (None, Some(blocker_id)) => game_state
+                .get_player(blocker_id)
+                .is_ok_and(|blocker| blocker.has_skill(Skill::MightyBlow)),
+            (None, None) => false,
+        };
+        i8::from(skilled)
+    }
 }
 impl Procedure for Armor {
     fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
         let mut procs: Vec<AnyProc> = Vec::new();
         let mut injury_proc = Injury::new_pure(self.id);
+        let modifier = self.modifier(game_state);
         let armor_broken = match input {
-            ProcInput::Nothing if self.foul_target.is_some() => {
-                return ProcState::NeedRoll(RequestedRoll::FoulArmor(self.foul_target.unwrap().1));
-            }
             ProcInput::Nothing => {
-                return ProcState::NeedRoll(RequestedRoll::Sum2D6PassFail(
-                    game_state.get_player_unsafe(self.id).armor_target(),
-                ));
+                let target = self.target(game_state);
+                let mut modified_target = target;
+                modified_target.add_modifer(modifier);
+                let unmodified_target = self.unmodified_target(game_state, target);
+                return ProcState::NeedRoll(match self.foul_target {
+                    Some(_) => RequestedRoll::FoulArmor(modified_target, target),
+                    None if modifier == 0 => RequestedRoll::Sum2D6PassFail(unmodified_target),
+                    None => RequestedRoll::Sum2D6ThreeOutcomes(modified_target, unmodified_target),
+                });
             }
-            ProcInput::Roll(RollResult::FoulArmor { broken, ejected }) => {
-                if ejected {
-                    procs.push(Ejection::new(self.foul_target.unwrap().0));
+            ProcInput::Roll(RollResult::FoulArmor {
+                broken,
+                modifier_used,
+                ejected,
+            }) => {
+                let fouler_id = self.foul_target.unwrap().0;
+                let sneaky_git = game_state
+                    .get_player_unsafe(fouler_id)
+                    .has_skill(Skill::SneakyGit);
+                // a sneaky git is not sent off for a double on the armour roll
+                if ejected && !sneaky_git {
+                    procs.push(Ejection::new_foul(fouler_id));
                 } else if broken {
                     // injury proc shall also check of ejection
-                    injury_proc.fouler = Some(self.foul_target.unwrap().0);
+                    injury_proc.fouler = Some(fouler_id);
+                }
+                if !modifier_used {
+                    injury_proc.modifier = modifier;
                 }
                 broken
             }
-            ProcInput::Roll(RollResult::Pass) => true,
+            ProcInput::Roll(RollResult::Pass) => {
+                injury_proc.modifier = modifier;
+                true
+            }
+            ProcInput::Roll(RollResult::MiddleOutcome) => true,
             ProcInput::Roll(RollResult::Fail) => false,
             _ => panic!("Unexpected input"),
         };
@@ -63,17 +134,63 @@ impl Procedure for Armor {
     }
 }
 
+/// Sends a player off. A player caught fouling is a turnover, unless the ref is bribed. Before
+/// that the team may spend a bribe (2+ to stay on the pitch) or argue the call on any ejection,
+/// where a 6 sends the player to the reserves instead and a 1 gets the head coach sent off as
+/// well, with no chance left to bribe the ref.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Ejection {
     id: PlayerID,
+    foul: bool,
+    argued: bool,
+    roll_for: Option<SimpleAT>,
 }
 impl Ejection {
     pub fn new(id: PlayerID) -> AnyProc {
-        AnyProc::Ejection(Ejection { id })
+        AnyProc::Ejection(Ejection {
+            id,
+            foul: false,
+            argued: false,
+            roll_for: None,
+        })
     }
-}
-impl Procedure for Ejection {
-    fn step(&mut self, game_state: &mut GameState, _action: ProcInput) -> ProcState {
+    pub fn new_foul(id: PlayerID) -> AnyProc {
+        AnyProc::Ejection(Ejection {
+            id,
+            foul: true,
+            argued: false,
+            roll_for: None,
+        })
+    }
+
+    fn available_actions(&self, game_state: &GameState) -> Option<Box<AvailableActions>> {
+        let team_type = game_state.get_player_unsafe(self.id).stats.team;
+        let team = game_state.get_team(team_type);
+        let mut aa = AvailableActions::new(team_type);
+        if team.bribes > 0 {
+            aa.insert_simple(SimpleAT::UseBribe);
+        }
+        if !self.argued && !team.coach_ejected {
+            aa.insert_simple(SimpleAT::ArgueTheCall);
+        }
+        if aa.is_empty() {
+            return None;
+        }
+        aa.insert_simple(SimpleAT::Decline);
+        Some(aa)
+    }
+
+    fn decide_or_send_off(&self, game_state: &mut GameState) -> ProcState {
+        match self.available_actions(game_state) {
+            Some(aa) => ProcState::NeedAction(aa),
+            None => self.send_off(game_state, DugoutPlace::Ejected),
+        }
+    }
+
+    fn send_off(&self, game_state: &mut GameState, place: DugoutPlace) -> ProcState {
+        if self.foul {
+            game_state.info.turnover = true;
+        }
         let position = game_state.get_player_unsafe(self.id).position;
         let ret = if matches!(game_state.ball, BallState::Carried(carrier_id) if carrier_id == self.id)
         {
@@ -82,18 +199,61 @@ impl Procedure for Ejection {
         } else {
             ProcState::Done
         };
-        game_state
-            .unfield_player(self.id, DugoutPlace::Ejected)
-            .unwrap();
+        game_state.unfield_player(self.id, place).unwrap();
         ret
     }
 }
+impl Procedure for Ejection {
+    fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
+        match input {
+            ProcInput::Nothing => self.decide_or_send_off(game_state),
+            ProcInput::Action(Action::Simple(SimpleAT::Decline)) => {
+                self.send_off(game_state, DugoutPlace::Ejected)
+            }
+            ProcInput::Action(Action::Simple(SimpleAT::UseBribe)) => {
+                let team = game_state.get_player_unsafe(self.id).stats.team;
+                game_state.get_mut_team(team).bribes -= 1;
+                self.roll_for = Some(SimpleAT::UseBribe);
+                ProcState::NeedRoll(RequestedRoll::D6)
+            }
+            ProcInput::Action(Action::Simple(SimpleAT::ArgueTheCall)) => {
+                self.argued = true;
+                self.roll_for = Some(SimpleAT::ArgueTheCall);
+                ProcState::NeedRoll(RequestedRoll::D6)
+            }
+            ProcInput::Roll(RollResult::D6(roll)) => match (self.roll_for.take(), roll) {
+                (Some(SimpleAT::UseBribe), D6::One) => {
+                    game_state.log("the ref refuses the bribe".to_string());
+                    self.decide_or_send_off(game_state)
+                }
+                (Some(SimpleAT::UseBribe), _) => {
+                    game_state.log("the ref is bribed".to_string());
+                    ProcState::Done
+                }
+                (Some(SimpleAT::ArgueTheCall), D6::Six) => {
+                    game_state.log("the call is overturned".to_string());
+                    self.send_off(game_state, DugoutPlace::Reserves)
+                }
+                (Some(SimpleAT::ArgueTheCall), D6::One) => {
+                    let team = game_state.get_player_unsafe(self.id).stats.team;
+                    game_state.log(format!("the head coach of {:?} is sent off", team));
+                    game_state.get_mut_team(team).coach_ejected = true;
+                    self.send_off(game_state, DugoutPlace::Ejected)
+                }
+                (Some(SimpleAT::ArgueTheCall), _) => self.decide_or_send_off(game_state),
+                _ => panic!("Unexpected roll"),
+            },
+            _ => panic!("Unexpected input"),
+        }
+    }
+}
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Injury {
     id: PlayerID,
     crowd: bool,
     fouler: Option<PlayerID>,
+    modifier: i8,
 }
 impl Injury {
     pub fn new(id: PlayerID) -> AnyProc {
@@ -101,6 +261,7 @@ impl Injury {
             id,
             crowd: false,
             fouler: None,
+            modifier: 0,
         })
     }
 
@@ -109,6 +270,7 @@ impl Injury {
             id,
             crowd: true,
             fouler: None,
+            modifier: 0,
         })
     }
     pub fn new_pure(id: PlayerID) -> Injury {
@@ -116,42 +278,47 @@ impl Injury {
             id,
             crowd: false,
             fouler: None,
+            modifier: 0,
         }
     }
+
+    fn outcome(&self, game_state: &GameState, roll: Sum2D6) -> InjuryOutcome {
+        game_state
+            .get_player_unsafe(self.id)
+            .injury_outcome(roll as i8 + self.modifier)
+    }
 }
 impl Procedure for Injury {
     fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
         let mut procs: Vec<AnyProc> = Vec::new();
-
         let injury_outcome = match input {
             ProcInput::Nothing if self.fouler.is_some() => {
-                return ProcState::NeedRoll(RequestedRoll::FoulInjury(
-                    Sum2D6Target::EightPlus,
-                    Sum2D6Target::TenPlus,
-                ));
-            }
-            ProcInput::Nothing => {
-                return ProcState::NeedRoll(RequestedRoll::Sum2D6ThreeOutcomes(
-                    Sum2D6Target::EightPlus,
-                    Sum2D6Target::TenPlus,
-                ));
+                return ProcState::NeedRoll(RequestedRoll::FoulInjury);
             }
-            ProcInput::Roll(RollResult::FoulInjury { outcome, ejected }) => {
+            ProcInput::Nothing => return ProcState::NeedRoll(RequestedRoll::Sum2D6),
+            ProcInput::Roll(RollResult::FoulInjury { roll, ejected }) => {
                 if ejected {
-                    procs.push(Ejection::new(self.fouler.unwrap()));
+                    procs.push(Ejection::new_foul(self.fouler.unwrap()));
                 }
-                outcome
+                self.outcome(game_state, roll)
             }
-            ProcInput::Roll(RollResult::Fail) => InjuryOutcome::Stunned,
-            ProcInput::Roll(RollResult::MiddleOutcome) => InjuryOutcome::KO,
-            ProcInput::Roll(RollResult::Pass) => InjuryOutcome::Casualty,
-
+            ProcInput::Roll(RollResult::Sum2D6(roll)) => self.outcome(game_state, roll),
             _ => panic!("Unexpected input"),
         };
 
         let dugout_place = match injury_outcome {
-            InjuryOutcome::Casualty => Some(DugoutPlace::Injuried),
-            InjuryOutcome::KO => Some(DugoutPlace::KnockOut),
+            InjuryOutcome::Casualty => {
+                procs.push(Casualty::new(self.id));
+                None
+            }
+            InjuryOutcome::BadlyHurt => {
+                procs.push(Casualty::new_badly_hurt(self.id));
+                None
+            }
+            InjuryOutcome::KO => {
+                procs.push(KnockOut::new(self.id));
+                None
+            }
             InjuryOutcome::Stunned if self.crowd => Some(DugoutPlace::Reserves),
             InjuryOutcome::Stunned => {
                 game_state.get_mut_player_unsafe(self.id).status = PlayerStatus::Stunned;
@@ -166,10 +333,172 @@ impl Procedure for Injury {
     }
 }
 
+/// Lets the team of the player decide to use the apothecary, if it has one left
+fn ask_for_apothecary(game_state: &GameState, id: PlayerID) -> Option<ProcState> {
+    let team = game_state.get_player_unsafe(id).stats.team;
+    if game_state.get_team(team).apothecaries == 0 {
+        return None;
+    }
+    let mut aa = AvailableActions::new(team);
+    aa.insert_simple(SimpleAT::UseApothecary);
+    aa.insert_simple(SimpleAT::Decline);
+    Some(ProcState::NeedAction(aa))
+}
+
+fn use_apothecary(game_state: &mut GameState, id: PlayerID) {
+    let team = game_state.get_player_unsafe(id).stats.team;
+    game_state.get_mut_team(team).apothecaries -= 1;
+    game_state.log(format!("{:?} uses the apothecary", team));
+}
+
+/// A knocked out player is patched up and goes to the reserves if the apothecary is used
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct KnockOut {
+    id: PlayerID,
+}
+impl KnockOut {
+    pub fn new(id: PlayerID) -> AnyProc {
+        AnyProc::KnockOut(KnockOut { id })
+    }
+}
+impl Procedure for KnockOut {
+    fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
+        let place = match input {
+            ProcInput::Nothing => match ask_for_apothecary(game_state, self.id) {
+                Some(state) => return state,
+                None => DugoutPlace::KnockOut,
+            },
+            ProcInput::Action(Action::Simple(SimpleAT::UseApothecary)) => {
+                use_apothecary(game_state, self.id);
+                DugoutPlace::Reserves
+            }
+            ProcInput::Action(Action::Simple(SimpleAT::Decline)) => DugoutPlace::KnockOut,
+            _ => panic!("Unexpected input"),
+        };
+        game_state.unfield_player(self.id, place).unwrap();
+        ProcState::Done
+    }
+}
+
+/// Rolls on the casualty table and records the outcome on the player in the dugout. With the
+/// apothecary the table is rolled again and the coach keeps one of the two outcomes. After that a
+/// player with Regeneration rolls 4+ to avoid the casualty and go to the reserves.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Casualty {
+    id: PlayerID,
+    outcomes: Vec<CasualtyOutcome>,
+    badly_hurt: bool,
+    /// The outcome kept, waiting for the Regeneration roll
+    regenerating: Option<CasualtyOutcome>,
+}
+impl Casualty {
+    pub fn new(id: PlayerID) -> AnyProc {
+        AnyProc::Casualty(Casualty {
+            id,
+            outcomes: Vec::new(),
+            badly_hurt: false,
+            regenerating: None,
+        })
+    }
+
+    /// The first outcome is Badly Hurt instead of a roll on the casualty table
+    pub fn new_badly_hurt(id: PlayerID) -> AnyProc {
+        AnyProc::Casualty(Casualty {
+            id,
+            outcomes: Vec::new(),
+            badly_hurt: true,
+            regenerating: None,
+        })
+    }
+
+    fn apply(&mut self, game_state: &mut GameState, outcome: CasualtyOutcome) -> ProcState {
+        game_state.log(format!("casualty: {:?}", outcome));
+        let patched_up = self.outcomes.len() == 2 && outcome == CasualtyOutcome::BadlyHurt;
+        if patched_up {
+            game_state
+                .unfield_player(self.id, DugoutPlace::Reserves)
+                .unwrap();
+            return ProcState::Done;
+        }
+        if game_state
+            .get_player_unsafe(self.id)
+            .has_skill(Skill::Regeneration)
+        {
+            self.regenerating = Some(outcome);
+            return ProcState::NeedRoll(RequestedRoll::D6PassFail(D6Target::FourPlus));
+        }
+        self.injure(game_state, outcome)
+    }
+
+    fn injure(&self, game_state: &mut GameState, outcome: CasualtyOutcome) -> ProcState {
+        let dugout_id = game_state
+            .unfield_player(self.id, DugoutPlace::Injuried)
+            .unwrap();
+        game_state
+            .get_dugout_player_mut(dugout_id)
+            .unwrap()
+            .casualty = Some(outcome);
+        ProcState::Done
+    }
+}
+impl Procedure for Casualty {
+    fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
+        let outcome = match input {
+            ProcInput::Roll(RollResult::Pass) => {
+                game_state.log("the player regenerates".to_string());
+                game_state
+                    .unfield_player(self.id, DugoutPlace::Reserves)
+                    .unwrap();
+                return ProcState::Done;
+            }
+            ProcInput::Roll(RollResult::Fail) => {
+                return self.injure(game_state, self.regenerating.unwrap());
+            }
+            ProcInput::Nothing if self.badly_hurt => CasualtyOutcome::BadlyHurt,
+            ProcInput::Nothing => return ProcState::NeedRoll(RequestedRoll::D16),
+            ProcInput::Roll(RollResult::D16(roll)) => match CasualtyOutcome::from(roll) {
+                CasualtyOutcome::LastingInjury(None) => {
+                    return ProcState::NeedRoll(RequestedRoll::D6);
+                }
+                outcome => outcome,
+            },
+            ProcInput::Roll(RollResult::D6(roll)) => {
+                CasualtyOutcome::LastingInjury(Some(LastingInjury::from(roll)))
+            }
+            ProcInput::Action(Action::Simple(SimpleAT::UseApothecary)) => {
+                use_apothecary(game_state, self.id);
+                return ProcState::NeedRoll(RequestedRoll::D16);
+            }
+            ProcInput::Action(Action::Simple(SimpleAT::Decline))
+            | ProcInput::Action(Action::Simple(SimpleAT::SelectFirstCasualty)) => {
+                return self.apply(game_state, self.outcomes[0]);
+            }
+            ProcInput::Action(Action::Simple(SimpleAT::SelectSecondCasualty)) => {
+                return self.apply(game_state, self.outcomes[1]);
+            }
+            _ => panic!("Unexpected input"),
+        };
+        self.outcomes.push(outcome);
+
+        if self.outcomes.len() == 2 {
+            let team = game_state.get_player_unsafe(self.id).stats.team;
+            let mut aa = AvailableActions::new(team);
+            aa.insert_simple(SimpleAT::SelectFirstCasualty);
+            aa.insert_simple(SimpleAT::SelectSecondCasualty);
+            return ProcState::NeedAction(aa);
+        }
+        match ask_for_apothecary(game_state, self.id) {
+            Some(state) => state,
+            None => self.apply(game_state, outcome),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
-    use crate::core::dices::D8;
+    use crate::core::dices::{BlockDice, D8};
+    use crate::core::gamestate::GameState;
     use crate::core::model::*;
     use crate::core::table::*;
     use crate::core::{gamestate::GameStateBuilder, model::Position, table::PosAT};
@@ -198,7 +527,7 @@ mod tests {
         state.fixes.fix_d6(5); //armor
         state.fixes.fix_d8(d8_fix as u8);
 
-        state.step_simple(SimpleAT::DontUseReroll);
+        state.step_simple(SimpleAT::Decline);
 
         let player = state.get_player_unsafe(id);
         assert!(player.used);
@@ -227,6 +556,7 @@ mod tests {
         state.fixes.fix_d6(1); //injury
 
         state.step_positional(PosAT::Foul, foul_pos);
+        state.step_simple(SimpleAT::Decline);
 
         assert!(matches!(
             state.get_dugout().next(),
@@ -260,6 +590,7 @@ mod tests {
         state.fixes.fix_d6(2); //injury
 
         state.step_positional(PosAT::Foul, foul_pos);
+        state.step_simple(SimpleAT::Decline);
 
         assert!(matches!(
             state.get_dugout().next(),
@@ -273,4 +604,360 @@ mod tests {
             })
         ));
     }
+
+    /// Fouls a prone away player with armor broken and the given injury roll, away has an
+    /// apothecary
+    fn foul_injury(injury: (u8, u8), fix_rolls: impl FnOnce(&mut GameState)) -> GameState {
+        let start_pos = Position::new((5, 5));
+        let foul_pos = start_pos + (1, 0);
+        let mut state = GameStateBuilder::new()
+            .add_home_player(start_pos)
+            .add_away_player(foul_pos)
+            .build();
+        state.away.apothecaries = 1;
+
+        let victim_id = state.get_player_id_at(foul_pos).unwrap();
+        state.get_mut_player_unsafe(victim_id).status = PlayerStatus::Down;
+        state.step_positional(PosAT::StartFoul, start_pos);
+
+        state.fixes.fix_d6(5); //armor
+        state.fixes.fix_d6(6); //armor
+        state.fixes.fix_d6(injury.0);
+        state.fixes.fix_d6(injury.1);
+        fix_rolls(&mut state);
+        state.step_positional(PosAT::Foul, foul_pos);
+        state
+    }
+
+    fn foul_to_casualty(fix_rolls: impl FnOnce(&mut GameState)) -> GameState {
+        let mut state = foul_injury((4, 6), fix_rolls);
+        state.step_simple(SimpleAT::Decline);
+
+        assert_eq!(state.get_players_on_pitch().count(), 1);
+        assert!(state.fixes.is_empty());
+        state
+    }
+
+    #[test]
+    fn casualty_table() {
+        let state = foul_to_casualty(|state| state.fixes.fix_d16(9));
+        let dplayer = state.get_dugout().next().unwrap();
+        assert_eq!(dplayer.place, DugoutPlace::Injuried);
+        assert_eq!(dplayer.stats.team, TeamType::Away);
+        assert_eq!(dplayer.casualty, Some(CasualtyOutcome::SeriouslyHurt));
+    }
+
+    #[test]
+    fn lasting_injury() {
+        let state = foul_to_casualty(|state| {
+            state.fixes.fix_d16(13);
+            state.fixes.fix_d6(4);
+        });
+        let injury = LastingInjury::BrokenArm;
+        let dplayer = state.get_dugout().next().unwrap();
+        assert_eq!(
+            dplayer.casualty,
+            Some(CasualtyOutcome::LastingInjury(Some(injury)))
+        );
+        assert_eq!(injury.characteristic(), Characteristic::PA);
+    }
+
+    #[test]
+    fn apothecary_on_ko() {
+        let mut state = foul_injury((4, 5), |_| ());
+        assert_eq!(state.get_available_actions().team, Some(TeamType::Away));
+        state.step_simple(SimpleAT::UseApothecary);
+
+        let dplayer = state.get_dugout().next().unwrap();
+        assert_eq!(dplayer.place, DugoutPlace::Reserves);
+        assert_eq!(state.get_team(TeamType::Away).apothecaries, 0);
+    }
+
+    #[test]
+    fn apothecary_on_casualty() {
+        let mut state = foul_injury((4, 6), |state| state.fixes.fix_d16(16));
+        state.fixes.fix_d16(14);
+        state.fixes.fix_d6(6);
+        state.step_simple(SimpleAT::UseApothecary);
+        assert!(state.get_dugout().next().is_none());
+
+        state.step_simple(SimpleAT::SelectSecondCasualty);
+        let dplayer = state.get_dugout().next().unwrap();
+        assert_eq!(dplayer.place, DugoutPlace::Injuried);
+        assert_eq!(
+            dplayer.casualty,
+            Some(CasualtyOutcome::LastingInjury(Some(
+                LastingInjury::DislocatedShoulder
+            )))
+        );
+    }
+
+    #[test]
+    fn apothecary_patches_up_badly_hurt() {
+        let mut state = foul_injury((4, 6), |state| state.fixes.fix_d16(16));
+        state.fixes.fix_d16(1);
+        state.step_simple(SimpleAT::UseApothecary);
+        state.step_simple(SimpleAT::SelectSecondCasualty);
+
+        let dplayer = state.get_dugout().next().unwrap();
+        assert_eq!(dplayer.place, DugoutPlace::Reserves);
+        assert_eq!(dplayer.casualty, None);
+    }
+
+    #[test]
+    fn no_apothecary_left() {
+        let state = foul_injury((4, 5), |state| {
+            state.get_mut_team(TeamType::Away).apothecaries = 0;
+        });
+        assert!(!state.is_legal_action(&Action::Simple(SimpleAT::UseApothecary)));
+        assert_eq!(
+            state.get_dugout().next().unwrap().place,
+            DugoutPlace::KnockOut
+        );
+    }
+
+    /// Fouls with doubles on the armor roll, the fouler is caught by the ref
+    fn foul_caught(bribes: u8) -> GameState {
+        let start_pos = Position::new((5, 5));
+        let mut state = GameStateBuilder::new()
+            .add_home_player(start_pos)
+            .add_away_player(start_pos + (1, 0))
+            .build();
+        state.home.bribes = bribes;
+        let victim_id = state.get_player_id_at(start_pos + (1, 0)).unwrap();
+        state.get_mut_player_unsafe(victim_id).status = PlayerStatus::Down;
+
+        state.step_positional(PosAT::StartFoul, start_pos);
+        state.fixes.fix_d6(2); //armor
+        state.fixes.fix_d6(2); //armor
+        state.step_positional(PosAT::Foul, start_pos + (1, 0));
+        state
+    }
+
+    #[test]
+    fn bribe_the_ref() {
+        let mut state = foul_caught(1);
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::UseBribe)));
+        state.fixes.fix_d6(2);
+        state.step_simple(SimpleAT::UseBribe);
+
+        assert_eq!(state.home.bribes, 0);
+        assert_eq!(
+            state.get_players_on_pitch_in_team(TeamType::Home).count(),
+            1
+        );
+        assert!(state.get_dugout().next().is_none());
+        assert!(!state.info.turnover);
+    }
+
+    #[test]
+    fn argue_the_call() {
+        let mut state = foul_caught(0);
+        state.fixes.fix_d6(6);
+        state.step_simple(SimpleAT::ArgueTheCall);
+
+        let dplayer = state.get_dugout().next().unwrap();
+        assert_eq!(dplayer.place, DugoutPlace::Reserves);
+        assert!(!state.home.coach_ejected);
+        assert_eq!(state.info.team_turn, TeamType::Away);
+    }
+
+    #[test]
+    fn coach_sent_off_when_arguing() {
+        let mut state = foul_caught(1);
+        state.fixes.fix_d6(1);
+        state.step_simple(SimpleAT::ArgueTheCall);
+        assert!(state.home.coach_ejected);
+        assert_eq!(state.home.bribes, 1);
+
+        let dplayer = state.get_dugout().next().unwrap();
+        assert_eq!(dplayer.place, DugoutPlace::Ejected);
+        assert_eq!(state.info.team_turn, TeamType::Away);
+    }
+
+    /// Knocks down an away player with a pow, the blocker has the given skills and away has an
+    /// apothecary
+    fn block_knockdown(skills: &[Skill], fix_rolls: impl FnOnce(&mut GameState)) -> GameState {
+        let home_pos = Position::new((5, 5));
+        let away_pos = Position::new((6, 5));
+        let mut state = GameStateBuilder::new()
+            .add_home_player(home_pos)
+            .add_away_player(away_pos)
+            .build();
+        state.away.apothecaries = 1;
+        let id = state.get_player_id_at(home_pos).unwrap();
+        for skill in skills {
+            state.get_mut_player_unsafe(id).stats.give_skill(*skill);
+        }
+
+        state.step_positional(PosAT::StartBlock, home_pos);
+        state.fixes.fix_blockdice(BlockDice::Pow);
+        state.step_positional(PosAT::Block, away_pos);
+        state.step_simple(SimpleAT::SelectPow);
+        state.step_positional(PosAT::Push, away_pos + (1, 0));
+        fix_rolls(&mut state);
+        state.step_positional(PosAT::FollowUp, home_pos);
+        assert!(state.fixes.is_empty());
+        state
+    }
+
+    #[test]
+    fn mighty_blow_and_claws() {
+        let pushed_to = Position::new((7, 5));
+        let state = block_knockdown(&[Skill::MightyBlow, Skill::Claws], |state| {
+            state.fixes.fix_d6(3); //armor, claws need an unmodified 8
+            state.fixes.fix_d6(4);
+        });
+        assert_eq!(
+            state.get_player_at(pushed_to).unwrap().status,
+            PlayerStatus::Down
+        );
+
+        let state = block_knockdown(&[Skill::MightyBlow, Skill::Claws], |state| {
+            state.fixes.fix_d6(4); //armor, broken by claws
+            state.fixes.fix_d6(4);
+            state.fixes.fix_d6(4); //injury, 7 + 1 is knocked out
+            state.fixes.fix_d6(3);
+        });
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::UseApothecary)));
+
+        let state = block_knockdown(&[Skill::MightyBlow], |state| {
+            state.fixes.fix_d6(6); //armor
+            state.fixes.fix_d6(6);
+            state.fixes.fix_d6(4); //injury, 7 + 1 is knocked out
+            state.fixes.fix_d6(3);
+        });
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::UseApothecary)));
+
+        let state = block_knockdown(&[Skill::Claws], |state| {
+            state.fixes.fix_d6(3); //armor
+            state.fixes.fix_d6(4);
+        });
+        assert_eq!(
+            state.get_player_at(pushed_to).unwrap().status,
+            PlayerStatus::Down
+        );
+    }
+
+    fn give_victim_skill(state: &mut GameState, skill: Skill) {
+        let id = state.get_player_id_at(Position::new((6, 5))).unwrap();
+        state.get_mut_player_unsafe(id).stats.give_skill(skill);
+    }
+
+    #[test]
+    fn thick_skull_and_stunty() {
+        let state = foul_injury((4, 4), |state| give_victim_skill(state, Skill::ThickSkull));
+        let victim = state.get_player_at(Position::new((6, 5))).unwrap();
+        assert_eq!(victim.status, PlayerStatus::Stunned);
+
+        let state = foul_injury((3, 4), |state| give_victim_skill(state, Skill::Stunty));
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::UseApothecary)));
+
+        let state = foul_to_casualty(|state| {
+            give_victim_skill(state, Skill::Stunty);
+            give_victim_skill(state, Skill::ThickSkull);
+            state.fixes.fix_d16(1);
+        });
+        let dplayer = state.get_dugout().next().unwrap();
+        assert_eq!(dplayer.casualty, Some(CasualtyOutcome::BadlyHurt));
+    }
+
+    #[test]
+    fn stunty_badly_hurt_on_nine() {
+        let mut state = foul_injury((4, 5), |state| give_victim_skill(state, Skill::Stunty));
+        state.step_simple(SimpleAT::Decline);
+
+        let dplayer = state.get_dugout().next().unwrap();
+        assert_eq!(dplayer.place, DugoutPlace::Injuried);
+        assert_eq!(dplayer.casualty, Some(CasualtyOutcome::BadlyHurt));
+    }
+
+    #[test]
+    fn dirty_player() {
+        let state = foul_injury((4, 3), |state| {
+            let id = state.get_player_id_at(Position::new((5, 5))).unwrap();
+            state
+                .get_mut_player_unsafe(id)
+                .stats
+                .give_skill(Skill::DirtyPlayer);
+        });
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::UseApothecary)));
+    }
+
+    #[test]
+    fn sneaky_git() {
+        let start_pos = Position::new((5, 5));
+        let mut state = GameStateBuilder::new()
+            .add_home_player(start_pos)
+            .add_away_player(start_pos + (1, 0))
+            .build();
+        let fouler_id = state.get_player_id_at(start_pos).unwrap();
+        state
+            .get_mut_player_unsafe(fouler_id)
+            .stats
+            .give_skill(Skill::SneakyGit);
+        let victim_id = state.get_player_id_at(start_pos + (1, 0)).unwrap();
+        state.get_mut_player_unsafe(victim_id).status = PlayerStatus::Down;
+
+        state.step_positional(PosAT::StartFoul, start_pos);
+        state.fixes.fix_d6(5); //armor, broken with a double
+        state.fixes.fix_d6(5);
+        state.fixes.fix_d6(3); //injury
+        state.fixes.fix_d6(4);
+        state.step_positional(PosAT::Foul, start_pos + (1, 0));
+
+        assert!(state.get_dugout().next().is_none());
+        assert_eq!(
+            state.get_player_unsafe(victim_id).status,
+            PlayerStatus::Stunned
+        );
+        assert!(!state.info.turnover);
+        assert_eq!(state.info.team_turn, TeamType::Home);
+
+        // the fouler may move on but not foul again
+        assert!(!state.get_player_unsafe(fouler_id).used);
+        assert!(state.is_legal_action(&Action::Positional(PosAT::Move, start_pos + (-1, 0))));
+        assert!(!state.is_legal_action(&Action::Positional(PosAT::Foul, start_pos + (1, 0))));
+    }
+
+    #[test]
+    fn regeneration() {
+        for (roll, regenerated) in [(4, true), (3, false)] {
+            let mut state = foul_injury((4, 6), |state| {
+                give_victim_skill(state, Skill::Regeneration);
+                state.fixes.fix_d16(9);
+            });
+            state.fixes.fix_d6(roll);
+            state.step_simple(SimpleAT::Decline);
+
+            let dplayer = state.get_dugout().next().unwrap();
+            if regenerated {
+                assert_eq!(dplayer.place, DugoutPlace::Reserves);
+                assert_eq!(dplayer.casualty, None);
+            } else {
+                assert_eq!(dplayer.place, DugoutPlace::Injuried);
+                assert_eq!(dplayer.casualty, Some(CasualtyOutcome::SeriouslyHurt));
+            }
+        }
+    }
+
+    #[test]
+    fn regeneration_after_casualty_roll_and_apothecary() {
+        let mut state = foul_injury((4, 6), |state| {
+            give_victim_skill(state, Skill::Regeneration);
+            state.fixes.fix_d16(9);
+        });
+        // the casualty roll and the apothecary come before the regeneration roll
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::UseApothecary)));
+        state.fixes.fix_d16(15);
+        state.step_simple(SimpleAT::UseApothecary);
+        state.fixes.fix_d6(4);
+        state.step_simple(SimpleAT::SelectFirstCasualty);
+
+        assert!(state.fixes.is_empty());
+        let dplayer = state.get_dugout().next().unwrap();
+        assert_eq!(dplayer.place, DugoutPlace::Reserves);
+        assert_eq!(dplayer.casualty, None);
+        assert_eq!(state.away.apothecaries, 0);
+    }
 }
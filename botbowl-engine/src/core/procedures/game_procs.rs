@@ -1,12 +1,15 @@
+use std::cmp::Ordering;
+
 use serde::{Deserialize, Serialize};
 
 use crate::core::model::{DugoutPlayerID, ProcInput};
 
-use crate::core::dices::{RequestedRoll, RollResult};
+use crate::core::dices::{RequestedRoll, RollResult, D3};
 use crate::core::model::{
-    other_team, Action, AvailableActions, BallState, DugoutPlace, PlayerStatus, Position,
-    ProcState, Procedure, TeamType,
+    other_team, Action, AvailableActions, BallState, DugoutPlace, FieldedPlayer, PlayerID,
+    PlayerStatus, Position, ProcState, Procedure, TeamState, TeamType, Weather,
 };
+use crate::core::procedures::procedure_tools::{SimpleProc, SimpleProcContainer};
 use crate::core::procedures::{ball_procs, block_procs, kickoff_procs, movement_procs};
 use crate::core::table::*;
 
@@ -31,6 +34,19 @@ impl Half {
             kickoff: None,
         })
     }
+    /// A team with a player with Leader, on the pitch or in reserves, gets a Leader re-roll
+    fn has_leader(game_state: &GameState, team: TeamType) -> bool {
+        game_state
+            .get_players_on_pitch_in_team(team)
+            .map(|p| &p.stats)
+            .chain(
+                game_state
+                    .get_dugout()
+                    .filter(|p| p.stats.team == team && p.place == DugoutPlace::Reserves)
+                    .map(|p| &p.stats),
+            )
+            .any(|stats| stats.has_skill(Skill::Leader))
+    }
     fn do_kickoff(&mut self, kicking_team: TeamType, game_state: &mut GameState) -> ProcState {
         //SCORING IN THE OPPONENT’S TURN
         // In some rare cases a team will score a touchdown in the
@@ -43,16 +59,29 @@ impl Half {
         // celebrating this unusual method of scoring!
 
         game_state.info.kicking_this_drive = kicking_team;
+        game_state.home.end_drive();
+        game_state.away.end_drive();
+
+        // players that collapsed in the heat have sat out a drive
+        game_state
+            .get_dugout_mut()
+            .filter(|player| player.place == DugoutPlace::Heated)
+            .for_each(|player| player.place = DugoutPlace::Reserves);
 
-        let procs: Vec<AnyProc> = vec![
+        let mut procs: Vec<AnyProc> = vec![
             kickoff_procs::Kickoff::new(),
+            PrayerBlessings::new(),
             kickoff_procs::Setup::new(kicking_team),
             kickoff_procs::Setup::new(other_team(kicking_team)),
             KOWakeUp::new(),
         ];
 
         game_state.ball = BallState::OffPitch;
-        game_state.unfield_all_players().unwrap();
+        if game_state.info.weather == Weather::Sweltering {
+            procs.push(SwelteringHeat::new());
+        } else {
+            game_state.unfield_all_players().unwrap();
+        }
 
         ProcState::NotDoneNewProcs(procs)
     }
@@ -60,9 +89,16 @@ impl Half {
 
 impl Procedure for Half {
     fn step(&mut self, game_state: &mut GameState, _input: ProcInput) -> ProcState {
-        let info = &mut game_state.info;
         if !self.started {
             self.started = true;
+            if self.half > 1 {
+                game_state.end_prayers(EffectDuration::Half);
+            }
+            for team in [TeamType::Home, TeamType::Away] {
+                let leader_reroll = Half::has_leader(game_state, team);
+                game_state.get_mut_team(team).start_half(leader_reroll);
+            }
+            let info = &mut game_state.info;
             info.half = self.half;
             info.home_turn = 0;
             info.away_turn = 0;
@@ -75,8 +111,12 @@ impl Procedure for Half {
             };
             self.kickoff = Some(self.kicking_this_half);
         } else {
-            self.kickoff = info.kickoff_by_team.take();
+            self.kickoff = game_state.info.kickoff_by_team.take();
+            if self.kickoff.is_some() {
+                game_state.end_prayers(EffectDuration::Drive);
+            }
         }
+        let info = &mut game_state.info;
 
         if info.home_turn == 8 && info.away_turn == 8 {
             return ProcState::Done;
@@ -103,11 +143,9 @@ impl Procedure for Half {
         info.blitz_available = true;
         info.foul_available = true;
         info.pass_available = true;
+        info.throw_team_mate_available = true;
         info.turnover = false;
-
-        game_state
-            .get_players_on_pitch_mut()
-            .for_each(|p| p.used = false);
+        game_state.get_mut_team(next_team).start_turn();
 
         game_state
             .get_players_on_pitch_mut()
@@ -152,14 +190,17 @@ impl Turn {
 
         let positions: Vec<Position> = game_state
             .get_players_on_pitch_in_team(self.team)
-            .filter(|p| !p.used)
+            .filter(|p| !p.used && p.status != PlayerStatus::Stunned)
             .map(|p| p.position)
             .collect();
 
         if !positions.is_empty() {
             let block_positions: Vec<Position> = positions
                 .iter()
-                .filter(|&&pos| game_state.get_player_at(pos).unwrap().status == PlayerStatus::Up)
+                .filter(|&&pos| {
+                    let player = game_state.get_player_at(pos).unwrap();
+                    player.status == PlayerStatus::Up || player.has_skill(Skill::JumpUp)
+                })
                 .filter(|&&pos| {
                     game_state.get_adj_players(pos).any(|adj_player| {
                         adj_player.status == PlayerStatus::Up && adj_player.stats.team != self.team
@@ -186,6 +227,20 @@ impl Turn {
                 aa.insert_positional(PosAT::StartPass, positions.clone());
             }
 
+            let throw_positions: Vec<Position> = positions
+                .iter()
+                .filter(|&&pos| {
+                    game_state
+                        .get_player_at(pos)
+                        .unwrap()
+                        .has_skill(Skill::ThrowTeamMate)
+                })
+                .copied()
+                .collect();
+            if game_state.info.throw_team_mate_available && !throw_positions.is_empty() {
+                aa.insert_positional(PosAT::StartThrowTeamMate, throw_positions);
+            }
+
             aa.insert_positional(PosAT::StartMove, positions);
         }
         aa.insert_simple(SimpleAT::EndTurn);
@@ -211,21 +266,26 @@ impl Procedure for Turn {
         }
 
         if let ProcInput::Action(Action::Positional(at, position)) = input {
-            game_state.set_active_player(game_state.get_player_id_at(position).unwrap());
+            let id = game_state.get_player_id_at(position).unwrap();
+            game_state.set_active_player(id);
             let info = &mut game_state.info;
             info.player_action_type = Some(at);
             match at {
-                PosAT::StartMove => (),
+                PosAT::StartMove | PosAT::StartBlock => (),
                 PosAT::StartHandoff => info.handoff_available = false,
                 PosAT::StartPass => info.pass_available = false,
+                PosAT::StartThrowTeamMate => info.throw_team_mate_available = false,
                 PosAT::StartFoul => info.foul_available = false,
                 PosAT::StartBlitz => info.blitz_available = false,
-                PosAT::StartBlock => {
-                    return ProcState::NotDoneNew(block_procs::BlockAction::new());
-                }
                 _ => unreachable!(),
             }
-            ProcState::NotDoneNew(movement_procs::MoveAction::new(info.active_player.unwrap()))
+            let player = game_state.get_mut_player_unsafe(id);
+            player.lost_tackle_zones = false;
+            if ActivationRoll::negative_trait(player).is_some() {
+                ProcState::NotDoneNew(ActivationRoll::new(game_state, id, at))
+            } else {
+                ProcState::NotDoneNew(action_proc(id, at))
+            }
         } else if let ProcInput::Action(Action::Simple(SimpleAT::EndTurn)) = input {
             ProcState::Done
         } else {
@@ -234,6 +294,140 @@ impl Procedure for Turn {
     }
 }
 
+fn action_proc(id: PlayerID, action: PosAT) -> AnyProc {
+    match action {
+        PosAT::StartBlock => block_procs::BlockAction::new(),
+        _ => movement_procs::MoveAction::new(id),
+    }
+}
+
+/// Rolls for the negative trait of a player before the declared action. Failing ends the
+/// activation, and a Bone Head or Really Stupid player also loses their tackle zones until
+/// activated again. A player failing Animal Savagery instead knocks down an adjacent standing
+/// team-mate and carries on, or ends the activation if there is no one to lash out at.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ActivationRoll {
+    id: PlayerID,
+    action: PosAT,
+    target: D6Target,
+}
+impl ActivationRoll {
+    pub fn new(game_state: &GameState, id: PlayerID, action: PosAT) -> AnyProc {
+        AnyProc::ActivationRoll(SimpleProcContainer::new(ActivationRoll {
+            id,
+            action,
+            target: ActivationRoll::target(game_state, id, action),
+        }))
+    }
+
+    pub fn negative_trait(player: &FieldedPlayer) -> Option<Skill> {
+        [
+            Skill::BoneHead,
+            Skill::ReallyStupid,
+            Skill::WildAnimal,
+            Skill::UnchannelledFury,
+            Skill::AnimalSavagery,
+        ]
+        .into_iter()
+        .find(|skill| player.has_skill(*skill))
+    }
+
+    fn target(game_state: &GameState, id: PlayerID, action: PosAT) -> D6Target {
+        let player = game_state.get_player_unsafe(id);
+        let helped = match ActivationRoll::negative_trait(player).unwrap() {
+            Skill::BoneHead => return D6Target::TwoPlus,
+            Skill::ReallyStupid => game_state.get_adj_players(player.position).any(|adj| {
+                adj.stats.team == player.stats.team
+                    && adj.status == PlayerStatus::Up
+                    && !adj.has_skill(Skill::ReallyStupid)
+            }),
+            _ => matches!(action, PosAT::StartBlock | PosAT::StartBlitz),
+        };
+        if helped {
+            D6Target::TwoPlus
+        } else {
+            D6Target::FourPlus
+        }
+    }
+}
+impl SimpleProc for ActivationRoll {
+    fn d6_target(&self) -> D6Target {
+        self.target
+    }
+
+    fn reroll_skill(&self) -> Option<Skill> {
+        None
+    }
+
+    fn apply_success(&self, _game_state: &mut GameState) -> Vec<AnyProc> {
+        vec![action_proc(self.id, self.action)]
+    }
+
+    fn apply_failure(&mut self, game_state: &mut GameState) -> Vec<AnyProc> {
+        let player = game_state.get_player_unsafe(self.id);
+        let negative_trait = ActivationRoll::negative_trait(player).unwrap();
+        game_state.log(format!("{:?} roll failed", negative_trait));
+        if negative_trait == Skill::AnimalSavagery
+            && !LashOut::team_mates(game_state, self.id).is_empty()
+        {
+            return vec![LashOut::new(self.id, self.action)];
+        }
+        let player = game_state.get_mut_player_unsafe(self.id);
+        player.used = true;
+        player.lost_tackle_zones = matches!(negative_trait, Skill::BoneHead | Skill::ReallyStupid);
+        Vec::new()
+    }
+
+    fn player_id(&self) -> PlayerID {
+        self.id
+    }
+}
+
+/// A player failing Animal Savagery knocks down an adjacent standing team-mate chosen by the
+/// coach, then carries on with the action
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LashOut {
+    id: PlayerID,
+    action: PosAT,
+}
+impl LashOut {
+    pub fn new(id: PlayerID, action: PosAT) -> AnyProc {
+        AnyProc::LashOut(LashOut { id, action })
+    }
+
+    fn team_mates(game_state: &GameState, id: PlayerID) -> Vec<Position> {
+        let player = game_state.get_player_unsafe(id);
+        game_state
+            .get_adj_players(player.position)
+            .filter(|adj| adj.stats.team == player.stats.team && adj.status == PlayerStatus::Up)
+            .map(|adj| adj.position)
+            .collect()
+    }
+}
+impl Procedure for LashOut {
+    fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
+        match input {
+            ProcInput::Nothing => {
+                let team = game_state.get_player_unsafe(self.id).stats.team;
+                let mut aa = AvailableActions::new(team);
+                aa.insert_positional(
+                    PosAT::SelectPosition,
+                    LashOut::team_mates(game_state, self.id),
+                );
+                ProcState::NeedAction(aa)
+            }
+            ProcInput::Action(Action::Positional(PosAT::SelectPosition, position)) => {
+                let team_mate_id = game_state.get_player_id_at(position).unwrap();
+                ProcState::DoneNewProcs(vec![
+                    action_proc(self.id, self.action),
+                    block_procs::KnockDown::new_placed_prone(team_mate_id),
+                ])
+            }
+            _ => panic!("Unexpected input {:?}", input),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct GameOver;
 impl GameOver {
@@ -244,15 +438,15 @@ impl GameOver {
 impl Procedure for GameOver {
     fn step(&mut self, game_state: &mut GameState, _input: ProcInput) -> ProcState {
         game_state.info.winner = match game_state.home.score.cmp(&game_state.away.score) {
-            std::cmp::Ordering::Less => Some(TeamType::Away),
-            std::cmp::Ordering::Equal => None,
-            std::cmp::Ordering::Greater => Some(TeamType::Home),
+            Ordering::Less => Some(TeamType::Away),
+            Ordering::Equal => None,
+            Ordering::Greater => Some(TeamType::Home),
         };
         game_state.info.game_over = true;
 
         let mut aa = AvailableActions::new(TeamType::Home);
         aa.insert_simple(SimpleAT::EndSetup);
-        aa.insert_simple(SimpleAT::DontUseReroll);
+        aa.insert_simple(SimpleAT::Decline);
         ProcState::NeedAction(aa)
     }
 }
@@ -295,6 +489,436 @@ impl Procedure for KOWakeUp {
         }
     }
 }
+/// Ends the drive in sweltering heat. D3 random players from each team collapse and miss the
+/// next drive, then the rest leave the pitch as usual.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SwelteringHeat {
+    teams: Vec<TeamType>,
+    team: Option<TeamType>,
+}
+impl SwelteringHeat {
+    pub fn new() -> AnyProc {
+        AnyProc::SwelteringHeat(SwelteringHeat {
+            teams: vec![TeamType::Away, TeamType::Home],
+            team: None,
+        })
+    }
+    fn next_team(&mut self, game_state: &mut GameState) -> ProcState {
+        while let Some(team) = self.teams.pop() {
+            if game_state.get_players_on_pitch_in_team(team).count() > 0 {
+                self.team = Some(team);
+                return ProcState::NeedRoll(RequestedRoll::D3);
+            }
+        }
+        game_state.unfield_all_players().unwrap();
+        ProcState::Done
+    }
+}
+impl Procedure for SwelteringHeat {
+    fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
+        match input {
+            ProcInput::Nothing => self.next_team(game_state),
+            ProcInput::Roll(RollResult::D3(roll)) => {
+                ProcState::NeedRoll(RequestedRoll::RandomPlayers(self.team.unwrap(), roll as u8))
+            }
+            ProcInput::Roll(RollResult::RandomPlayers(ids)) => {
+                for id in ids.into_iter().flatten() {
+                    game_state.unfield_player(id, DugoutPlace::Heated).unwrap();
+                }
+                self.next_team(game_state)
+            }
+            _ => panic!("Unexpected input {:?}", input),
+        }
+    }
+}
+/// The team with the lowest team value and how much lower it is
+fn underdog(game_state: &GameState) -> Option<(TeamType, u32)> {
+    let (home, away) = (game_state.home.team_value, game_state.away.team_value);
+    match home.cmp(&away) {
+        Ordering::Less => Some((TeamType::Home, away - home)),
+        Ordering::Greater => Some((TeamType::Away, home - away)),
+        Ordering::Equal => None,
+    }
+}
+
+/// The pre-game sequence: fan factor, weather, inducements and prayers to Nuffle
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Pregame {}
+impl Pregame {
+    pub fn new() -> AnyProc {
+        AnyProc::Pregame(Pregame {})
+    }
+}
+impl Procedure for Pregame {
+    fn step(&mut self, _game_state: &mut GameState, _input: ProcInput) -> ProcState {
+        ProcState::DoneNewProcs(vec![
+            PrayersToNuffle::new_pregame(),
+            Inducements::new(),
+            WeatherRoll::new(),
+            FanFactor::new(),
+        ])
+    }
+}
+
+/// Each team's fan factor is a D3 plus its dedicated fans
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FanFactor {
+    home_roll: Option<D3>,
+}
+impl FanFactor {
+    pub fn new() -> AnyProc {
+        AnyProc::FanFactor(FanFactor { home_roll: None })
+    }
+}
+impl Procedure for FanFactor {
+    fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
+        match input {
+            ProcInput::Nothing => ProcState::NeedRoll(RequestedRoll::D3),
+            ProcInput::Roll(RollResult::D3(roll)) if self.home_roll.is_none() => {
+                self.home_roll = Some(roll);
+                ProcState::NeedRoll(RequestedRoll::D3)
+            }
+            ProcInput::Roll(RollResult::D3(away_roll)) => {
+                let home = &mut game_state.home;
+                home.fan_factor = self.home_roll.unwrap() as u8 + home.dedicated_fans;
+                let away = &mut game_state.away;
+                away.fan_factor = away_roll as u8 + away.dedicated_fans;
+                ProcState::Done
+            }
+            _ => panic!("Unexpected input {:?} for FanFactor", input),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WeatherRoll {}
+impl WeatherRoll {
+    pub fn new() -> AnyProc {
+        AnyProc::WeatherRoll(WeatherRoll {})
+    }
+}
+impl Procedure for WeatherRoll {
+    fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
+        match input {
+            ProcInput::Nothing => ProcState::NeedRoll(RequestedRoll::Sum2D6),
+            ProcInput::Roll(RollResult::Sum2D6(roll)) => {
+                game_state.info.weather = Weather::from(roll);
+                game_state.log(format!("the weather is {:?}", game_state.info.weather));
+                ProcState::Done
+            }
+            _ => panic!("Unexpected input {:?} for WeatherRoll", input),
+        }
+    }
+}
+
+/// The underdog gets the team value difference as petty cash to spend on inducements, which
+/// count towards its team value
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Inducements {
+    team: TeamType,
+    budget: u32,
+    bought: Vec<SimpleAT>,
+}
+impl Inducements {
+    pub fn new() -> AnyProc {
+        AnyProc::Inducements(Inducements {
+            team: TeamType::Home,
+            budget: 0,
+            bought: Vec::new(),
+        })
+    }
+    /// The cost in thousands and how many may be bought
+    fn price(inducement: SimpleAT) -> (u32, usize) {
+        match inducement {
+            SimpleAT::BuyExtraTeamTraining => (100, 8),
+            SimpleAT::BuyBribe => (100, 3),
+            SimpleAT::BuyWanderingApothecary => (100, 2),
+            SimpleAT::BuyAssistantCoach => (20, 3),
+            SimpleAT::BuyCheerleader => (20, 4),
+            _ => panic!("Not an inducement {:?}", inducement),
+        }
+    }
+    fn buy(
+        &mut self,
+        game_state: &mut GameState,
+        inducement: SimpleAT,
+        apply: impl FnOnce(&mut TeamState),
+    ) -> ProcState {
+        let (cost, _) = Inducements::price(inducement);
+        self.budget -= cost;
+        self.bought.push(inducement);
+        let team = game_state.get_mut_team(self.team);
+        team.team_value += cost;
+        apply(team);
+        self.next_purchase()
+    }
+    fn available_actions(&self) -> Option<Box<AvailableActions>> {
+        let mut aa = AvailableActions::new(self.team);
+        [
+            SimpleAT::BuyExtraTeamTraining,
+            SimpleAT::BuyBribe,
+            SimpleAT::BuyWanderingApothecary,
+            SimpleAT::BuyAssistantCoach,
+            SimpleAT::BuyCheerleader,
+        ]
+        .into_iter()
+        .filter(|&at| {
+            let (cost, max) = Inducements::price(at);
+            cost <= self.budget && self.bought.iter().filter(|&&b| b == at).count() < max
+        })
+        .for_each(|at| aa.insert_simple(at));
+        if aa.is_empty() {
+            return None;
+        }
+        aa.insert_simple(SimpleAT::EndInducements);
+        Some(aa)
+    }
+    fn next_purchase(&self) -> ProcState {
+        match self.available_actions() {
+            Some(aa) => ProcState::NeedAction(aa),
+            None => ProcState::Done,
+        }
+    }
+}
+impl Procedure for Inducements {
+    fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
+        match input {
+            ProcInput::Nothing => match underdog(game_state) {
+                Some((team, budget)) => {
+                    self.team = team;
+                    self.budget = budget;
+                    self.next_purchase()
+                }
+                None => ProcState::Done,
+            },
+            ProcInput::Action(Action::Simple(SimpleAT::EndInducements)) => ProcState::Done,
+            ProcInput::Action(Action::Simple(SimpleAT::BuyExtraTeamTraining)) => {
+                self.buy(game_state, SimpleAT::BuyExtraTeamTraining, |team| {
+                    team.rerolls_start += 1;
+                    team.rerolls += 1;
+                })
+            }
+            ProcInput::Action(Action::Simple(SimpleAT::BuyBribe)) => {
+                self.buy(game_state, SimpleAT::BuyBribe, |team| team.bribes += 1)
+            }
+            ProcInput::Action(Action::Simple(SimpleAT::BuyWanderingApothecary)) => {
+                self.buy(game_state, SimpleAT::BuyWanderingApothecary, |team| {
+                    team.apothecaries += 1
+                })
+            }
+            ProcInput::Action(Action::Simple(SimpleAT::BuyAssistantCoach)) => {
+                self.buy(game_state, SimpleAT::BuyAssistantCoach, |team| {
+                    team.ass_coaches += 1
+                })
+            }
+            ProcInput::Action(Action::Simple(SimpleAT::BuyCheerleader)) => {
+                self.buy(game_state, SimpleAT::BuyCheerleader, |team| {
+                    team.cheerleaders += 1
+                })
+            }
+            _ => panic!("Unexpected input {:?} for Inducements", input),
+        }
+    }
+}
+
+/// Rolls on the Prayers to Nuffle table, a result already in effect for the team is rolled again.
+/// The team chooses which of its players on the pitch is blessed, before the game it chooses once
+/// set up. Opposing players are randomly selected from the pitch, or from the reserves before the
+/// game.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PrayersToNuffle {
+    team: Option<TeamType>,
+    prayers: u8,
+    prayer: Option<PrayerToNuffle>,
+}
+impl PrayersToNuffle {
+    pub fn new(team: TeamType, prayers: u8) -> AnyProc {
+        AnyProc::PrayersToNuffle(PrayersToNuffle {
+            team: Some(team),
+            prayers,
+            prayer: None,
+        })
+    }
+    /// The underdog prays once for every 50k of team value the other team has over it
+    pub fn new_pregame() -> AnyProc {
+        AnyProc::PrayersToNuffle(PrayersToNuffle {
+            team: None,
+            prayers: 0,
+            prayer: None,
+        })
+    }
+    fn underdog(game_state: &GameState) -> Option<(TeamType, u8)> {
+        let (team, difference) = underdog(game_state)?;
+        // there are only 16 different prayers
+        let prayers = (difference / 50).min(16) as u8;
+        (prayers > 0).then_some((team, prayers))
+    }
+    fn next_prayer(&self) -> ProcState {
+        if self.prayers == 0 {
+            ProcState::Done
+        } else {
+            ProcState::NeedRoll(RequestedRoll::D16)
+        }
+    }
+    fn select_own_player(&self, game_state: &mut GameState) -> ProcState {
+        let team = self.team.unwrap();
+        let positions: Vec<Position> = game_state
+            .get_players_on_pitch_in_team(team)
+            .map(|p| p.position)
+            .collect();
+        if positions.is_empty() {
+            game_state.log(format!(
+                "{:?} chooses the player once set up",
+                self.prayer.unwrap()
+            ));
+            return self.next_prayer();
+        }
+        let mut aa = AvailableActions::new(team);
+        aa.insert_positional(PosAT::SelectPosition, positions);
+        ProcState::NeedAction(aa)
+    }
+    fn select_opposing_players(&self, game_state: &GameState, num: u8) -> ProcState {
+        let team = other_team(self.team.unwrap());
+        if game_state
+            .get_players_on_pitch_in_team(team)
+            .next()
+            .is_some()
+        {
+            ProcState::NeedRoll(RequestedRoll::RandomPlayers(team, num))
+        } else {
+            ProcState::NeedRoll(RequestedRoll::RandomReserves(team, num))
+        }
+    }
+}
+impl Procedure for PrayersToNuffle {
+    fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
+        match input {
+            ProcInput::Nothing if self.team.is_none() => {
+                match PrayersToNuffle::underdog(game_state) {
+                    Some((team, prayers)) => {
+                        self.team = Some(team);
+                        self.prayers = prayers;
+                        self.next_prayer()
+                    }
+                    None => ProcState::Done,
+                }
+            }
+            ProcInput::Nothing => self.next_prayer(),
+            ProcInput::Roll(RollResult::D16(roll)) => {
+                let team = self.team.unwrap();
+                let prayer = PrayerToNuffle::from(roll);
+                if game_state.info.prayers.contains(&(team, prayer)) {
+                    return ProcState::NeedRoll(RequestedRoll::D16);
+                }
+                game_state.log(format!("{:?} prays for {:?}", team, prayer));
+                game_state.info.prayers.push((team, prayer));
+                self.prayers -= 1;
+                self.prayer = Some(prayer);
+                match prayer.target() {
+                    PrayerTarget::Own => self.select_own_player(game_state),
+                    PrayerTarget::Opponent if prayer == PrayerToNuffle::BadHabits => {
+                        ProcState::NeedRoll(RequestedRoll::D3)
+                    }
+                    PrayerTarget::Opponent => self.select_opposing_players(game_state, 1),
+                    PrayerTarget::None => self.next_prayer(),
+                }
+            }
+            ProcInput::Roll(RollResult::D3(roll)) => {
+                self.select_opposing_players(game_state, roll as u8)
+            }
+            ProcInput::Action(Action::Positional(PosAT::SelectPosition, position)) => {
+                let id = game_state.get_player_id_at(position).unwrap();
+                game_state
+                    .get_mut_player_unsafe(id)
+                    .stats
+                    .add_prayer(self.prayer.unwrap());
+                self.next_prayer()
+            }
+            ProcInput::Roll(RollResult::RandomPlayers(ids)) => {
+                let prayer = self.prayer.unwrap();
+                for id in ids.into_iter().flatten() {
+                    game_state
+                        .get_mut_player_unsafe(id)
+                        .stats
+                        .add_prayer(prayer);
+                }
+                self.next_prayer()
+            }
+            ProcInput::Roll(RollResult::RandomReserves(ids)) => {
+                let prayer = self.prayer.unwrap();
+                for id in ids.into_iter().flatten() {
+                    let player = game_state.get_dugout_player_mut(id).unwrap();
+                    player.stats.add_prayer(prayer);
+                }
+                self.next_prayer()
+            }
+            _ => panic!("Unexpected input {:?} for PrayersToNuffle", input),
+        }
+    }
+}
+
+/// Once set up, each team chooses the players blessed by its prayers to Nuffle from before the game
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PrayerBlessings {
+    blessing: Option<(TeamType, PrayerToNuffle)>,
+}
+impl PrayerBlessings {
+    pub fn new() -> AnyProc {
+        AnyProc::PrayerBlessings(PrayerBlessings { blessing: None })
+    }
+    fn is_blessed(game_state: &GameState, team: TeamType, prayer: PrayerToNuffle) -> bool {
+        game_state
+            .get_players_on_pitch_in_team(team)
+            .map(|p| &p.stats)
+            .chain(game_state.get_dugout().map(|p| &p.stats))
+            .any(|stats| stats.team == team && stats.has_prayer(prayer))
+    }
+    fn next_blessing(&mut self, game_state: &GameState) -> ProcState {
+        self.blessing = game_state
+            .info
+            .prayers
+            .iter()
+            .copied()
+            .find(|(team, prayer)| {
+                prayer.target() == PrayerTarget::Own
+                    && !PrayerBlessings::is_blessed(game_state, *team, *prayer)
+                    && game_state
+                        .get_players_on_pitch_in_team(*team)
+                        .next()
+                        .is_some()
+            });
+        let Some((team, _)) = self.blessing else {
+            return ProcState::Done;
+        };
+        let mut aa = AvailableActions::new(team);
+        aa.insert_positional(
+            PosAT::SelectPosition,
+            game_state
+                .get_players_on_pitch_in_team(team)
+                .map(|p| p.position)
+                .collect(),
+        );
+        ProcState::NeedAction(aa)
+    }
+}
+impl Procedure for PrayerBlessings {
+    fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
+        match input {
+            ProcInput::Nothing => self.next_blessing(game_state),
+            ProcInput::Action(Action::Positional(PosAT::SelectPosition, position)) => {
+                let (_, prayer) = self.blessing.take().unwrap();
+                let id = game_state.get_player_id_at(position).unwrap();
+                game_state
+                    .get_mut_player_unsafe(id)
+                    .stats
+                    .add_prayer(prayer);
+                self.next_blessing(game_state)
+            }
+            _ => panic!("Unexpected input {:?} for PrayerBlessings", input),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct CoinToss {
     choosen_action: SimpleAT,
@@ -402,7 +1026,7 @@ mod tests {
     use crate::core::model::*;
     use crate::core::table::*;
     use crate::core::{
-        gamestate::{GameState, GameStateBuilder},
+        gamestate::{BuilderState, GameState, GameStateBuilder},
         model::{Position, TeamType},
         table::PosAT,
     };
@@ -465,7 +1089,6 @@ mod tests {
         assert!(state.get_player_unsafe(id).used);
 
         state.step_simple(SimpleAT::EndTurn);
-        assert!(!state.get_player_unsafe(id).used);
 
         assert!(state.away_to_act());
         state.step_simple(SimpleAT::EndTurn);
@@ -496,6 +1119,17 @@ mod tests {
 
         assert!(state.away_to_act());
         assert_eq!(state.get_player_unsafe(id).status, PlayerStatus::Stunned);
+        state.step_simple(SimpleAT::EndTurn);
+
+        // a stunned player can't be activated until it has turned back to down
+        assert!(state.home_to_act());
+        assert_eq!(state.get_player_unsafe(id).status, PlayerStatus::Stunned);
+        assert!(state.get_player_unsafe(id).used);
+        state.step_simple(SimpleAT::EndTurn);
+        state.step_simple(SimpleAT::EndTurn);
+
+        assert!(state.home_to_act());
+        assert_eq!(state.get_player_unsafe(id).status, PlayerStatus::Down);
         assert!(!state.get_player_unsafe(id).used);
     }
 
@@ -559,6 +1193,89 @@ mod tests {
         assert!(matches!(state.ball, BallState::OnGround(_)));
         assert_eq!(ball_pos, Position::new((23, 2)));
     }
+    #[test]
+    fn one_reroll_per_turn() {
+        let start_pos = Position::new((1, 1));
+        let mut state = GameStateBuilder::new().add_home_player(start_pos).build();
+
+        state.step_positional(PosAT::StartMove, start_pos);
+        state.fixes.fix_d6(1); //fail gfi
+        state.step_positional(PosAT::Move, Position::new((8, 1)));
+        state.fixes.fix_d6(2); //succeed with team reroll
+        state.step_simple(SimpleAT::UseReroll);
+        assert!(!state.home.can_use_reroll());
+
+        state.step_simple(SimpleAT::EndPlayerTurn);
+        state.step_simple(SimpleAT::EndTurn);
+        state.step_simple(SimpleAT::EndTurn);
+
+        assert!(state.home_to_act());
+        assert!(state.home.can_use_reroll());
+        assert_eq!(state.home.rerolls, 2);
+    }
+
+    #[test]
+    fn pregame() {
+        let mut state = GameStateBuilder::new_pregame();
+        state.away.team_value = 1150;
+        state.fixes.fix_d3(2); // home fan factor
+        state.fixes.fix_d3(3); // away fan factor
+        state.fixes.fix_d6(6);
+        state.fixes.fix_d6(5); // weather
+        state.step_simple(SimpleAT::EndTurn);
+
+        assert_eq!(state.home.fan_factor, 3);
+        assert_eq!(state.away.fan_factor, 4);
+        assert_eq!(state.info.weather, Weather::Rain);
+
+        // the underdog spends the difference on inducements
+        assert!(state.home_to_act());
+        state.step_simple(SimpleAT::BuyBribe);
+        assert!(!state.is_legal_action(&Action::Simple(SimpleAT::BuyBribe)));
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::BuyCheerleader)));
+        state.fixes.fix_d16(9);
+        state.step_simple(SimpleAT::EndInducements);
+
+        // and prays for what's left
+        assert_eq!(state.home.bribes, 1);
+        assert_eq!(state.home.team_value, 1100);
+        assert_eq!(
+            state.info.prayers,
+            vec![(TeamType::Home, PrayerToNuffle::MolesUnderThePitch)]
+        );
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::Heads)));
+    }
+
+    #[test]
+    fn rerolls_restored_at_half_time() {
+        let leader_pos = Position::new((5, 5));
+        let mut state = GameStateBuilder::new()
+            .add_away_player(leader_pos)
+            .set_state(BuilderState::Turn { turn: 7 })
+            .build();
+        state.home.rerolls = 0;
+        state.away.rerolls = 1;
+        let id = state.get_player_id_at(leader_pos).unwrap();
+        state
+            .get_mut_player_unsafe(id)
+            .stats
+            .give_skill(Skill::Leader);
+
+        for _ in 0..4 {
+            assert_eq!(state.info.half, 1);
+            state.step_simple(SimpleAT::EndTurn);
+        }
+
+        // the Leader re-roll is kept apart from the team re-rolls, it's only usable while a
+        // player with Leader is on the pitch
+        assert_eq!(state.info.half, 2);
+        assert_eq!(state.home.rerolls, 3);
+        assert_eq!(state.away.rerolls, 3);
+        assert!(!state.home.leader_reroll);
+        assert!(state.away.leader_reroll);
+        assert!(state.away.can_use_leader_reroll());
+    }
+
     #[test]
     fn turn_order() -> Result<()> {
         let mut state = standard_state();
@@ -606,6 +1323,37 @@ mod tests {
         assert!(state.is_legal_action(&Action::Simple(SimpleAT::SetupLine)));
     }
 
+    #[test]
+    fn sweltering_heat_after_touchdown() {
+        let start_pos = Position::new((2, 1));
+        let td_pos = Position::new((1, 5));
+        let mut state = GameStateBuilder::new()
+            .add_home_player(start_pos)
+            .add_home_player(Position::new((8, 8)))
+            .add_away_player(Position::new((10, 10)))
+            .add_ball_pos(start_pos)
+            .build();
+        state.info.weather = Weather::Sweltering;
+        let carrier_id = state.get_player_id_at(start_pos).unwrap();
+        let away_id = state.get_player_id_at_coord(10, 10).unwrap();
+
+        state.step_positional(PosAT::StartMove, start_pos);
+        state.fixes.fix_d3(1);
+        state.fixes.fix_random_player(carrier_id);
+        state.fixes.fix_d3(2);
+        state.fixes.fix_random_player(away_id);
+        state.step_positional(PosAT::Move, td_pos);
+
+        assert_eq!(state.home.score, 1);
+        assert_eq!(state.get_players_on_pitch().count(), 0);
+        let heated: Vec<TeamType> = state
+            .get_dugout()
+            .filter(|p| p.place == DugoutPlace::Heated)
+            .map(|p| p.stats.team)
+            .collect();
+        assert_eq!(heated, vec![TeamType::Home, TeamType::Away]);
+    }
+
     #[test]
     fn failed_gfi_touchdown() {
         let start_pos = Position::new((2, 5));
@@ -631,7 +1379,7 @@ mod tests {
         state.fixes.fix_d8(4);
         state.fixes.fix_d6(1);
         state.fixes.fix_d6(1);
-        state.step_simple(SimpleAT::DontUseReroll);
+        state.step_simple(SimpleAT::Decline);
 
         assert_eq!(state.home.score, 0);
         assert_eq!(state.away.score, 0);
@@ -764,7 +1512,7 @@ mod tests {
         state.step_positional(PosAT::Move, td_pos);
 
         state.fixes.fix_d8(4);
-        state.step_simple(SimpleAT::DontUseReroll);
+        state.step_simple(SimpleAT::Decline);
 
         assert_eq!(state.home.score, 0);
         assert_eq!(state.away.score, 0);
@@ -827,10 +1575,174 @@ mod tests {
         state.fixes.fix_d6(5); //armor
         state.fixes.fix_d6(6); //injury
         state.fixes.fix_d6(5); //injury
+        state.fixes.fix_d16(16); //dead
         let d8_fix = D8::Two;
         state.fixes.fix_d8(d8_fix as u8);
         state.step_positional(PosAT::FollowUp, away_pos);
 
         // let direction = Direction::from(d8_fix);
     }
+
+    fn give_skill(state: &mut GameState, position: Position, skill: Skill) -> PlayerID {
+        let id = state.get_player_id_at(position).unwrap();
+        state.get_mut_player_unsafe(id).stats.give_skill(skill);
+        id
+    }
+
+    #[test]
+    fn bone_head() {
+        let home_pos = Position::new((5, 5));
+        let away_pos = Position::new((6, 5));
+        let mut state = GameStateBuilder::new()
+            .add_home_player(home_pos)
+            .add_away_player(away_pos)
+            .build();
+        let id = give_skill(&mut state, home_pos, Skill::BoneHead);
+        let away_id = state.get_player_id_at(away_pos).unwrap();
+
+        state.fixes.fix_d6(1);
+        state.step_positional(PosAT::StartMove, home_pos);
+        state.step_simple(SimpleAT::Decline);
+        assert!(state.get_player_unsafe(id).used);
+        assert!(!state.get_player_unsafe(id).has_tackle_zone());
+        assert_eq!(state.get_tz_on(away_id), 0);
+        assert!(state.home_to_act());
+
+        state.step_simple(SimpleAT::EndTurn);
+        state.step_simple(SimpleAT::EndTurn);
+        assert_eq!(state.get_tz_on(away_id), 0);
+
+        state.fixes.fix_d6(2);
+        state.step_positional(PosAT::StartMove, home_pos);
+        assert_eq!(state.get_tz_on(away_id), 1);
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::EndPlayerTurn)));
+    }
+
+    #[test]
+    fn bone_head_team_reroll() {
+        let home_pos = Position::new((5, 5));
+        let mut state = GameStateBuilder::new()
+            .add_home_player(home_pos)
+            .add_away_player(Position::new((10, 10)))
+            .build();
+        let id = give_skill(&mut state, home_pos, Skill::BoneHead);
+        let rerolls = state.home.rerolls;
+
+        state.fixes.fix_d6(1);
+        state.step_positional(PosAT::StartMove, home_pos);
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::UseReroll)));
+
+        state.fixes.fix_d6(2);
+        state.step_simple(SimpleAT::UseReroll);
+        assert_eq!(state.home.rerolls, rerolls - 1);
+        let player = state.get_player_unsafe(id);
+        assert!(!player.used);
+        assert!(player.has_tackle_zone());
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::EndPlayerTurn)));
+    }
+
+    #[test]
+    fn really_stupid_and_wild_animal() {
+        let home_pos = Position::new((5, 5));
+        let mate_pos = Position::new((5, 6));
+        let away_pos = Position::new((6, 5));
+        let mut state = GameStateBuilder::new()
+            .add_home_player(home_pos)
+            .add_home_player(mate_pos)
+            .add_away_player(away_pos)
+            .build();
+        let id = give_skill(&mut state, home_pos, Skill::ReallyStupid);
+        let mate_id = give_skill(&mut state, mate_pos, Skill::WildAnimal);
+
+        state.fixes.fix_d6(2); // 2+ with a team-mate next to the player
+        state.step_positional(PosAT::StartMove, home_pos);
+        assert!(!state.get_player_unsafe(id).used);
+        state.step_simple(SimpleAT::EndPlayerTurn);
+
+        state.fixes.fix_d6(3); // 4+ when not blocking or blitzing
+        state.step_positional(PosAT::StartMove, mate_pos);
+        state.step_simple(SimpleAT::Decline);
+        assert!(state.get_player_unsafe(mate_id).used);
+        assert!(state.get_player_unsafe(mate_id).has_tackle_zone());
+    }
+
+    #[test]
+    fn animal_savagery() {
+        let home_pos = Position::new((5, 5));
+        let mate_pos = Position::new((5, 6));
+        let mut state = GameStateBuilder::new()
+            .add_home_player(home_pos)
+            .add_home_player(mate_pos)
+            .add_away_player(Position::new((10, 10)))
+            .build();
+        let id = give_skill(&mut state, home_pos, Skill::AnimalSavagery);
+
+        state.fixes.fix_d6(1);
+        state.step_positional(PosAT::StartMove, home_pos);
+        state.step_simple(SimpleAT::Decline);
+        assert!(state.is_legal_action(&Action::Positional(PosAT::SelectPosition, mate_pos)));
+
+        state.fixes.fix_d6(1); //armor
+        state.fixes.fix_d6(2); //armor
+        state.step_positional(PosAT::SelectPosition, mate_pos);
+        assert_eq!(
+            state.get_player_at(mate_pos).unwrap().status,
+            PlayerStatus::Down
+        );
+        assert!(!state.get_player_unsafe(id).used);
+        assert!(state.home_to_act());
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::EndPlayerTurn)));
+    }
+
+    #[test]
+    fn loner_wastes_reroll() {
+        let home_pos = Position::new((5, 5));
+        let mut state = GameStateBuilder::new()
+            .add_home_player(home_pos)
+            .add_away_player(Position::new((6, 5)))
+            .build();
+        let id = give_skill(&mut state, home_pos, Skill::Loner);
+        let rerolls = state.home.rerolls;
+
+        state.step_positional(PosAT::StartMove, home_pos);
+        state.fixes.fix_d6(1); //dodge
+        state.step_positional(PosAT::Move, home_pos + (-1, 0));
+
+        state.fixes.fix_d6(3); //loner
+        state.fixes.fix_d6(1); //armor
+        state.fixes.fix_d6(2); //armor
+        state.step_simple(SimpleAT::UseReroll);
+
+        assert_eq!(state.home.rerolls, rerolls - 1);
+        assert_eq!(state.get_player_unsafe(id).status, PlayerStatus::Down);
+        assert!(state.away_to_act());
+    }
+
+    #[test]
+    fn bad_habits_loner() {
+        let home_pos = Position::new((5, 5));
+        let mut state = GameStateBuilder::new()
+            .add_home_player(home_pos)
+            .add_away_player(Position::new((6, 5)))
+            .build();
+        let id = state.get_player_id_at(home_pos).unwrap();
+        state
+            .get_mut_player_unsafe(id)
+            .stats
+            .add_prayer(PrayerToNuffle::BadHabits);
+        let rerolls = state.home.rerolls;
+
+        state.step_positional(PosAT::StartMove, home_pos);
+        state.fixes.fix_d6(1); //dodge
+        state.step_positional(PosAT::Move, home_pos + (-1, 0));
+
+        state.fixes.fix_d6(2); //loner (2+)
+        state.fixes.fix_d6(6); //dodge
+        state.step_simple(SimpleAT::UseReroll);
+
+        assert_eq!(state.home.rerolls, rerolls - 1);
+        let player = state.get_player_unsafe(id);
+        assert_eq!(player.position, home_pos + (-1, 0));
+        assert_eq!(player.status, PlayerStatus::Up);
+    }
 }
@@ -1,15 +1,19 @@
 use crate::core::model::ProcInput;
+use std::cmp::Ordering;
 use std::ops::RangeInclusive;
 
+use itertools::Itertools;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
-use crate::core::dices::{RequestedRoll, RollResult, Sum2D6};
+use crate::core::dices::{RequestedRoll, RollResult, Sum2D6, D6, D8};
+use crate::core::formations::Formation;
 use crate::core::model::{
-    other_team, Action, AvailableActions, BallState, Coord, Direction, DugoutPlace, PlayerID,
-    Position, ProcState, Procedure, Result, TeamType, Weather, HEIGHT_, LINE_OF_SCRIMMAGE_Y_RANGE,
+    other_team, Action, AvailableActions, BallState, Coord, Direction, DugoutPlace, DugoutPlayerID,
+    PlayerID, PlayerStatus, Position, ProcState, Procedure, TeamState, TeamType, Weather,
+    LINE_OF_SCRIMMAGE_Y_RANGE, MAX_PLAYERS_ON_PITCH, NORTH_WING_Y_RANGE, SOUTH_WING_Y_RANGE,
 };
-use crate::core::procedures::ball_procs;
+use crate::core::procedures::{ball_procs, casualty_procs, game_procs, movement_procs};
 use crate::core::table::*;
 
 use crate::core::gamestate::GameState;
@@ -18,33 +22,116 @@ use super::AnyProc;
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Kickoff {
     aim: Position,
+    deviation: Option<(D6, D8)>,
 }
 impl Kickoff {
     pub fn new() -> AnyProc {
         AnyProc::Kickoff(Kickoff {
             aim: Position::new((0, 0)),
+            deviation: None,
         })
     }
+    /// Players with Kick that may be the kicking player, standing and neither on the line of
+    /// scrimmage nor in a wide zone
+    fn kickers(game_state: &GameState) -> Vec<Position> {
+        let team = game_state.info.kicking_this_drive;
+        game_state
+            .get_players_on_pitch_in_team(team)
+            .filter(|p| p.has_skill(Skill::Kick) && p.status == PlayerStatus::Up)
+            .map(|p| p.position)
+            .filter(|pos| !is_on_line_of_scrimmage(game_state, team, *pos))
+            .filter(|pos| !NORTH_WING_Y_RANGE.contains(&pos.y))
+            .filter(|pos| !SOUTH_WING_Y_RANGE.contains(&pos.y))
+            .collect()
+    }
+    fn land(&self, game_state: &mut GameState, distance: u8) -> ProcState {
+        let (_, dir_roll) = self.deviation.unwrap();
+        let ball_pos = self.aim + Direction::from(dir_roll) * (distance as Coord);
+        game_state.ball = BallState::InAir(ball_pos);
+        ProcState::DoneNew(KickoffTable::new())
+    }
+    /// Where the ball can land when the kicking team aims at `aim`, before it bounces or is
+    /// caught. An eligible player with Kick is assumed to be used.
+    pub fn landing_distribution(
+        game_state: &GameState,
+        aim: Position,
+    ) -> Vec<(KickoffLanding, f32)> {
+        let kick = !Kickoff::kickers(game_state).is_empty();
+        Kickoff::landing_distribution_for(aim, game_state.info.kicking_this_drive, kick)
+    }
+    /// Same as `landing_distribution`, with or without Kick halving the deviation. Squares that
+    /// are out of bounds or on the kicking team's half are merged into a single touchback.
+    pub fn landing_distribution_for(
+        aim: Position,
+        kicking_team: TeamType,
+        kick: bool,
+    ) -> Vec<(KickoffLanding, f32)> {
+        let mut distribution: Vec<(KickoffLanding, f32)> = Vec::new();
+        for (len_roll, direction) in (1..=6).cartesian_product(Direction::all_directions_as_array())
+        {
+            let distance = if kick { len_roll / 2 } else { len_roll };
+            let position = aim + direction * distance;
+            let landing = if position.is_out() || position.is_on_team_side(kicking_team) {
+                KickoffLanding::Touchback
+            } else {
+                KickoffLanding::Square(position)
+            };
+            match distribution.iter_mut().find(|(l, _)| *l == landing) {
+                Some((_, prob)) => *prob += 1.0 / 48.0,
+                None => distribution.push((landing, 1.0 / 48.0)),
+            }
+        }
+        distribution
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KickoffLanding {
+    Square(Position),
+    Touchback,
 }
 impl Procedure for Kickoff {
     fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
-        let (len_roll, dir_roll) = match input {
+        match input {
             ProcInput::Nothing => {
-                let mut aa = AvailableActions::new(game_state.info.kicking_this_drive);
+                let kicking_team = game_state.info.kicking_this_drive;
+                let mut aa = AvailableActions::new(kicking_team);
                 aa.insert_simple(SimpleAT::KickoffAimMiddle);
-                return ProcState::NeedAction(aa);
+                let receiving_half = Position::all_positions()
+                    .filter(|pos| !pos.is_out() && pos.is_on_team_side(other_team(kicking_team)))
+                    .collect();
+                aa.insert_positional(PosAT::KickoffAim, receiving_half);
+                ProcState::NeedAction(aa)
             }
             ProcInput::Action(Action::Simple(SimpleAT::KickoffAimMiddle)) => {
                 self.aim = game_state.get_best_kickoff_aim_for(game_state.info.kicking_this_drive);
-                return ProcState::NeedRoll(RequestedRoll::Deviate);
+                ProcState::NeedRoll(RequestedRoll::Deviate)
+            }
+            ProcInput::Action(Action::Positional(PosAT::KickoffAim, aim)) => {
+                self.aim = aim;
+                ProcState::NeedRoll(RequestedRoll::Deviate)
+            }
+            ProcInput::Roll(RollResult::Deviate(len_roll, dir_roll)) => {
+                self.deviation = Some((len_roll, dir_roll));
+                let kickers = Kickoff::kickers(game_state);
+                if kickers.is_empty() {
+                    return self.land(game_state, len_roll as u8);
+                }
+                let mut aa = AvailableActions::new(game_state.info.kicking_this_drive);
+                aa.insert_positional(PosAT::SelectPosition, kickers);
+                aa.insert_simple(SimpleAT::Decline);
+                ProcState::NeedAction(aa)
+            }
+            ProcInput::Action(Action::Positional(PosAT::SelectPosition, _)) => {
+                // Kick halves the deviation, rounding down
+                let (len_roll, _) = self.deviation.unwrap();
+                self.land(game_state, len_roll as u8 / 2)
+            }
+            ProcInput::Action(Action::Simple(SimpleAT::Decline)) => {
+                let (len_roll, _) = self.deviation.unwrap();
+                self.land(game_state, len_roll as u8)
             }
-            ProcInput::Roll(RollResult::Deviate(len_roll, dir_roll)) => (len_roll, dir_roll),
             _ => panic!("Unexpected input {:?}", input),
-        };
-
-        let ball_pos = self.aim + Direction::from(dir_roll) * (len_roll as Coord);
-        game_state.ball = BallState::InAir(ball_pos);
-        ProcState::DoneNew(KickoffTable::new())
+        }
     }
 }
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -63,8 +150,10 @@ impl Procedure for KickoffTable {
             ProcInput::Roll(RollResult::Sum2D6(kickoff_roll)) => kickoff_roll,
             _ => panic!("Unexpected input {:?}", input),
         };
-        let mut procs: Vec<AnyProc> = vec![LandKickoff::new()]; //TODO: this should be added
-                                                                //by the kickoff procedure
+        let kicking_team = game_state.info.kicking_this_drive;
+        let receiving_team = other_team(kicking_team);
+        //TODO: this should be added by the kickoff procedure
+        let mut procs: Vec<AnyProc> = vec![LandKickoff::new(), KickoffReturn::new(receiving_team)];
         match kickoff_roll {
             Sum2D6::Two => {
                 //get the ref
@@ -82,31 +171,31 @@ impl Procedure for KickoffTable {
                 }
             }
             Sum2D6::Four => {
-                //solid defense
+                procs.push(SolidDefence::new(kicking_team));
             }
             Sum2D6::Five => {
-                //High Kick
+                procs.push(HighKick::new(receiving_team));
             }
             Sum2D6::Six => {
-                //Cheering fans
+                procs.push(CheeringFans::new());
             }
             Sum2D6::Seven => {
-                //Brilliant coaching
+                procs.push(BrilliantCoaching::new());
             }
             Sum2D6::Eight => {
                 procs.push(ChangingWeather::new());
             }
             Sum2D6::Nine => {
-                //Quick snap
+                procs.push(QuickSnap::new(receiving_team));
             }
             Sum2D6::Ten => {
-                //Blitz!
+                procs.push(KickoffBlitz::new(kicking_team));
             }
             Sum2D6::Eleven => {
-                //Officious ref
+                procs.push(OfficiousRef::new());
             }
             Sum2D6::Twelve => {
-                //Pitch invasion
+                procs.push(PitchInvasion::new());
             }
         }
 
@@ -114,6 +203,498 @@ impl Procedure for KickoffTable {
     }
 }
 
+/// Open players are standing and not in the tackle zone of an opponent
+fn get_open_players(game_state: &GameState, team: TeamType) -> Vec<PlayerID> {
+    game_state
+        .get_players_on_pitch_in_team(team)
+        .filter(|p| p.status == PlayerStatus::Up && game_state.get_tz_on(p.id) == 0)
+        .map(|p| p.id)
+        .collect()
+}
+
+fn is_on_line_of_scrimmage(game_state: &GameState, team: TeamType, position: Position) -> bool {
+    position.x == game_state.get_line_of_scrimage_x(team)
+        && LINE_OF_SCRIMMAGE_Y_RANGE.contains(&position.y)
+}
+
+/// Compares the totals when both coaches have rolled a D6 and added a team bonus
+fn roll_off(
+    game_state: &GameState,
+    home_roll: D6,
+    away_roll: D6,
+    bonus: fn(&TeamState) -> u8,
+) -> Ordering {
+    let home_total = home_roll as u8 + bonus(&game_state.home);
+    let away_total = away_roll as u8 + bonus(&game_state.away);
+    home_total.cmp(&away_total)
+}
+
+/// The team(s) with the highest total in a roll off, both teams if it's a tie
+fn roll_off_highest(ordering: Ordering) -> Vec<TeamType> {
+    match ordering {
+        Ordering::Greater => vec![TeamType::Home],
+        Ordering::Less => vec![TeamType::Away],
+        Ordering::Equal => vec![TeamType::Home, TeamType::Away],
+    }
+}
+
+/// The team(s) with the lowest total in a roll off, both teams if it's a tie
+fn roll_off_lowest(ordering: Ordering) -> Vec<TeamType> {
+    roll_off_highest(ordering.reverse())
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SolidDefence {
+    team: TeamType,
+    num_players: u8,
+    moved: Vec<PlayerID>,
+    selected: Option<PlayerID>,
+    was_legal: bool,
+}
+impl SolidDefence {
+    pub fn new(team: TeamType) -> AnyProc {
+        AnyProc::SolidDefence(SolidDefence {
+            team,
+            num_players: 0,
+            moved: Vec::new(),
+            selected: None,
+            was_legal: true,
+        })
+    }
+    fn available_actions(&self, game_state: &GameState) -> Box<AvailableActions> {
+        let mut aa = AvailableActions::new(self.team);
+        if let Some(id) = self.selected {
+            let positions: Vec<Position> = Position::all_positions()
+                .filter(|pos| !pos.is_out() && pos.is_on_team_side(self.team))
+                .filter(|pos| match game_state.get_player_id_at(*pos) {
+                    Some(other_id) => other_id == id,
+                    None => true,
+                })
+                .collect();
+            aa.insert_positional(PosAT::PlacePlayer, positions);
+            return aa;
+        }
+
+        // players that are already moved can be moved again without counting twice
+        let mut candidates: Vec<PlayerID> = self.moved.clone();
+        if self.moved.len() < self.num_players as usize {
+            candidates.extend(
+                get_open_players(game_state, self.team)
+                    .into_iter()
+                    .filter(|id| !self.moved.contains(id)),
+            );
+        }
+        let positions: Vec<Position> = candidates
+            .into_iter()
+            .map(|id| game_state.get_player_unsafe(id).position)
+            .collect();
+
+        // the new setup only has to be legal if the original one was
+        if positions.is_empty() || !self.was_legal || game_state.is_setup_legal(self.team) {
+            aa.insert_simple(SimpleAT::EndSetup);
+        }
+        aa.insert_positional(PosAT::SelectPosition, positions);
+        aa
+    }
+}
+impl Procedure for SolidDefence {
+    fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
+        match input {
+            ProcInput::Nothing => return ProcState::NeedRoll(RequestedRoll::D3),
+            ProcInput::Roll(RollResult::D3(roll)) => {
+                self.num_players = roll as u8 + 3;
+                self.was_legal = game_state.is_setup_legal(self.team);
+            }
+            ProcInput::Action(Action::Positional(PosAT::SelectPosition, position)) => {
+                self.selected = game_state.get_player_id_at(position);
+            }
+            ProcInput::Action(Action::Positional(PosAT::PlacePlayer, position)) => {
+                let id = self.selected.take().unwrap();
+                if game_state.get_player_unsafe(id).position != position {
+                    game_state.move_player(id, position).unwrap();
+                }
+                if !self.moved.contains(&id) {
+                    self.moved.push(id);
+                }
+            }
+            ProcInput::Action(Action::Simple(SimpleAT::EndSetup)) => return ProcState::Done,
+            _ => panic!("Unexpected input {:?}", input),
+        }
+        ProcState::NeedAction(self.available_actions(game_state))
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct HighKick {
+    team: TeamType,
+}
+impl HighKick {
+    pub fn new(team: TeamType) -> AnyProc {
+        AnyProc::HighKick(HighKick { team })
+    }
+}
+impl Procedure for HighKick {
+    fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
+        let ball_pos = game_state.get_ball_position().unwrap();
+        match input {
+            ProcInput::Nothing => {
+                if ball_pos.is_out()
+                    || !ball_pos.is_on_team_side(self.team)
+                    || game_state.get_player_id_at(ball_pos).is_some()
+                {
+                    return ProcState::Done;
+                }
+                let positions: Vec<Position> = get_open_players(game_state, self.team)
+                    .into_iter()
+                    .map(|id| game_state.get_player_unsafe(id).position)
+                    .collect();
+                if positions.is_empty() {
+                    return ProcState::Done;
+                }
+                let mut aa = AvailableActions::new(self.team);
+                aa.insert_positional(PosAT::SelectPosition, positions);
+                aa.insert_simple(SimpleAT::Decline);
+                ProcState::NeedAction(aa)
+            }
+            ProcInput::Action(Action::Positional(PosAT::SelectPosition, position)) => {
+                let id = game_state.get_player_id_at(position).unwrap();
+                game_state.move_player(id, ball_pos).unwrap();
+                ProcState::Done
+            }
+            ProcInput::Action(Action::Simple(SimpleAT::Decline)) => ProcState::Done,
+            _ => panic!("Unexpected input {:?}", input),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CheeringFans {
+    home_roll: Option<D6>,
+}
+impl CheeringFans {
+    pub fn new() -> AnyProc {
+        AnyProc::CheeringFans(CheeringFans { home_roll: None })
+    }
+}
+impl Procedure for CheeringFans {
+    fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
+        match input {
+            ProcInput::Nothing => ProcState::NeedRoll(RequestedRoll::D6),
+            ProcInput::Roll(RollResult::D6(roll)) if self.home_roll.is_none() => {
+                self.home_roll = Some(roll);
+                ProcState::NeedRoll(RequestedRoll::D6)
+            }
+            ProcInput::Roll(RollResult::D6(away_roll)) => {
+                let ordering = roll_off(game_state, self.home_roll.unwrap(), away_roll, |team| {
+                    team.cheerleaders
+                });
+                let procs = roll_off_highest(ordering)
+                    .into_iter()
+                    .map(|team| {
+                        game_state.log(format!("{:?} wins the cheering fans roll off", team));
+                        game_procs::PrayersToNuffle::new(team, 1)
+                    })
+                    .collect();
+                ProcState::DoneNewProcs(procs)
+            }
+            _ => panic!("Unexpected input {:?}", input),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BrilliantCoaching {
+    home_roll: Option<D6>,
+}
+impl BrilliantCoaching {
+    pub fn new() -> AnyProc {
+        AnyProc::BrilliantCoaching(BrilliantCoaching { home_roll: None })
+    }
+}
+impl Procedure for BrilliantCoaching {
+    fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
+        match input {
+            ProcInput::Nothing => ProcState::NeedRoll(RequestedRoll::D6),
+            ProcInput::Roll(RollResult::D6(roll)) if self.home_roll.is_none() => {
+                self.home_roll = Some(roll);
+                ProcState::NeedRoll(RequestedRoll::D6)
+            }
+            ProcInput::Roll(RollResult::D6(away_roll)) => {
+                let ordering = roll_off(game_state, self.home_roll.unwrap(), away_roll, |team| {
+                    team.ass_coaches
+                });
+                for team in roll_off_highest(ordering) {
+                    game_state.get_mut_team(team).add_drive_reroll();
+                }
+                ProcState::Done
+            }
+            _ => panic!("Unexpected input {:?}", input),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct QuickSnap {
+    team: TeamType,
+    num_players: u8,
+    moved: Vec<PlayerID>,
+    selected: Option<PlayerID>,
+}
+impl QuickSnap {
+    pub fn new(team: TeamType) -> AnyProc {
+        AnyProc::QuickSnap(QuickSnap {
+            team,
+            num_players: 0,
+            moved: Vec::new(),
+            selected: None,
+        })
+    }
+    fn get_free_adj_positions(game_state: &GameState, id: PlayerID) -> Vec<Position> {
+        game_state
+            .get_adj_positions(game_state.get_player_unsafe(id).position)
+            .filter(|pos| !pos.is_out() && game_state.get_player_id_at(*pos).is_none())
+            .collect()
+    }
+    fn available_actions(&self, game_state: &GameState) -> Option<Box<AvailableActions>> {
+        let mut aa = AvailableActions::new(self.team);
+        if let Some(id) = self.selected {
+            aa.insert_positional(
+                PosAT::PlacePlayer,
+                QuickSnap::get_free_adj_positions(game_state, id),
+            );
+            return Some(aa);
+        }
+        if self.moved.len() >= self.num_players as usize {
+            return None;
+        }
+        let positions: Vec<Position> = get_open_players(game_state, self.team)
+            .into_iter()
+            .filter(|id| !self.moved.contains(id))
+            .filter(|id| !QuickSnap::get_free_adj_positions(game_state, *id).is_empty())
+            .map(|id| game_state.get_player_unsafe(id).position)
+            .collect();
+        if positions.is_empty() {
+            return None;
+        }
+        aa.insert_positional(PosAT::SelectPosition, positions);
+        aa.insert_simple(SimpleAT::EndSetup);
+        Some(aa)
+    }
+}
+impl Procedure for QuickSnap {
+    fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
+        match input {
+            ProcInput::Nothing => return ProcState::NeedRoll(RequestedRoll::D3),
+            ProcInput::Roll(RollResult::D3(roll)) => {
+                self.num_players = roll as u8 + 3;
+            }
+            ProcInput::Action(Action::Positional(PosAT::SelectPosition, position)) => {
+                self.selected = game_state.get_player_id_at(position);
+            }
+            ProcInput::Action(Action::Positional(PosAT::PlacePlayer, position)) => {
+                let id = self.selected.take().unwrap();
+                game_state.move_player(id, position).unwrap();
+                self.moved.push(id);
+            }
+            ProcInput::Action(Action::Simple(SimpleAT::EndSetup)) => return ProcState::Done,
+            _ => panic!("Unexpected input {:?}", input),
+        }
+        match self.available_actions(game_state) {
+            Some(aa) => ProcState::NeedAction(aa),
+            None => ProcState::Done,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct KickoffBlitz {
+    team: TeamType,
+    activations_left: Option<u8>,
+    /// The team whose turn it was before the free turn of the kicking team
+    team_turn: Option<TeamType>,
+}
+impl KickoffBlitz {
+    pub fn new(team: TeamType) -> AnyProc {
+        AnyProc::KickoffBlitz(KickoffBlitz {
+            team,
+            activations_left: None,
+            team_turn: None,
+        })
+    }
+    fn available_actions(&self, game_state: &GameState) -> Box<AvailableActions> {
+        let mut aa = AvailableActions::new(self.team);
+        let positions: Vec<Position> = get_open_players(game_state, self.team)
+            .into_iter()
+            .map(|id| game_state.get_player_unsafe(id))
+            .filter(|p| !p.used)
+            .map(|p| p.position)
+            .collect();
+        if game_state.info.blitz_available {
+            aa.insert_positional(PosAT::StartBlitz, positions.clone());
+        }
+        aa.insert_positional(PosAT::StartMove, positions);
+        aa.insert_simple(SimpleAT::EndTurn);
+        aa
+    }
+    fn end_blitz(&self, game_state: &mut GameState) -> ProcState {
+        let info = &mut game_state.info;
+        info.team_turn = self.team_turn.unwrap();
+        info.turnover = false;
+        info.active_player = None;
+        info.player_action_type = None;
+        ProcState::Done
+    }
+}
+impl Procedure for KickoffBlitz {
+    fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
+        match input {
+            ProcInput::Nothing if self.activations_left.is_none() => {
+                return ProcState::NeedRoll(RequestedRoll::D3);
+            }
+            ProcInput::Roll(RollResult::D3(roll)) => {
+                self.activations_left = Some(roll as u8 + 3);
+                let info = &mut game_state.info;
+                self.team_turn = Some(info.team_turn);
+                info.team_turn = self.team;
+                info.blitz_available = true;
+                info.turnover = false;
+            }
+            ProcInput::Nothing => (), // an activation just ended
+            ProcInput::Action(Action::Positional(at, position)) => {
+                let id = game_state.get_player_id_at(position).unwrap();
+                game_state.set_active_player(id);
+                let info = &mut game_state.info;
+                info.player_action_type = Some(at);
+                if at == PosAT::StartBlitz {
+                    info.blitz_available = false;
+                }
+                *self.activations_left.as_mut().unwrap() -= 1;
+                return ProcState::NotDoneNew(movement_procs::MoveAction::new(id));
+            }
+            ProcInput::Action(Action::Simple(SimpleAT::EndTurn)) => {
+                return self.end_blitz(game_state);
+            }
+            _ => panic!("Unexpected input {:?}", input),
+        }
+
+        if game_state.info.turnover || self.activations_left == Some(0) {
+            return self.end_blitz(game_state);
+        }
+        ProcState::NeedAction(self.available_actions(game_state))
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct OfficiousRef {
+    home_roll: Option<D6>,
+    teams: Option<Vec<TeamType>>,
+    player: Option<PlayerID>,
+}
+impl OfficiousRef {
+    pub fn new() -> AnyProc {
+        AnyProc::OfficiousRef(OfficiousRef {
+            home_roll: None,
+            teams: None,
+            player: None,
+        })
+    }
+    fn next_team(&mut self, game_state: &GameState) -> ProcState {
+        while let Some(team) = self.teams.as_mut().unwrap().pop() {
+            if game_state.get_players_on_pitch_in_team(team).count() > 0 {
+                return ProcState::NeedRoll(RequestedRoll::RandomPlayers(team, 1));
+            }
+        }
+        ProcState::Done
+    }
+}
+impl Procedure for OfficiousRef {
+    fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
+        match input {
+            ProcInput::Nothing if self.teams.is_none() => ProcState::NeedRoll(RequestedRoll::D6),
+            ProcInput::Nothing => self.next_team(game_state),
+            ProcInput::Roll(RollResult::D6(roll)) if self.home_roll.is_none() => {
+                self.home_roll = Some(roll);
+                ProcState::NeedRoll(RequestedRoll::D6)
+            }
+            ProcInput::Roll(RollResult::D6(away_roll)) if self.teams.is_none() => {
+                let ordering = roll_off(game_state, self.home_roll.unwrap(), away_roll, |team| {
+                    team.fan_factor
+                });
+                self.teams = Some(roll_off_lowest(ordering));
+                self.next_team(game_state)
+            }
+            ProcInput::Roll(RollResult::RandomPlayers([Some(id), ..])) => {
+                self.player = Some(id);
+                ProcState::NeedRoll(RequestedRoll::D6)
+            }
+            ProcInput::Roll(RollResult::D6(roll)) => {
+                let id = self.player.take().unwrap();
+                if roll == D6::One {
+                    ProcState::NotDoneNew(casualty_procs::Ejection::new(id))
+                } else {
+                    let player = game_state.get_mut_player_unsafe(id);
+                    player.status = PlayerStatus::Stunned;
+                    self.next_team(game_state)
+                }
+            }
+            _ => panic!("Unexpected input {:?}", input),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PitchInvasion {
+    home_roll: Option<D6>,
+    teams: Option<Vec<TeamType>>,
+    team: Option<TeamType>,
+}
+impl PitchInvasion {
+    pub fn new() -> AnyProc {
+        AnyProc::PitchInvasion(PitchInvasion {
+            home_roll: None,
+            teams: None,
+            team: None,
+        })
+    }
+    fn next_team(&mut self, game_state: &GameState) -> ProcState {
+        while let Some(team) = self.teams.as_mut().unwrap().pop() {
+            if game_state.get_players_on_pitch_in_team(team).count() > 0 {
+                self.team = Some(team);
+                return ProcState::NeedRoll(RequestedRoll::D3);
+            }
+        }
+        ProcState::Done
+    }
+}
+impl Procedure for PitchInvasion {
+    fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
+        match input {
+            ProcInput::Nothing => ProcState::NeedRoll(RequestedRoll::D6),
+            ProcInput::Roll(RollResult::D6(roll)) if self.home_roll.is_none() => {
+                self.home_roll = Some(roll);
+                ProcState::NeedRoll(RequestedRoll::D6)
+            }
+            ProcInput::Roll(RollResult::D6(away_roll)) => {
+                let ordering = roll_off(game_state, self.home_roll.unwrap(), away_roll, |team| {
+                    team.fan_factor
+                });
+                self.teams = Some(roll_off_lowest(ordering));
+                self.next_team(game_state)
+            }
+            ProcInput::Roll(RollResult::D3(roll)) => {
+                ProcState::NeedRoll(RequestedRoll::RandomPlayers(self.team.unwrap(), roll as u8))
+            }
+            ProcInput::Roll(RollResult::RandomPlayers(ids)) => {
+                for id in ids.into_iter().flatten() {
+                    game_state.get_mut_player_unsafe(id).status = PlayerStatus::Stunned;
+                }
+                self.next_team(game_state)
+            }
+            _ => panic!("Unexpected input {:?}", input),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ChangingWeather {}
 impl ChangingWeather {
@@ -144,6 +725,96 @@ impl Procedure for ChangingWeather {
     }
 }
 
+/// Once the kick-off event is resolved, an open receiving player with Kick-off Return that isn't
+/// on the line of scrimmage may move up to three squares within their own half, unless the kick
+/// is a touchback. Entering a tackle zone ends the move, as there's no dodging out of it.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct KickoffReturn {
+    team: TeamType,
+    selected: Option<PlayerID>,
+}
+impl KickoffReturn {
+    pub fn new(team: TeamType) -> AnyProc {
+        AnyProc::KickoffReturn(KickoffReturn {
+            team,
+            selected: None,
+        })
+    }
+    fn reachable_positions(&self, game_state: &GameState, id: PlayerID) -> Vec<Position> {
+        let start = game_state.get_player_unsafe(id).position;
+        let marked = |pos: Position| {
+            game_state
+                .get_adj_players(pos)
+                .any(|p| p.stats.team != self.team && p.has_tackle_zone())
+        };
+        let mut reached: Vec<Position> = Vec::new();
+        let mut frontier = vec![start];
+        for _ in 0..3 {
+            frontier = frontier
+                .into_iter()
+                .filter(|pos| *pos == start || !marked(*pos))
+                .flat_map(|pos| game_state.get_adj_positions(pos))
+                .filter(|pos| !pos.is_out() && pos.is_on_team_side(self.team))
+                .filter(|pos| game_state.get_player_id_at(*pos).is_none())
+                .unique()
+                .filter(|pos| !reached.contains(pos))
+                .collect();
+            reached.extend(frontier.iter());
+        }
+        reached
+    }
+    fn returners(&self, game_state: &GameState) -> Vec<Position> {
+        get_open_players(game_state, self.team)
+            .into_iter()
+            .filter(|id| {
+                game_state
+                    .get_player_unsafe(*id)
+                    .has_skill(Skill::KickoffReturn)
+            })
+            .filter(|id| !self.reachable_positions(game_state, *id).is_empty())
+            .map(|id| game_state.get_player_unsafe(id).position)
+            .filter(|pos| !is_on_line_of_scrimmage(game_state, self.team, *pos))
+            .collect()
+    }
+}
+impl Procedure for KickoffReturn {
+    fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
+        match input {
+            ProcInput::Nothing => {
+                let BallState::InAir(ball_position) = game_state.ball else {
+                    return ProcState::Done;
+                };
+                if ball_position.is_out() || !ball_position.is_on_team_side(self.team) {
+                    return ProcState::Done;
+                }
+                let returners = self.returners(game_state);
+                if returners.is_empty() {
+                    return ProcState::Done;
+                }
+                let mut aa = AvailableActions::new(self.team);
+                aa.insert_positional(PosAT::SelectPosition, returners);
+                aa.insert_simple(SimpleAT::Decline);
+                ProcState::NeedAction(aa)
+            }
+            ProcInput::Action(Action::Positional(PosAT::SelectPosition, position)) => {
+                let id = game_state.get_player_id_at(position).unwrap();
+                self.selected = Some(id);
+                let mut aa = AvailableActions::new(self.team);
+                aa.insert_positional(PosAT::PlacePlayer, self.reachable_positions(game_state, id));
+                ProcState::NeedAction(aa)
+            }
+            ProcInput::Action(Action::Positional(PosAT::PlacePlayer, position)) => {
+                game_state
+                    .move_player(self.selected.unwrap(), position)
+                    .unwrap();
+                ProcState::Done
+            }
+            ProcInput::Action(Action::Simple(SimpleAT::Decline)) => ProcState::Done,
+            _ => panic!("Unexpected input {:?}", input),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct LandKickoff {}
 impl LandKickoff {
@@ -163,23 +834,95 @@ impl Procedure for LandKickoff {
             return ProcState::DoneNew(ball_procs::Touchback::new());
         }
 
-        match game_state.get_player_id_at(ball_position) {
-            Some(id) => ProcState::DoneNew(ball_procs::Catch::new_with_kick_arg(
-                id,
-                game_state.get_catch_target(id).unwrap(),
-                true,
-            )),
-            None => ProcState::DoneNew(ball_procs::Bounce::new_with_kick_arg(true)),
+        match game_state.get_player_at(ball_position) {
+            Some(player) if player.can_catch() => {
+                ProcState::DoneNew(ball_procs::Catch::new_with_kick_arg(
+                    player.id,
+                    game_state.get_catch_target(player.id).unwrap(),
+                    true,
+                ))
+            }
+            _ => ProcState::DoneNew(ball_procs::Bounce::new_with_kick_arg(true)),
         }
     }
 }
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Setup {
     team: TeamType,
+    selected: Option<PlayerID>,
 }
 impl Setup {
     pub fn new(team: TeamType) -> AnyProc {
-        AnyProc::Setup(Setup { team })
+        AnyProc::Setup(Setup {
+            team,
+            selected: None,
+        })
+    }
+    fn get_reserve(&self, game_state: &GameState, role: PlayerRole) -> Option<DugoutPlayerID> {
+        game_state
+            .get_dugout()
+            .find(|p| {
+                p.stats.team == self.team
+                    && p.place == DugoutPlace::Reserves
+                    && p.stats.role == role
+            })
+            .map(|p| p.id)
+    }
+    fn reserve_roles<'a>(
+        &'a self,
+        game_state: &'a GameState,
+    ) -> impl Iterator<Item = PlayerRole> + 'a {
+        [
+            PlayerRole::Lineman,
+            PlayerRole::Blitzer,
+            PlayerRole::Thrower,
+            PlayerRole::Catcher,
+        ]
+        .into_iter()
+        .filter(|role| self.get_reserve(game_state, *role).is_some())
+    }
+    fn available_actions(&self, game_state: &GameState) -> Box<AvailableActions> {
+        let mut aa = AvailableActions::new(self.team);
+        let own_half =
+            Position::all_positions().filter(|pos| !pos.is_out() && pos.is_on_team_side(self.team));
+
+        if self.selected.is_some() {
+            let positions = own_half
+                .filter(|pos| match game_state.get_player_at(*pos) {
+                    Some(player) => player.stats.team == self.team,
+                    None => true,
+                })
+                .collect();
+            aa.insert_positional(PosAT::PlacePlayer, positions);
+            return aa;
+        }
+
+        let fielded: Vec<Position> = game_state
+            .get_players_on_pitch_in_team(self.team)
+            .map(|p| p.position)
+            .collect();
+        if fielded.len() < MAX_PLAYERS_ON_PITCH {
+            let empty: Vec<Position> = own_half
+                .filter(|pos| game_state.get_player_id_at(*pos).is_none())
+                .collect();
+            for role in self.reserve_roles(game_state) {
+                aa.insert_positional(PosAT::from(role), empty.clone());
+            }
+        }
+        aa.insert_positional(PosAT::SelectPosition, fielded.clone());
+        aa.insert_positional(PosAT::SendToReserves, fielded);
+        aa.insert_simple(SimpleAT::SetupLine);
+        aa.insert_simple(SimpleAT::SetupWedge);
+        aa.insert_simple(SimpleAT::SetupSpread);
+        aa.insert_simple(SimpleAT::SetupZone);
+        aa.insert_simple(SimpleAT::SetupKickToBlitzers);
+        for index in 0..game_state.formations.len() {
+            aa.insert_simple(SimpleAT::SetupCustom(index as u8));
+        }
+        if game_state.is_setup_legal(self.team) {
+            aa.insert_simple(SimpleAT::EndSetup);
+        }
+        aa
     }
     fn get_empty_pos_in_box(
         game_state: &GameState,
@@ -199,7 +942,7 @@ impl Setup {
         #[allow(clippy::needless_collect)]
         let players: Vec<PlayerID> = game_state
             .get_dugout()
-            .take(11)
+            .take(MAX_PLAYERS_ON_PITCH)
             .filter(|dplayer| dplayer.stats.team == self.team)
             .map(|p| p.id)
             .collect();
@@ -230,78 +973,217 @@ impl Setup {
             game_state.field_dugout_player(id, p);
         }
     }
-    fn setup_line(&self, game_state: &mut GameState) -> Result<()> {
-        //unfield all players
-        let player_ids = game_state
-            .get_players_on_pitch_in_team(self.team)
-            .map(|p| p.id)
-            .collect::<Vec<_>>();
-        for id in player_ids {
-            game_state.unfield_player(id, DugoutPlace::Reserves)?;
-        }
-        let mut linemen_pos = vec![(0, 0), (0, -1), (0, 1), (0, -3), (0, 3)];
-        let mut blitzer_pos = vec![(0, -2), (0, 2)];
-        let mut catcher_pos = vec![(2, 2), (2, -2)];
-        let mut thrower_pos = vec![(6, 3), (6, -3)];
-        #[allow(clippy::needless_collect)]
-        let players: Vec<PlayerID> = game_state
-            .get_dugout()
-            .filter(|dplayer| dplayer.stats.team == self.team)
-            .filter(|dplayer| dplayer.place == DugoutPlace::Reserves)
-            .map(|p| p.id)
-            .collect();
-        let x_delta_sign = if self.team == TeamType::Home { 1 } else { -1 };
-        let middle_x = game_state.get_line_of_scrimage_x(self.team);
-        let middle_y = HEIGHT_ / 2;
-        for id in players {
-            let player = game_state.get_dugout_player(id).unwrap();
-            let (dx, dy) = {
-                match player.stats.role {
-                    PlayerRole::Blitzer if !blitzer_pos.is_empty() => blitzer_pos.pop().unwrap(),
-                    PlayerRole::Thrower if !thrower_pos.is_empty() => thrower_pos.pop().unwrap(),
-                    PlayerRole::Catcher if !catcher_pos.is_empty() => catcher_pos.pop().unwrap(),
-                    PlayerRole::Lineman if !linemen_pos.is_empty() => linemen_pos.pop().unwrap(),
-                    _ => continue,
-                }
-            };
-            let position = Position::new((middle_x + dx * x_delta_sign, middle_y + dy));
-            game_state.log(format!(
-                "fielding {:?} {:?} at {:?}",
-                player.stats.role, player.stats.team, position
-            ));
-            game_state.field_dugout_player(id, position)
-        }
-        Ok(())
-    }
 }
 impl Procedure for Setup {
     fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
-        let mut aa = AvailableActions::new(self.team);
-        if input == ProcInput::Nothing {
-            aa.insert_simple(SimpleAT::SetupLine);
-            return ProcState::NeedAction(aa);
-        }
-
         match input {
             ProcInput::Action(Action::Simple(SimpleAT::SetupLine)) => {
-                self.setup_line(game_state).unwrap();
-                aa.insert_simple(SimpleAT::EndSetup);
-                ProcState::NeedAction(aa)
+                Formation::line().setup(game_state, self.team).unwrap();
             }
-
-            ProcInput::Action(Action::Simple(SimpleAT::EndSetup)) => ProcState::Done,
+            ProcInput::Action(Action::Simple(SimpleAT::SetupWedge)) => {
+                Formation::wedge().setup(game_state, self.team).unwrap();
+            }
+            ProcInput::Action(Action::Simple(SimpleAT::SetupSpread)) => {
+                Formation::spread().setup(game_state, self.team).unwrap();
+            }
+            ProcInput::Action(Action::Simple(SimpleAT::SetupZone)) => {
+                Formation::zone().setup(game_state, self.team).unwrap();
+            }
+            ProcInput::Action(Action::Simple(SimpleAT::SetupKickToBlitzers)) => {
+                Formation::kick_to_blitzers()
+                    .setup(game_state, self.team)
+                    .unwrap();
+            }
+            ProcInput::Action(Action::Simple(SimpleAT::SetupCustom(index))) => {
+                let formation = game_state.formations[index as usize].clone();
+                formation.setup(game_state, self.team).unwrap();
+            }
+            ProcInput::Action(Action::Simple(SimpleAT::EndSetup)) => return ProcState::Done,
+            ProcInput::Action(Action::Positional(PosAT::SelectPosition, position)) => {
+                self.selected = game_state.get_player_id_at(position);
+            }
+            ProcInput::Action(Action::Positional(PosAT::PlacePlayer, position)) => {
+                let id = self.selected.take().unwrap();
+                match game_state.get_player_id_at(position) {
+                    None => game_state.move_player(id, position).unwrap(),
+                    Some(other_id) if other_id != id => {
+                        game_state.swap_players(id, other_id).unwrap()
+                    }
+                    Some(_) => (),
+                }
+            }
+            ProcInput::Action(Action::Positional(PosAT::SendToReserves, position)) => {
+                let id = game_state.get_player_id_at(position).unwrap();
+                game_state
+                    .unfield_player(id, DugoutPlace::Reserves)
+                    .unwrap();
+            }
+            ProcInput::Action(Action::Positional(at, position)) => {
+                let role = match at {
+                    PosAT::PlaceLineman => PlayerRole::Lineman,
+                    PosAT::PlaceBlitzer => PlayerRole::Blitzer,
+                    PosAT::PlaceThrower => PlayerRole::Thrower,
+                    PosAT::PlaceCatcher => PlayerRole::Catcher,
+                    _ => unreachable!(),
+                };
+                let id = self.get_reserve(game_state, role).unwrap();
+                game_state.field_dugout_player(id, position);
+            }
+            ProcInput::Nothing => (),
             _ => unreachable!(),
         }
+        ProcState::NeedAction(self.available_actions(game_state))
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::core::dices::D6Target;
     use crate::core::gamestate::{BuilderState, GameState, GameStateBuilder};
     use crate::core::model::*;
+    use crate::core::procedures::kickoff_procs::{Kickoff, KickoffLanding};
     use crate::core::table::*;
     use std::iter::zip;
 
+    #[test]
+    fn setup_free_placement() {
+        let mut state: GameState = GameStateBuilder::new_at_setup();
+        let team = TeamType::Home;
+        assert_eq!(state.available_actions.team, Some(team));
+        assert!(!state.is_legal_action(&Action::Simple(SimpleAT::EndSetup)));
+
+        let (a, b, c) = (
+            Position::new((14, 8)),
+            Position::new((14, 7)),
+            Position::new((17, 8)),
+        );
+        state.step_positional(PosAT::PlaceLineman, a);
+        state.step_positional(PosAT::PlaceBlitzer, b);
+        assert_eq!(
+            state.get_player_at(a).unwrap().stats.role,
+            PlayerRole::Lineman
+        );
+        assert_eq!(
+            state.get_player_at(b).unwrap().stats.role,
+            PlayerRole::Blitzer
+        );
+        assert!(!state.is_legal_action(&Action::Positional(
+            PosAT::PlaceThrower,
+            Position::new((13, 8))
+        )));
+
+        //move to an empty square
+        state.step_positional(PosAT::SelectPosition, a);
+        assert!(!state.is_legal_action(&Action::Simple(SimpleAT::EndSetup)));
+        state.step_positional(PosAT::PlacePlayer, c);
+        assert!(state.get_player_at(a).is_none());
+        assert_eq!(
+            state.get_player_at(c).unwrap().stats.role,
+            PlayerRole::Lineman
+        );
+
+        //swap with a teammate
+        state.step_positional(PosAT::SelectPosition, b);
+        state.step_positional(PosAT::PlacePlayer, c);
+        assert_eq!(
+            state.get_player_at(b).unwrap().stats.role,
+            PlayerRole::Lineman
+        );
+        assert_eq!(
+            state.get_player_at(c).unwrap().stats.role,
+            PlayerRole::Blitzer
+        );
+
+        //back to reserves
+        let num_reserves = state
+            .get_dugout()
+            .filter(|p| p.stats.team == team && p.place == DugoutPlace::Reserves)
+            .count();
+        state.step_positional(PosAT::SendToReserves, c);
+        assert!(state.get_player_at(c).is_none());
+        assert_eq!(
+            state
+                .get_dugout()
+                .filter(|p| p.stats.team == team && p.place == DugoutPlace::Reserves)
+                .count(),
+            num_reserves + 1
+        );
+
+        state.step_simple(SimpleAT::SetupLine);
+        assert!(state.is_setup_legal(team));
+        state.step_simple(SimpleAT::EndSetup);
+        assert_eq!(state.available_actions.team, Some(TeamType::Away));
+    }
+
+    /// Home sets up in a line and then moves the given players
+    fn setup_line_and_move(moves: &[(Position, Position)]) -> GameState {
+        let mut state: GameState = GameStateBuilder::new_at_setup();
+        state.step_simple(SimpleAT::SetupLine);
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::EndSetup)));
+        for (from, to) in moves {
+            state.step_positional(PosAT::SelectPosition, *from);
+            state.step_positional(PosAT::PlacePlayer, *to);
+        }
+        state
+    }
+
+    #[test]
+    fn setup_max_players_on_pitch() {
+        let mut state = setup_line_and_move(&[]);
+        let team = TeamType::Home;
+        assert_eq!(
+            state.get_players_on_pitch_in_team(team).count(),
+            MAX_PLAYERS_ON_PITCH
+        );
+        let reserve = state
+            .get_dugout()
+            .find(|p| p.stats.team == team && p.place == DugoutPlace::Reserves)
+            .unwrap();
+        let place_reserve =
+            Action::Positional(PosAT::from(reserve.stats.role), Position::new((24, 8)));
+        assert!(!state.is_legal_action(&place_reserve));
+
+        state.field_dugout_player(reserve.id, Position::new((24, 8)));
+        assert!(!state.is_setup_legal(team));
+    }
+
+    #[test]
+    fn setup_line_of_scrimmage_minimum() {
+        let moves: Vec<_> = (5..=9)
+            .map(|y| (Position::new((14, y)), Position::new((15, y))))
+            .collect();
+        let state = setup_line_and_move(&moves);
+        assert!(!state.is_legal_action(&Action::Simple(SimpleAT::EndSetup)));
+
+        let state = setup_line_and_move(&moves[..4]);
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::EndSetup)));
+    }
+
+    #[test]
+    fn setup_wide_zones() {
+        let moves = [((16, 6), (17, 1)), ((16, 10), (17, 2)), ((20, 5), (17, 3))]
+            .map(|(from, to)| (Position::new(from), Position::new(to)));
+        let state = setup_line_and_move(&moves);
+        assert!(!state.is_legal_action(&Action::Simple(SimpleAT::EndSetup)));
+
+        let state = setup_line_and_move(&moves[..2]);
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::EndSetup)));
+    }
+
+    #[test]
+    fn setup_own_half() {
+        let mut state = setup_line_and_move(&[]);
+        let team = TeamType::Home;
+        state.step_positional(PosAT::SelectPosition, Position::new((16, 6)));
+        let other_half = Action::Positional(PosAT::PlacePlayer, Position::new((13, 3)));
+        assert!(!state.is_legal_action(&other_half));
+        state.step_positional(PosAT::PlacePlayer, Position::new((16, 6)));
+
+        let id = state.get_player_id_at(Position::new((16, 6))).unwrap();
+        state.move_player(id, Position::new((13, 3))).unwrap();
+        assert!(!state.is_setup_legal(team));
+    }
+
     #[test]
     fn test_setup_preconfigured_formations() {
         let mut state: GameState = GameStateBuilder::new_at_setup();
@@ -357,6 +1239,79 @@ mod tests {
         }
     }
 
+    #[test]
+    fn kickoff_aim_at_receiver() {
+        let mut state: GameState = GameStateBuilder::new_at_kickoff();
+        let aim = Position::new((15, 10));
+        let catcher_pos = Position::new((16, 10));
+        assert!(state.is_legal_action(&Action::Positional(PosAT::KickoffAim, aim)));
+        assert!(!state.is_legal_action(&Action::Positional(
+            PosAT::KickoffAim,
+            Position::new((13, 10))
+        )));
+
+        state.fixes.fix_d8_direction(Direction::right()); // scatter direction
+        state.fixes.fix_d6(1); // scatter length
+        state.fixes.fix_d6(1); // get the ref
+        state.fixes.fix_d6(1);
+        state.fixes.fix_d6(6); // catch
+        state.step_positional(PosAT::KickoffAim, aim);
+
+        let catcher_id = state.get_player_id_at(catcher_pos).unwrap();
+        assert_eq!(state.ball, BallState::Carried(catcher_id));
+        assert!(state.home_to_act());
+    }
+
+    #[test]
+    fn kickoff_landing_distribution() {
+        let aim = Position::new((22, 3));
+        let mut state: GameState = GameStateBuilder::new_at_kickoff();
+        let kicking_team = state.info.kicking_this_drive;
+        let prob_of = |distribution: &[(KickoffLanding, f32)], landing| {
+            distribution
+                .iter()
+                .find(|(l, _)| *l == landing)
+                .map_or(0.0, |(_, prob)| *prob)
+        };
+
+        let distribution = Kickoff::landing_distribution(&state, aim);
+        let total: f32 = distribution.iter().map(|(_, prob)| prob).sum();
+        assert!((total - 1.0).abs() < 1e-5);
+        assert!(distribution.iter().all(|(landing, _)| match landing {
+            KickoffLanding::Square(pos) => pos.distance_to(&aim) <= 6 && !pos.is_out(),
+            KickoffLanding::Touchback => true,
+        }));
+        let touchback = prob_of(&distribution, KickoffLanding::Touchback);
+        assert!(touchback > 0.0);
+        assert_eq!(
+            distribution.len(),
+            48 - (touchback * 48.0).round() as usize + 1
+        );
+        assert_eq!(prob_of(&distribution, KickoffLanding::Square(aim)), 0.0);
+
+        // with Kick the deviation is halved, a 1 lands on the aim
+        let kicking_ids: Vec<PlayerID> = state
+            .get_players_on_pitch_in_team(kicking_team)
+            .map(|p| p.id)
+            .collect();
+        for id in kicking_ids {
+            state
+                .get_mut_player_unsafe(id)
+                .stats
+                .give_skill(Skill::Kick);
+        }
+        let distribution = Kickoff::landing_distribution(&state, aim);
+        let total: f32 = distribution.iter().map(|(_, prob)| prob).sum();
+        assert!((total - 1.0).abs() < 1e-5);
+        let on_aim = prob_of(&distribution, KickoffLanding::Square(aim));
+        assert!((on_aim - 1.0 / 6.0).abs() < 1e-5);
+        assert!(distribution.iter().all(|(landing, _)| match landing {
+            KickoffLanding::Square(pos) => pos.distance_to(&aim) <= 3,
+            KickoffLanding::Touchback => true,
+        }));
+        assert!(prob_of(&distribution, KickoffLanding::Touchback) < touchback);
+    }
+
     #[test]
     fn kickoff_get_the_ref() {
         let mut state: GameState = GameStateBuilder::new_at_kickoff();
@@ -427,99 +1382,399 @@ mod tests {
         assert_eq!(state.info.home_turn, 6);
         assert_eq!(state.info.away_turn, 5);
     }
-    // #[test]
-    // fn kickoff_solid_defence() {
-    //     let mut state: GameState = GameStateBuilder::new_at_kickoff();
-    //     // ball fixes
-    //     state.fixes.fix_d8_direction(Direction::up()); // scatter direction
-    //     state.fixes.fix_d6(5); // scatter length
-    //
-    //     // kickoff event fix
-    //     state.fixes.fix_d6(1);
-    //     state.fixes.fix_d6(3);
-    //
-    //     state.fixes.fix_d6(6); //fix number of re-arranged players (d3+3)
-    //     state.step_simple(SimpleAT::KickoffAimMiddle);
-    //
-    //     // TODO: haven't implemented the setup yet
-    // }
-    //
-    // #[test]
-    // fn kickoff_high_kick() {
-    //     let mut state: GameState = GameStateBuilder::new_at_kickoff();
-    //     // ball fixes
-    //     state.fixes.fix_d8_direction(Direction::up()); // scatter direction
-    //     state.fixes.fix_d6(5); // scatter length
-    //
-    //     // kickoff event fix
-    //     state.fixes.fix_d6(1);
-    //     state.fixes.fix_d6(4);
-    //
-    //     state.step_simple(SimpleAT::KickoffAimMiddle);
-    //
-    //     let ball_pos = state.get_ball_position().unwrap();
-    //     assert!(matches!(state.ball, BallState::InAir(_)));
-    //
-    //     assert!(state.home_to_act());
-    //     let legal_positions = [(2, 9), (7, 9)]; //Open players
-    //     for pos in legal_positions {
-    //         let action = Action::Positional(PosAT::SelectPosition, Position::new(pos));
-    //         assert!(state.available_actions.is_legal_action(action));
-    //     }
-    //
-    //     let catcher_start_pos = Position::new(legal_positions[0]);
-    //     let catcher_id = state.get_player_id_at(catcher_start_pos).unwrap();
-    //
-    //     state.fixes.fix_d6(6); // fix the roll for the catch
-    //     state.step_positional(PosAT::SelectPosition, Position::new(legal_positions[0]));
-    //
-    //     assert_eq!(state.get_player_id_at(ball_pos).unwrap(), catcher_id);
-    //     assert_eq!(state.get_player_id_at(catcher_start_pos), None);
-    //
-    //     match state.ball {
-    //         BallState::Carried(id) => {
-    //             assert_eq!(id, catcher_id);
-    //         }
-    //         _ => panic!("ball should be carried"),
-    //     }
-    //
-    //     assert!(state.home_to_act());
-    // }
-    //
-    // #[test]
-    // fn kickoff_cheering_fans() {
-    //     let mut state: GameState = GameStateBuilder::new_at_kickoff();
-    //     // ball fixes
-    //     state.fixes.fix_d8_direction(Direction::up()); // scatter direction
-    //     state.fixes.fix_d6(5); // scatter length
-    //
-    //     // kickoff event fix
-    //     state.fixes.fix_d6(1);
-    //     state.fixes.fix_d6(5);
-    //     // TODO: Implement prayers to nuffle...
-    //
-    //     state.step_simple(SimpleAT::KickoffAimMiddle);
-    // }
-    //
-    // #[test]
-    // fn kickoff_brilliant_coaching() {
-    //     let mut state: GameState = GameStateBuilder::new_at_kickoff();
-    //     // ball fixes
-    //     state.fixes.fix_d8_direction(Direction::up()); // scatter direction
-    //     state.fixes.fix_d6(5); // scatter length
-    //
-    //     // kickoff event fix
-    //     state.fixes.fix_d6(1);
-    //     state.fixes.fix_d6(1);
-    //
-    //     state.fixes.fix_d6(5); //fix home brilliant coaching roll
-    //     state.fixes.fix_d6(6); //fix away brilliant coaching roll
-    //
-    //     state.step_simple(SimpleAT::KickoffAimMiddle);
-    //
-    //     assert_eq!(state.away.rerolls, 4);
-    //     assert_eq!(state.home.rerolls, 3);
-    // }
+    /// Fixes the dice for a kickoff that lands on (21, 2) with the given kickoff table result
+    fn fix_kickoff(state: &mut GameState, kickoff_d6s: (u8, u8)) {
+        // ball fixes
+        state.fixes.fix_d8_direction(Direction::up()); // scatter direction
+        state.fixes.fix_d6(5); // scatter length
+
+        // kickoff event fix
+        state.fixes.fix_d6(kickoff_d6s.0);
+        state.fixes.fix_d6(kickoff_d6s.1);
+    }
+
+    #[test]
+    fn kickoff_solid_defence() {
+        let mut state: GameState = GameStateBuilder::new_at_kickoff();
+        fix_kickoff(&mut state, (1, 3));
+        state.fixes.fix_d3(1); // four players may be set up again
+        state.step_simple(SimpleAT::KickoffAimMiddle);
+
+        assert!(state.away_to_act());
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::EndSetup)));
+        let not_open = Action::Positional(PosAT::SelectPosition, Position::new((13, 8)));
+        assert!(!state.is_legal_action(&not_open));
+
+        // three players in the north wing is not a legal setup
+        let moves = [((7, 5), (7, 2)), ((7, 11), (7, 3)), ((11, 6), (11, 4))];
+        for (from, to) in moves {
+            state.step_positional(PosAT::SelectPosition, Position::new(from));
+            let wrong_half = Action::Positional(PosAT::PlacePlayer, Position::new((20, 2)));
+            assert!(!state.is_legal_action(&wrong_half));
+            state.step_positional(PosAT::PlacePlayer, Position::new(to));
+        }
+        assert!(!state.is_legal_action(&Action::Simple(SimpleAT::EndSetup)));
+
+        // moving a player again doesn't count as another player
+        state.step_positional(PosAT::SelectPosition, Position::new((11, 4)));
+        state.step_positional(PosAT::PlacePlayer, Position::new((10, 6)));
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::EndSetup)));
+
+        state.fixes.fix_d8_direction(Direction::down()); // bounce
+        state.step_simple(SimpleAT::EndSetup);
+
+        assert!(state.home_to_act());
+        assert!(state.get_player_at(Position::new((7, 5))).is_none());
+        assert!(state.get_player_at(Position::new((10, 6))).is_some());
+        assert_eq!(state.ball, BallState::OnGround(Position::new((21, 3))));
+    }
+
+    #[test]
+    fn kickoff_high_kick() {
+        let mut state: GameState = GameStateBuilder::new_at_kickoff();
+        fix_kickoff(&mut state, (1, 4));
+        state.step_simple(SimpleAT::KickoffAimMiddle);
+
+        let ball_pos = state.get_ball_position().unwrap();
+        assert!(matches!(state.ball, BallState::InAir(_)));
+
+        assert!(state.home_to_act());
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::Decline)));
+        let legal_positions = [(16, 10), (16, 6), (20, 11), (20, 5)]; //Open players
+        for pos in legal_positions {
+            let action = Action::Positional(PosAT::SelectPosition, Position::new(pos));
+            assert!(state.is_legal_action(&action));
+        }
+        let marked_player = Action::Positional(PosAT::SelectPosition, Position::new((14, 8)));
+        assert!(!state.is_legal_action(&marked_player));
+
+        let catcher_start_pos = Position::new(legal_positions[0]);
+        let catcher_id = state.get_player_id_at(catcher_start_pos).unwrap();
+
+        state.fixes.fix_d6(6); // fix the roll for the catch
+        state.step_positional(PosAT::SelectPosition, catcher_start_pos);
+
+        assert_eq!(state.get_player_id_at(ball_pos).unwrap(), catcher_id);
+        assert_eq!(state.get_player_id_at(catcher_start_pos), None);
+        assert_eq!(state.ball, BallState::Carried(catcher_id));
+        assert!(state.home_to_act());
+    }
+
+    #[test]
+    fn kickoff_cheering_fans() {
+        let mut state: GameState = GameStateBuilder::new_at_kickoff();
+        fix_kickoff(&mut state, (1, 5));
+        state.fixes.fix_d6(3); // home roll
+        state.fixes.fix_d6(2); // away roll
+        state.fixes.fix_d16(9); // home prays for moles under the pitch
+        state.fixes.fix_d8_direction(Direction::down()); // bounce
+        state.step_simple(SimpleAT::KickoffAimMiddle);
+
+        assert!(state.home_to_act());
+        assert_eq!(state.ball, BallState::OnGround(Position::new((21, 3))));
+        assert_eq!(
+            state.info.prayers,
+            vec![(TeamType::Home, PrayerToNuffle::MolesUnderThePitch)]
+        );
+        assert_eq!(state.get_rush_target(), D6Target::ThreePlus);
+    }
+
+    #[test]
+    fn kickoff_cheering_fans_player_of_choice() {
+        let mut state: GameState = GameStateBuilder::new_at_kickoff();
+        fix_kickoff(&mut state, (1, 5));
+        state.fixes.fix_d6(3); // home roll
+        state.fixes.fix_d6(2); // away roll
+        state.fixes.fix_d16(5); // home prays for knuckle dusters
+        state.step_simple(SimpleAT::KickoffAimMiddle);
+
+        let player = state
+            .get_players_on_pitch_in_team(TeamType::Home)
+            .next()
+            .unwrap();
+        let (id, position) = (player.id, player.position);
+        assert!(state.home_to_act());
+        assert!(state.is_legal_action(&Action::Positional(PosAT::SelectPosition, position)));
+
+        state.fixes.fix_d8_direction(Direction::down()); // bounce
+        state.step_positional(PosAT::SelectPosition, position);
+        assert!(state.get_player_unsafe(id).has_skill(Skill::MightyBlow));
+        assert_eq!(state.ball, BallState::OnGround(Position::new((21, 3))));
+    }
+
+    #[test]
+    fn kickoff_brilliant_coaching() {
+        let mut state: GameState = GameStateBuilder::new_at_kickoff();
+        fix_kickoff(&mut state, (3, 4));
+        state.fixes.fix_d6(5); //fix home brilliant coaching roll
+        state.fixes.fix_d6(6); //fix away brilliant coaching roll
+        state.fixes.fix_d8_direction(Direction::down()); // bounce
+
+        state.step_simple(SimpleAT::KickoffAimMiddle);
+
+        assert_eq!(state.away.rerolls, 4);
+        assert_eq!(state.home.rerolls, 3);
+
+        // the extra re-roll is lost when the drive ends
+        state.away.end_drive();
+        assert_eq!(state.away.rerolls, 3);
+    }
+
+    #[test]
+    fn kickoff_brilliant_coaching_tie() {
+        let mut state: GameState = GameStateBuilder::new_at_kickoff();
+        state.away.ass_coaches = 1;
+        fix_kickoff(&mut state, (3, 4));
+        state.fixes.fix_d6(5); //fix home brilliant coaching roll
+        state.fixes.fix_d6(4); //fix away brilliant coaching roll
+        state.fixes.fix_d8_direction(Direction::down()); // bounce
+
+        state.step_simple(SimpleAT::KickoffAimMiddle);
+
+        assert_eq!(state.away.rerolls, 4);
+        assert_eq!(state.home.rerolls, 4);
+    }
+
+    #[test]
+    fn kick_halves_deviation() {
+        let mut state: GameState = GameStateBuilder::new_at_kickoff();
+        let kicker_pos = Position::new((11, 6));
+        let on_line = Position::new((13, 8));
+        for pos in [kicker_pos, on_line] {
+            let id = state.get_player_id_at(pos).unwrap();
+            state
+                .get_mut_player_unsafe(id)
+                .stats
+                .give_skill(Skill::Kick);
+        }
+        state.fixes.fix_d8_direction(Direction::up()); // scatter direction
+        state.fixes.fix_d6(5); // scatter length
+        state.step_simple(SimpleAT::KickoffAimMiddle);
+
+        assert!(state.away_to_act());
+        let on_line_kicker = Action::Positional(PosAT::SelectPosition, on_line);
+        assert!(!state.is_legal_action(&on_line_kicker));
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::Decline)));
+
+        state.fixes.fix_d6(1); // get the ref
+        state.fixes.fix_d6(1);
+        state.fixes.fix_d8_direction(Direction::down()); // bounce
+        state.step_positional(PosAT::SelectPosition, kicker_pos);
+
+        assert!(state.home_to_act());
+        assert_eq!(state.ball, BallState::OnGround(Position::new((21, 6))));
+    }
+
+    #[test]
+    fn kickoff_return() {
+        let mut state: GameState = GameStateBuilder::new_at_kickoff();
+        let returner_pos = Position::new((16, 6));
+        let on_line = Position::new((14, 8));
+        for pos in [returner_pos, on_line] {
+            let id = state.get_player_id_at(pos).unwrap();
+            state
+                .get_mut_player_unsafe(id)
+                .stats
+                .give_skill(Skill::KickoffReturn);
+        }
+        let id = state.get_player_id_at(returner_pos).unwrap();
+        fix_kickoff(&mut state, (1, 1));
+        state.step_simple(SimpleAT::KickoffAimMiddle);
+
+        assert!(state.home_to_act());
+        assert!(matches!(state.ball, BallState::InAir(_)));
+        let on_line_returner = Action::Positional(PosAT::SelectPosition, on_line);
+        assert!(!state.is_legal_action(&on_line_returner));
+        state.step_positional(PosAT::SelectPosition, returner_pos);
+
+        let four_squares = Action::Positional(PosAT::PlacePlayer, returner_pos + (4, 0));
+        assert!(!state.is_legal_action(&four_squares));
+        state.fixes.fix_d8_direction(Direction::down()); // bounce
+        state.step_positional(PosAT::PlacePlayer, returner_pos + (3, -2));
+
+        assert!(state.home_to_act());
+        assert_eq!(state.get_player_unsafe(id).position, returner_pos + (3, -2));
+        assert_eq!(state.ball, BallState::OnGround(Position::new((21, 3))));
+    }
+
+    #[test]
+    fn kickoff_quick_snap() {
+        let mut state: GameState = GameStateBuilder::new_at_kickoff();
+        fix_kickoff(&mut state, (4, 5));
+        state.fixes.fix_d3(1); // four players may move
+        state.step_simple(SimpleAT::KickoffAimMiddle);
+
+        assert!(state.home_to_act());
+        let marked_player = Action::Positional(PosAT::SelectPosition, Position::new((14, 8)));
+        assert!(!state.is_legal_action(&marked_player));
+
+        let start_pos = Position::new((16, 6));
+        let id = state.get_player_id_at(start_pos).unwrap();
+        state.step_positional(PosAT::SelectPosition, start_pos);
+        let two_squares = Action::Positional(PosAT::PlacePlayer, start_pos + (-2, 0));
+        assert!(!state.is_legal_action(&two_squares));
+        state.step_positional(PosAT::PlacePlayer, start_pos + (-1, 0));
+
+        let moved_player = Action::Positional(PosAT::SelectPosition, start_pos + (-1, 0));
+        assert!(!state.is_legal_action(&moved_player));
+
+        state.fixes.fix_d8_direction(Direction::down()); // bounce
+        state.step_simple(SimpleAT::EndSetup);
+
+        assert!(state.home_to_act());
+        assert_eq!(state.get_player_unsafe(id).position, start_pos + (-1, 0));
+    }
+
+    #[test]
+    fn kickoff_blitz() {
+        let mut state: GameState = GameStateBuilder::new_at_kickoff();
+        fix_kickoff(&mut state, (4, 6));
+        state.fixes.fix_d3(1); // four players may be activated
+        state.step_simple(SimpleAT::KickoffAimMiddle);
+
+        assert!(state.away_to_act());
+        let marked_player = Action::Positional(PosAT::StartMove, Position::new((13, 8)));
+        assert!(!state.is_legal_action(&marked_player));
+
+        let open_players = [(7, 5), (7, 11), (11, 6), (11, 10)];
+        let ids: Vec<PlayerID> = open_players
+            .iter()
+            .map(|&pos| state.get_player_id_at(Position::new(pos)).unwrap())
+            .collect();
+
+        state.step_positional(PosAT::StartBlitz, Position::new(open_players[0]));
+        state.step_positional(PosAT::Move, Position::new((9, 5)));
+        state.step_simple(SimpleAT::EndPlayerTurn);
+
+        let blitz = Action::Positional(PosAT::StartBlitz, Position::new(open_players[1]));
+        assert!(!state.is_legal_action(&blitz));
+
+        for pos in &open_players[1..3] {
+            state.step_positional(PosAT::StartMove, Position::new(*pos));
+            state.step_simple(SimpleAT::EndPlayerTurn);
+        }
+
+        // the fourth activation ends the blitz
+        state.step_positional(PosAT::StartMove, Position::new(open_players[3]));
+        state.fixes.fix_d8_direction(Direction::down()); // bounce
+        state.step_simple(SimpleAT::EndPlayerTurn);
+
+        assert!(state.home_to_act());
+        assert_eq!(state.info.team_turn, TeamType::Home);
+        assert_eq!(
+            state.get_player_unsafe(ids[0]).position,
+            Position::new((9, 5))
+        );
+        assert_eq!(state.ball, BallState::OnGround(Position::new((21, 3))));
+    }
+
+    #[test]
+    fn kickoff_blitz_restores_team_turn() {
+        let mut state: GameState = GameStateBuilder::new_at_kickoff();
+        // as in the second half, the receiving team played the last turn before the kick-off
+        state.info.team_turn = TeamType::Home;
+        let returner_pos = Position::new((16, 6));
+        let id = state.get_player_id_at(returner_pos).unwrap();
+        state
+            .get_mut_player_unsafe(id)
+            .stats
+            .give_skill(Skill::KickoffReturn);
+        fix_kickoff(&mut state, (4, 6));
+        state.fixes.fix_d3(1);
+        state.step_simple(SimpleAT::KickoffAimMiddle);
+
+        assert!(state.away_to_act());
+        assert_eq!(state.info.team_turn, TeamType::Away);
+        state.step_simple(SimpleAT::EndTurn);
+
+        // the kick-off return comes after the blitz, before the receiving team's first turn
+        assert!(state.is_legal_action(&Action::Positional(PosAT::SelectPosition, returner_pos)));
+        assert_eq!(state.info.team_turn, TeamType::Home);
+        assert!(!state.info.turnover);
+    }
+
+    #[test]
+    fn kickoff_officious_ref() {
+        let mut state: GameState = GameStateBuilder::new_at_kickoff();
+        fix_kickoff(&mut state, (5, 6));
+        state.fixes.fix_d6(2); // home roll
+        state.fixes.fix_d6(5); // away roll
+
+        let id = state.get_player_id_at(Position::new((20, 5))).unwrap();
+        state.fixes.fix_random_player(id);
+        state.fixes.fix_d6(1); // sent off
+        state.step_simple(SimpleAT::KickoffAimMiddle);
+
+        // the call may be argued on any ejection
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::ArgueTheCall)));
+        state.fixes.fix_d8_direction(Direction::down()); // bounce
+        state.step_simple(SimpleAT::Decline);
+
+        assert!(state.home_to_act());
+        assert!(state.get_player(id).is_err());
+        assert_eq!(
+            state.get_players_on_pitch_in_team(TeamType::Home).count(),
+            10
+        );
+        assert_eq!(
+            state
+                .get_dugout()
+                .filter(|p| p.place == DugoutPlace::Ejected)
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn kickoff_officious_ref_tie() {
+        let mut state: GameState = GameStateBuilder::new_at_kickoff();
+        fix_kickoff(&mut state, (5, 6));
+        state.fixes.fix_d6(3); // home roll
+        state.fixes.fix_d6(3); // away roll
+
+        let away_id = state.get_player_id_at(Position::new((7, 5))).unwrap();
+        let home_id = state.get_player_id_at(Position::new((20, 5))).unwrap();
+        state.fixes.fix_random_player(away_id);
+        state.fixes.fix_d6(4); // stunned
+        state.fixes.fix_random_player(home_id);
+        state.fixes.fix_d6(2); // stunned
+        state.fixes.fix_d8_direction(Direction::down()); // bounce
+        state.step_simple(SimpleAT::KickoffAimMiddle);
+
+        assert!(state.home_to_act());
+        for id in [away_id, home_id] {
+            assert_eq!(state.get_player_unsafe(id).status, PlayerStatus::Stunned);
+        }
+        let stunned_player = Action::Positional(PosAT::StartMove, Position::new((20, 5)));
+        assert!(!state.is_legal_action(&stunned_player));
+    }
+
+    #[test]
+    fn kickoff_pitch_invasion() {
+        let mut state: GameState = GameStateBuilder::new_at_kickoff();
+        fix_kickoff(&mut state, (6, 6));
+        state.fixes.fix_d6(1); // home roll
+        state.fixes.fix_d6(6); // away roll
+        state.fixes.fix_d3(2); // two players are hit
+
+        let ids = [
+            state.get_player_id_at(Position::new((14, 8))).unwrap(),
+            state.get_player_id_at(Position::new((16, 6))).unwrap(),
+        ];
+        ids.iter().for_each(|id| state.fixes.fix_random_player(*id));
+        state.fixes.fix_d8_direction(Direction::down()); // bounce
+        state.step_simple(SimpleAT::KickoffAimMiddle);
+
+        assert!(state.home_to_act());
+        let num_stunned = state
+            .get_players_on_pitch()
+            .filter(|p| p.status == PlayerStatus::Stunned)
+            .count();
+        assert_eq!(num_stunned, 2);
+        for id in ids {
+            assert_eq!(state.get_player_unsafe(id).status, PlayerStatus::Stunned);
+        }
+    }
     // #[test]
     // fn kickoff_changing_weather() {
     //     let mut state: GameState = GameStateBuilder::new_at_kickoff();
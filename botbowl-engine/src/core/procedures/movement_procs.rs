@@ -1,7 +1,11 @@
 use serde::{Deserialize, Serialize};
 
-use crate::core::model::ProcInput;
-use crate::core::model::{Action, AvailableActions, PlayerID, PlayerStatus, ProcState, Procedure};
+use crate::core::dices::{RequestedRoll, RollResult, RollTarget, D6};
+use crate::core::model::{
+    other_team, Action, AvailableActions, BallState, PlayerID, PlayerStatus, Position, ProcState,
+    Procedure,
+};
+use crate::core::model::{ProcInput, Reaction};
 use crate::core::pathing::{
     event_ends_player_action, CustomIntoIter, NodeIterator, PathFinder, PathingEvent,
     PositionOrEvent,
@@ -20,7 +24,7 @@ pub struct GfiProc {
     id: PlayerID,
 }
 impl GfiProc {
-    fn new(id: PlayerID, target: D6Target) -> AnyProc {
+    pub fn new(id: PlayerID, target: D6Target) -> AnyProc {
         AnyProc::GfiProc(SimpleProcContainer::new(GfiProc { target, id }))
     }
 }
@@ -57,20 +61,238 @@ impl Procedure for StandUp {
             game_state.get_player_unsafe(self.id).status,
             PlayerStatus::Down
         );
-        game_state.get_mut_player_unsafe(self.id).status = PlayerStatus::Up;
-        game_state.get_mut_player_unsafe(self.id).add_move(3);
+        let player = game_state.get_mut_player_unsafe(self.id);
+        player.status = PlayerStatus::Up;
+        player.add_move(player.standup_cost());
 
         ProcState::Done
     }
 }
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct JumpUpProc {
+    target: D6Target,
+    id: PlayerID,
+}
+impl JumpUpProc {
+    /// Standing up to block, an agility test with +1
+    pub fn new(id: PlayerID, ag_target: D6Target) -> AnyProc {
+        let mut target = ag_target;
+        target.add_modifer(1);
+        AnyProc::JumpUpProc(SimpleProcContainer::new(JumpUpProc { target, id }))
+    }
+}
+impl SimpleProc for JumpUpProc {
+    fn d6_target(&self) -> D6Target {
+        self.target
+    }
+
+    fn reroll_skill(&self) -> Option<Skill> {
+        None
+    }
+
+    fn apply_success(&self, game_state: &mut GameState) -> Vec<AnyProc> {
+        game_state.get_mut_player_unsafe(self.id).status = PlayerStatus::Up;
+        Vec::new()
+    }
+
+    fn apply_failure(&mut self, game_state: &mut GameState) -> Vec<AnyProc> {
+        // stays down and the activation is over, but it's not a turnover
+        game_state.get_mut_player_unsafe(self.id).used = true;
+        Vec::new()
+    }
+
+    fn player_id(&self) -> PlayerID {
+        self.id
+    }
+}
+
+/// Opposing players with the skill next to the square the dodger left
+fn players_reacting_to_dodge(
+    game_state: &GameState,
+    dodger: PlayerID,
+    from: Position,
+    skill: Skill,
+) -> Vec<Position> {
+    let team = game_state.get_player_unsafe(dodger).stats.team;
+    game_state
+        .get_adj_players(from)
+        .filter(|p| p.stats.team != team && p.has_tackle_zone() && p.has_skill(skill))
+        .map(|p| p.position)
+        .collect()
+}
+
+/// After a passed dodge, jump or leap the opposing coach may dive with a player marking the
+/// square left, giving -2 to the roll
+fn diving_tackle_reaction(
+    game_state: &GameState,
+    id: PlayerID,
+    from: Position,
+) -> Option<(i8, Box<AvailableActions>)> {
+    let tacklers = players_reacting_to_dodge(game_state, id, from, Skill::DivingTackle);
+    if tacklers.is_empty() {
+        return None;
+    }
+    let team = game_state.get_player_unsafe(id).stats.team;
+    let mut aa = AvailableActions::new_reaction(other_team(team), Reaction::DivingTackle);
+    aa.insert_positional(PosAT::SelectPosition, tacklers);
+    Some((-2, aa))
+}
+
+/// The tackler is placed prone in the square left
+fn apply_diving_tackle(game_state: &mut GameState, from: Position, action: Action) -> Vec<AnyProc> {
+    let Action::Positional(PosAT::SelectPosition, position) = action else {
+        panic!("Unexpected action {:?}", action);
+    };
+    let tackler_id = game_state.get_player_id_at(position).unwrap();
+    game_state.move_player(tackler_id, from).unwrap();
+    game_state.get_mut_player_unsafe(tackler_id).status = PlayerStatus::Down;
+    if game_state.ball == BallState::Carried(tackler_id) {
+        game_state.ball = BallState::InAir(from);
+        vec![ball_procs::Bounce::new()]
+    } else {
+        Vec::new()
+    }
+}
+
+/// After a successful dodge, an opposing player with Shadowing may try to follow the dodger
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Shadowing {
+    id: PlayerID,
+    from: Position,
+    shadower: Option<PlayerID>,
+}
+impl Shadowing {
+    pub fn new(id: PlayerID, from: Position) -> AnyProc {
+        AnyProc::Shadowing(Shadowing {
+            id,
+            from,
+            shadower: None,
+        })
+    }
+}
+impl Procedure for Shadowing {
+    fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
+        match input {
+            ProcInput::Nothing => {
+                let team = game_state.get_player_unsafe(self.id).stats.team;
+                let mut aa = AvailableActions::new_reaction(other_team(team), Reaction::Shadowing);
+                aa.insert_positional(
+                    PosAT::SelectPosition,
+                    players_reacting_to_dodge(game_state, self.id, self.from, Skill::Shadowing),
+                );
+                ProcState::NeedAction(aa)
+            }
+            ProcInput::Reaction(
+                Reaction::Shadowing,
+                Action::Positional(PosAT::SelectPosition, position),
+            ) => {
+                self.shadower = game_state.get_player_id_at(position);
+                ProcState::NeedRoll(RequestedRoll::D6)
+            }
+            ProcInput::Reaction(Reaction::Shadowing, Action::Simple(SimpleAT::Decline)) => {
+                ProcState::Done
+            }
+            ProcInput::Roll(RollResult::D6(roll)) => {
+                let shadower_id = self.shadower.unwrap();
+                let shadower_ma = game_state
+                    .get_player_unsafe(shadower_id)
+                    .stats
+                    .movement_allowance();
+                let dodger_ma = game_state
+                    .get_player_unsafe(self.id)
+                    .stats
+                    .movement_allowance();
+                let result = roll as i8 + shadower_ma as i8 - dodger_ma as i8;
+                if roll == D6::Six || (roll != D6::One && result >= 6) {
+                    game_state.move_player(shadower_id, self.from).unwrap();
+                }
+                ProcState::Done
+            }
+            _ => panic!("Unexpected input {:?}", input),
+        }
+    }
+}
+
+/// An opposing player with Tentacles may try to hold a player leaving its tackle zone. If held,
+/// the player is put back in the square and the activation is over.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Tentacles {
+    id: PlayerID,
+    from: Position,
+    event: PathingEvent,
+    holder: Option<PlayerID>,
+}
+impl Tentacles {
+    pub fn new(id: PlayerID, from: Position, event: PathingEvent) -> AnyProc {
+        AnyProc::Tentacles(Tentacles {
+            id,
+            from,
+            event,
+            holder: None,
+        })
+    }
+    fn let_go(&self) -> ProcState {
+        ProcState::DoneNew(proc_from_roll(self.event.clone(), self.id, self.from))
+    }
+}
+impl Procedure for Tentacles {
+    fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
+        match input {
+            ProcInput::Nothing => {
+                let team = game_state.get_player_unsafe(self.id).stats.team;
+                let mut aa = AvailableActions::new_reaction(other_team(team), Reaction::Tentacles);
+                aa.insert_positional(
+                    PosAT::SelectPosition,
+                    players_reacting_to_dodge(game_state, self.id, self.from, Skill::Tentacles),
+                );
+                ProcState::NeedAction(aa)
+            }
+            ProcInput::Reaction(
+                Reaction::Tentacles,
+                Action::Positional(PosAT::SelectPosition, position),
+            ) => {
+                self.holder = game_state.get_player_id_at(position);
+                ProcState::NeedRoll(RequestedRoll::D6)
+            }
+            ProcInput::Reaction(Reaction::Tentacles, Action::Simple(SimpleAT::Decline)) => {
+                self.let_go()
+            }
+            ProcInput::Roll(RollResult::D6(roll)) => {
+                let holder_str = game_state
+                    .get_player_unsafe(self.holder.unwrap())
+                    .stats
+                    .str_;
+                let mover_str = game_state.get_player_unsafe(self.id).stats.str_;
+                let result = roll as i8 + holder_str as i8 - mover_str as i8;
+                if roll == D6::One || (roll != D6::Six && result < 6) {
+                    return self.let_go();
+                }
+                game_state.log(format!("Held by tentacles at {:?}", self.from));
+                game_state.move_player(self.id, self.from).unwrap();
+                // the activation ends and the rest of the path is dropped
+                game_state.get_mut_player_unsafe(self.id).used = true;
+                ProcState::Done
+            }
+            _ => panic!("Unexpected input {:?}", input),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct DodgeProc {
     target: D6Target,
     id: PlayerID,
+    from: Position,
+    dived: bool,
 }
 impl DodgeProc {
-    fn new(id: PlayerID, target: D6Target) -> AnyProc {
-        AnyProc::DodgeProc(SimpleProcContainer::new(DodgeProc { target, id }))
+    fn new(id: PlayerID, target: D6Target, from: Position) -> AnyProc {
+        AnyProc::DodgeProc(SimpleProcContainer::new(DodgeProc {
+            target,
+            id,
+            from,
+            dived: false,
+        }))
     }
 }
 impl SimpleProc for DodgeProc {
@@ -78,10 +300,35 @@ impl SimpleProc for DodgeProc {
         self.target
     }
 
+    fn reaction(&self, game_state: &GameState) -> Option<(i8, Box<AvailableActions>)> {
+        if self.dived {
+            return None;
+        }
+        diving_tackle_reaction(game_state, self.id, self.from)
+    }
+
+    fn apply_reaction(&mut self, game_state: &mut GameState, action: Action) -> Vec<AnyProc> {
+        self.dived = true;
+        self.target.add_modifer(-2);
+        apply_diving_tackle(game_state, self.from, action)
+    }
+
     fn reroll_skill(&self) -> Option<Skill> {
         Some(Skill::Dodge)
     }
 
+    fn apply_success(&self, game_state: &mut GameState) -> Vec<AnyProc> {
+        // a diving tackler may already lie in the square left
+        if game_state.get_player_at(self.from).is_some()
+            || players_reacting_to_dodge(game_state, self.id, self.from, Skill::Shadowing)
+                .is_empty()
+        {
+            Vec::new()
+        } else {
+            vec![Shadowing::new(self.id, self.from)]
+        }
+    }
+
     fn apply_failure(&mut self, game_state: &mut GameState) -> Vec<AnyProc> {
         game_state.info.turnover = true;
         vec![block_procs::KnockDown::new(self.id)]
@@ -91,26 +338,84 @@ impl SimpleProc for DodgeProc {
         self.id
     }
 }
-fn proc_from_roll(roll: PathingEvent, active_player: PlayerID) -> AnyProc {
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct JumpProc {
+    target: D6Target,
+    id: PlayerID,
+    from: Position,
+    dived: bool,
+}
+impl JumpProc {
+    fn new(id: PlayerID, target: D6Target, from: Position) -> AnyProc {
+        AnyProc::JumpProc(SimpleProcContainer::new(JumpProc {
+            target,
+            id,
+            from,
+            dived: false,
+        }))
+    }
+}
+impl SimpleProc for JumpProc {
+    fn d6_target(&self) -> D6Target {
+        self.target
+    }
+
+    fn reaction(&self, game_state: &GameState) -> Option<(i8, Box<AvailableActions>)> {
+        if self.dived {
+            return None;
+        }
+        diving_tackle_reaction(game_state, self.id, self.from)
+    }
+
+    fn apply_reaction(&mut self, game_state: &mut GameState, action: Action) -> Vec<AnyProc> {
+        self.dived = true;
+        self.target.add_modifer(-2);
+        apply_diving_tackle(game_state, self.from, action)
+    }
+
+    fn reroll_skill(&self) -> Option<Skill> {
+        None
+    }
+
+    fn apply_failure(&mut self, game_state: &mut GameState) -> Vec<AnyProc> {
+        game_state.info.turnover = true;
+        vec![block_procs::KnockDown::new(self.id)]
+    }
+
+    fn player_id(&self) -> PlayerID {
+        self.id
+    }
+}
+fn proc_from_roll(roll: PathingEvent, active_player: PlayerID, from: Position) -> AnyProc {
     match roll {
-        PathingEvent::Dodge(target) => DodgeProc::new(active_player, target),
+        PathingEvent::Dodge(target) => DodgeProc::new(active_player, target, from),
         PathingEvent::GFI(target) => GfiProc::new(active_player, target),
+        PathingEvent::Jump(target) => JumpProc::new(active_player, target, from),
         PathingEvent::Pickup(target) => ball_procs::PickupProc::new(active_player, target),
-        PathingEvent::Block(id, dices) => block_procs::Block::new(dices, id),
+        PathingEvent::Block(id, dices) => block_procs::Block::new_blitz(dices, id),
         PathingEvent::Handoff(id, target) => ball_procs::Catch::new(id, target),
         PathingEvent::Touchdown(id) => ball_procs::Touchdown::new(id),
         PathingEvent::Foul(victim, target) => {
             casualty_procs::Armor::new_foul(victim, target, active_player)
         }
         PathingEvent::StandUp => StandUp::new(active_player),
-        PathingEvent::Pass { to, pass, modifer } => ball_procs::Pass::new(to, pass, modifer),
+        PathingEvent::Pass {
+            to,
+            pass,
+            hail_mary: true,
+            ..
+        } => ball_procs::Pass::new_hail_mary(to, pass),
+        PathingEvent::Pass {
+            to, pass, modifer, ..
+        } => ball_procs::Pass::new(to, pass, modifer),
     }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 enum MoveActionState {
     Init,
-    ActivePath(NodeIterator),
+    /// The rest of the path and where it left the player, e.g. Tentacles may stop it before that
+    ActivePath(NodeIterator, Position),
     SelectPath,
 }
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -127,19 +432,50 @@ impl MoveAction {
     }
     fn continue_along_path(path: &mut NodeIterator, game_state: &mut GameState) -> ProcState {
         let player_id = game_state.info.active_player.unwrap();
+        let mut from = game_state.get_player_unsafe(player_id).position;
 
         for next_event in path.by_ref() {
             match next_event {
                 PositionOrEvent::Position(position) => {
+                    from = game_state.get_player_unsafe(player_id).position;
                     game_state.move_player(player_id, position).unwrap();
                     game_state.log(format!("Moved to {:?}", position));
                     game_state.get_mut_player_unsafe(player_id).add_move(1);
                 }
                 PositionOrEvent::Event(roll) => {
-                    if event_ends_player_action(&roll) {
+                    if matches!(roll, PathingEvent::Jump(_)) {
+                        // a jump is two squares of movement
+                        game_state.get_mut_player_unsafe(player_id).add_move(1);
+                    }
+                    if matches!(roll, PathingEvent::Block(..)) {
+                        // a blitz block costs a square of movement, then the blitzer may move on
+                        game_state.get_mut_player_unsafe(player_id).add_move(1);
+                        game_state.info.player_action_done = true;
+                    }
+                    if matches!(roll, PathingEvent::Dodge(_)) {
+                        // the pathfinder adds break tackle to the first dodge
+                        let player = game_state.get_mut_player_unsafe(player_id);
+                        if player.can_use_skill(Skill::BreakTackle) {
+                            player.use_skill(Skill::BreakTackle);
+                        }
+                    }
+                    let sneaky_git = matches!(roll, PathingEvent::Foul(..))
+                        && game_state
+                            .get_player_unsafe(player_id)
+                            .has_skill(Skill::SneakyGit);
+                    if sneaky_git {
+                        // a sneaky git may move on after the foul
+                        game_state.info.player_action_done = true;
+                    } else if event_ends_player_action(&roll) {
                         game_state.get_mut_player_unsafe(player_id).used = true;
                     }
-                    return ProcState::NotDoneNew(proc_from_roll(roll, player_id));
+                    if matches!(roll, PathingEvent::Dodge(_) | PathingEvent::Jump(_))
+                        && !players_reacting_to_dodge(game_state, player_id, from, Skill::Tentacles)
+                            .is_empty()
+                    {
+                        return ProcState::NotDoneNew(Tentacles::new(player_id, from, roll));
+                    }
+                    return ProcState::NotDoneNew(proc_from_roll(roll, player_id, from));
                 }
             }
         }
@@ -149,6 +485,12 @@ impl MoveAction {
         let player = game_state.get_player_unsafe(self.player_id);
         let mut aa = AvailableActions::new(player.stats.team);
         aa.insert_paths(PathFinder::player_paths(game_state, self.player_id).unwrap());
+        if game_state.info.player_action_type == Some(PosAT::StartThrowTeamMate) {
+            let throwable = game_state.get_throwable_team_mates(self.player_id);
+            if !throwable.is_empty() {
+                aa.insert_positional(PosAT::SelectPosition, throwable);
+            }
+        }
         aa.insert_simple(SimpleAT::EndPlayerTurn);
         aa
     }
@@ -171,13 +513,26 @@ impl Procedure for MoveAction {
                 self.state = MoveActionState::SelectPath;
                 ProcState::NeedAction(self.available_actions(game_state))
             }
-            (ProcInput::Nothing, MoveActionState::ActivePath(path)) => {
+            (ProcInput::Nothing, MoveActionState::ActivePath(path, position)) => {
+                if game_state.get_player_unsafe(self.player_id).position != *position {
+                    self.state = MoveActionState::SelectPath;
+                    return ProcState::NeedAction(self.available_actions(game_state));
+                }
                 let proc_state = MoveAction::continue_along_path(path, game_state);
+                *position = game_state.get_player_unsafe(self.player_id).position;
                 if path.is_empty() {
                     self.state = MoveActionState::Init;
                 }
                 proc_state
             }
+            (
+                ProcInput::Action(Action::Positional(PosAT::SelectPosition, position)),
+                MoveActionState::SelectPath,
+            ) => {
+                self.state = MoveActionState::Init;
+                let thrown_id = game_state.get_player_id_at(position).unwrap();
+                ProcState::NotDoneNew(ball_procs::ThrowTeamMate::new(thrown_id))
+            }
             (ProcInput::Action(Action::Positional(_, position)), MoveActionState::SelectPath) => {
                 let mut path = game_state
                     .available_actions
@@ -188,7 +543,8 @@ impl Procedure for MoveAction {
                 if path.is_empty() {
                     self.state = MoveActionState::Init;
                 } else {
-                    self.state = MoveActionState::ActivePath(path);
+                    let position = game_state.get_player_unsafe(self.player_id).position;
+                    self.state = MoveActionState::ActivePath(path, position);
                 }
                 proc_state
             }
@@ -208,7 +564,7 @@ mod tests {
     use std::collections::HashMap;
     use std::iter::zip;
 
-    use crate::core::dices::{BlockDice, D6Target};
+    use crate::core::dices::{BlockDice, D6Target, RollTarget};
     use crate::core::model::*;
     use crate::core::pathing::{PathFinder, PathingEvent};
     use crate::core::table::*;
@@ -266,7 +622,7 @@ mod tests {
         state.fixes.fix_d6(5); //armor
         state.fixes.fix_d6(4); //injury
         state.fixes.fix_d6(5); //injury
-        state.step_simple(SimpleAT::DontUseReroll);
+        state.step_simple(SimpleAT::Decline);
 
         assert!(state.get_player_id_at_coord(2, 1).is_none());
         assert!(state.get_players_on_pitch().all(|player| player.id != id));
@@ -715,7 +1071,7 @@ mod tests {
         state.fixes.fix_d6(1); //Catch
         state.step_positional(PosAT::Pass, target_pos);
         state.fixes.fix_d8_direction(Direction::up()); //Catch
-        state.step_simple(SimpleAT::DontUseReroll);
+        state.step_simple(SimpleAT::Decline);
         assert_eq!(
             state.ball,
             BallState::OnGround(interceptor + Direction::up())
@@ -730,7 +1086,7 @@ mod tests {
         state.fixes.fix_d6(1); //deflect
         state.step_positional(PosAT::Pass, target_pos);
         state.fixes.fix_d6(6); //Catch
-        state.step_simple(SimpleAT::DontUseReroll);
+        state.step_simple(SimpleAT::Decline);
         let carrier_id = state.get_player_id_at(target_pos).unwrap();
         assert_eq!(state.ball, BallState::Carried(carrier_id));
         assert_eq!(state.available_actions.team.unwrap(), TeamType::Home);
@@ -802,6 +1158,137 @@ mod tests {
         assert_eq!(state.available_actions.team.unwrap(), TeamType::Away);
     }
 
+    #[test]
+    fn pass_skill_modifiers() {
+        let (mut state, start_pos, target_pos, _) = setup_simple_pass(false, 7);
+        let id = state.get_player_id_at(start_pos).unwrap();
+        let quick_pos = start_pos + Direction::down() * 2;
+        let modifiers = |state: &GameState| {
+            (
+                state.get_pass_modifier(id, start_pos, quick_pos).unwrap(),
+                state.get_pass_modifier(id, start_pos, target_pos).unwrap(),
+            )
+        };
+        assert_eq!(modifiers(&state), (0, -2));
+
+        let player = state.get_mut_player_unsafe(id);
+        player.stats.give_skill(Skill::Accurate);
+        player.stats.give_skill(Skill::StrongArm);
+        assert_eq!(modifiers(&state), (1, -1));
+
+        state
+            .get_mut_player_unsafe(id)
+            .stats
+            .give_skill(Skill::NervesOfSteel);
+        let marker_id = state.get_player_id_at(target_pos).unwrap();
+        state.get_mut_player_unsafe(marker_id).stats.team = TeamType::Away;
+        state
+            .move_player(marker_id, start_pos + Direction::up())
+            .unwrap();
+        assert_eq!(modifiers(&state), (1, -1));
+    }
+    #[test]
+    fn safe_pass_keeps_the_ball() {
+        let (mut state, start_pos, target_pos, _) = setup_simple_pass(false, 2);
+        let id = state.get_player_id_at(start_pos).unwrap();
+        state
+            .get_mut_player_unsafe(id)
+            .stats
+            .give_skill(Skill::SafePass);
+        state.fixes.fix_d6(1); //Pass fumbled
+        state.step_positional(PosAT::Pass, target_pos);
+        assert_eq!(state.ball, BallState::Carried(id));
+        assert!(state.get_player_unsafe(id).used);
+        assert_eq!(state.available_actions.team.unwrap(), TeamType::Home);
+    }
+    #[test]
+    fn hail_mary_pass() {
+        let (state, start_pos, target_pos, _) = setup_simple_pass(false, 14);
+        assert!(!state.is_legal_action(&Action::Positional(PosAT::Pass, target_pos)));
+
+        let mut state = GameStateBuilder::new()
+            .add_home_player(start_pos)
+            .add_home_player(target_pos)
+            .add_ball_pos(start_pos)
+            .build();
+        let id = state.get_player_id_at(start_pos).unwrap();
+        let player = state.get_mut_player_unsafe(id);
+        player.stats.give_skill(Skill::HailMaryPass);
+        player.moves = player.total_movement_left();
+        state.step_positional(PosAT::StartPass, start_pos);
+        // never accurate, it must scatter back to the catcher
+        let paths = PathFinder::player_paths(&state, id).unwrap();
+        let prob = paths[target_pos].as_ref().unwrap().prob;
+        assert!((prob - 5.0 / 6.0 * 2.0 / 6.0 * 24.0 / 512.0).abs() < 0.001);
+
+        state.fixes.fix_d6(2); //Pass not fumbled
+        state.fixes.fix_d8_direction(Direction::right()); //Scatter
+        state.fixes.fix_d8_direction(Direction::from((-1, -1))); //Scatter
+        state.fixes.fix_d8_direction(Direction::down()); //Scatter
+        state.fixes.fix_d6(5); //Catch
+        state.step_positional(PosAT::Pass, target_pos);
+        let carrier_id = state.get_player_id_at(target_pos).unwrap();
+        assert_eq!(state.ball, BallState::Carried(carrier_id));
+    }
+    #[test]
+    fn diving_catch_next_to_landing_square() {
+        let (mut state, _, target_pos, _) = setup_simple_pass(false, 7);
+        let catcher_id = state.get_player_id_at(target_pos).unwrap();
+        state
+            .get_mut_player_unsafe(catcher_id)
+            .stats
+            .give_skill(Skill::DivingCatch);
+        state.fixes.fix_d6(4); //Pass inaccurate
+        state.fixes.fix_d8_direction(Direction::down()); //Scatter
+        state.fixes.fix_d8_direction(Direction::up()); //Scatter
+        state.fixes.fix_d8_direction(Direction::down()); //Scatter
+        state.fixes.fix_d6(6); //Diving catch
+        state.step_positional(PosAT::Pass, target_pos);
+        assert_eq!(state.ball, BallState::Carried(catcher_id));
+        assert_eq!(state.get_player_unsafe(catcher_id).position, target_pos);
+    }
+    #[test]
+    fn pass_block() {
+        let start_pos = Position::new((3, 3));
+        let target_pos = Position::new((9, 3));
+        let blocker_pos = Position::new((6, 6));
+        let mut state = GameStateBuilder::new()
+            .add_home_player(start_pos)
+            .add_home_player(target_pos)
+            .add_away_player(blocker_pos)
+            .add_ball_pos(start_pos)
+            .build();
+        let id = state.get_player_id_at(start_pos).unwrap();
+        state.get_mut_player_unsafe(id).moves = state.get_player_unsafe(id).total_movement_left();
+        let blocker_id = state.get_player_id_at(blocker_pos).unwrap();
+        let prob_at_target = |state: &GameState| {
+            PathFinder::player_paths(state, id).unwrap()[target_pos]
+                .as_ref()
+                .unwrap()
+                .prob
+        };
+        state.step_positional(PosAT::StartPass, start_pos);
+        let free_prob = prob_at_target(&state);
+        state
+            .get_mut_player_unsafe(blocker_id)
+            .stats
+            .give_skill(Skill::PassBlock);
+        assert!(prob_at_target(&state) < free_prob);
+
+        state.step_positional(PosAT::Pass, target_pos);
+        assert_eq!(state.available_actions.team.unwrap(), TeamType::Away);
+        state.step_positional(PosAT::SelectPosition, blocker_pos);
+        assert!(!state.is_legal_action(&Action::Positional(
+            PosAT::SelectPosition,
+            Position::new((6, 9))
+        )));
+        state.fixes.fix_d6(6); //Pass
+        state.fixes.fix_d6(6); //deflect
+        state.fixes.fix_d6(6); //Catch
+        state.step_positional(PosAT::SelectPosition, Position::new((6, 4)));
+        assert_eq!(state.ball, BallState::Carried(blocker_id));
+    }
+
     #[test]
     fn pass_avoid_intercepts() {
         let mut field = "".to_string();
@@ -920,6 +1407,25 @@ mod tests {
         state.step_positional(PosAT::FollowUp, target);
 
         assert!(!state.is_legal_action(&Action::Positional(PosAT::Block, push_to)));
+        // the blitzer may move on after the block
+        assert_eq!(state.info.player_action_type, Some(PosAT::StartBlitz));
+        assert!(state.is_legal_action(&Action::Positional(PosAT::Move, target + (-1, 0))));
+    }
+
+    #[test]
+    fn standup_pathing_with_greasy_cleats() -> Result<()> {
+        let start_pos = Position::new((5, 5));
+        let mut state = GameStateBuilder::new().add_home_player(start_pos).build();
+        let id = state.get_player_id_at(start_pos).unwrap();
+        let player = state.get_mut_player_unsafe(id);
+        player.status = PlayerStatus::Down;
+        player.stats.add_prayer(PrayerToNuffle::GreasyCleats);
+
+        // standing up costs 3 of the 5 squares left with Greasy Cleats
+        let paths = PathFinder::player_paths(&state, id)?;
+        assert!(paths.get(7, 5).as_ref().unwrap().prob > 0.99);
+        assert!(paths.get(8, 5).as_ref().unwrap().prob < 0.9);
+        Ok(())
     }
 
     #[test]
@@ -941,7 +1447,7 @@ mod tests {
         state.fixes.fix_d6(1); //injury
         state.fixes.fix_d6(2); //injury
 
-        state.step_simple(SimpleAT::DontUseReroll);
+        state.step_simple(SimpleAT::Decline);
         assert!(state.get_player_unsafe(id).used);
         assert_eq!(state.get_player_unsafe(id).status, PlayerStatus::Stunned);
         assert_eq!(state.get_player_unsafe(id).position, move_target);
@@ -963,4 +1469,567 @@ mod tests {
         assert_eq!(player.gfis_left(), 2);
         state.step_positional(PosAT::StartMove, move_target)
     }
+
+    fn jump_state(wall_status: PlayerStatus) -> (GameState, PlayerID) {
+        let start_pos = Position::new((2, 8));
+        let wall: Vec<(Coord, Coord)> = (1..HEIGHT_ - 1).map(|y| (3, y)).collect();
+        let mut state = GameStateBuilder::new()
+            .add_home_player(start_pos)
+            .add_away_players(&wall)
+            .add_away_player(Position::new((1, 8)))
+            .build();
+        for (x, y) in wall {
+            let wall_id = state.get_player_id_at_coord(x, y).unwrap();
+            state.get_mut_player_unsafe(wall_id).status = wall_status;
+        }
+        let id = state.get_player_id_at(start_pos).unwrap();
+        (state, id)
+    }
+
+    #[test]
+    fn jump_pathing() -> Result<()> {
+        let (state, id) = jump_state(PlayerStatus::Down);
+        let paths = PathFinder::player_paths(&state, id)?;
+        // 4+ for agility 3 without the dodge +1, one tackle zone where the jump starts
+        let path = paths.get(4, 7).as_ref().unwrap();
+        assert!((path.prob - 1.0 / 3.0).abs() < 0.001);
+        let path = paths.get(4, 9).as_ref().unwrap();
+        assert!((path.prob - 1.0 / 3.0).abs() < 0.001);
+        // the jump costs two squares of movement
+        let path = paths.get(7, 2).as_ref().unwrap();
+        assert!((path.prob - 1.0 / 3.0).abs() < 0.001);
+        let path = paths.get(10, 2).as_ref().unwrap();
+        assert!((path.prob - 1.0 / 3.0 * 5.0 / 6.0 * 5.0 / 6.0).abs() < 0.001);
+        assert!(paths.get(11, 2).is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn jump_uses_the_worse_square() -> Result<()> {
+        let (mut state, id) = jump_state(PlayerStatus::Down);
+        for (y, to) in [(1, Position::new((5, 6))), (2, Position::new((5, 7)))] {
+            let marker_id = state.get_player_id_at_coord(3, y).unwrap();
+            state.move_player(marker_id, to)?;
+            state.get_mut_player_unsafe(marker_id).status = PlayerStatus::Up;
+        }
+        let paths = PathFinder::player_paths(&state, id)?;
+        // two tackle zones where the jump lands and one where it starts
+        let path = paths.get(4, 7).as_ref().unwrap();
+        assert!((path.prob - 1.0 / 6.0).abs() < 0.001);
+        Ok(())
+    }
+
+    #[test]
+    fn leap_over_standing_players() -> Result<()> {
+        let (mut state, id) = jump_state(PlayerStatus::Up);
+        let paths = PathFinder::player_paths(&state, id)?;
+        assert!(paths.get(4, 8).is_none());
+
+        state
+            .get_mut_player_unsafe(id)
+            .stats
+            .give_skill(Skill::Leap);
+        let paths = PathFinder::player_paths(&state, id)?;
+        // four tackle zones where the jump starts, reduced by one with leap
+        let path = paths.get(4, 8).as_ref().unwrap();
+        assert!((path.prob - 1.0 / 6.0).abs() < 0.001);
+        Ok(())
+    }
+
+    #[test]
+    fn jump_success_and_failure() {
+        let (mut state, id) = jump_state(PlayerStatus::Stunned);
+        state.step_positional(PosAT::StartMove, Position::new((2, 8)));
+        state.fixes.fix_d6(5);
+        state.step_positional(PosAT::Move, Position::new((4, 7)));
+        let player = state.get_player_unsafe(id);
+        assert_eq!(player.position, Position::new((4, 7)));
+        assert_eq!(player.moves_left(), player.stats.ma - 2);
+        state.step_simple(SimpleAT::EndPlayerTurn);
+        state.step_simple(SimpleAT::EndTurn);
+        state.step_simple(SimpleAT::EndTurn);
+
+        //jump back and fail
+        state.step_positional(PosAT::StartMove, Position::new((4, 7)));
+        state.fixes.fix_d6(2);
+        state.step_positional(PosAT::Move, Position::new((2, 7)));
+        state.fixes.fix_d6(1); //armor
+        state.fixes.fix_d6(1); //armor
+        state.step_simple(SimpleAT::Decline);
+        let player = state.get_player_unsafe(id);
+        // the best path is to jump outside the tackle zone and then step in
+        assert_eq!(player.position, Position::new((2, 6)));
+        assert_eq!(player.status, PlayerStatus::Down);
+        assert!(state.away_to_act());
+    }
+
+    #[test]
+    fn weather_pass_probabilities() -> Result<()> {
+        let (mut state, start_pos, target_pos, _) = setup_simple_pass(false, 5);
+        let id = state.get_player_id_at(start_pos).unwrap();
+        let catcher_id = state.get_player_id_at(target_pos).unwrap();
+        let catch_target = state.get_catch_target(catcher_id)?;
+        let long_pos = Position::new((3 + 8, 3));
+        state.move_player(catcher_id, long_pos)?;
+        let nice_long = PathFinder::player_paths(&state, id)?
+            .get(long_pos.x as usize, 3)
+            .as_ref()
+            .unwrap()
+            .prob;
+        state.move_player(catcher_id, target_pos)?;
+        let nice = PathFinder::player_paths(&state, id)?
+            .get(target_pos.x as usize, 3)
+            .as_ref()
+            .unwrap()
+            .prob;
+
+        // short pass, 4+ for the lineman and -1 for the range
+        let pass_prob = D6Target::FivePlus.success_prob();
+        assert!((nice - pass_prob * catch_target.success_prob()).abs() < 0.001);
+
+        state.info.weather = Weather::Sunny;
+        let paths = PathFinder::player_paths(&state, id)?;
+        let sunny = paths.get(target_pos.x as usize, 3).as_ref().unwrap().prob;
+        let sunny_pass_prob = D6Target::SixPlus.success_prob();
+        assert!((sunny - sunny_pass_prob * catch_target.success_prob()).abs() < 0.001);
+
+        state.info.weather = Weather::Rain;
+        let paths = PathFinder::player_paths(&state, id)?;
+        let rain = paths.get(target_pos.x as usize, 3).as_ref().unwrap().prob;
+        let rain_catch_prob = catch_target.clone().add_modifer(-1).success_prob();
+        assert!((rain - pass_prob * rain_catch_prob).abs() < 0.001);
+
+        state.info.weather = Weather::Blizzard;
+        let paths = PathFinder::player_paths(&state, id)?;
+        let blizzard = paths.get(target_pos.x as usize, 3).as_ref().unwrap().prob;
+        assert!((blizzard - nice).abs() < 0.001);
+        state.move_player(catcher_id, long_pos)?;
+        let paths = PathFinder::player_paths(&state, id)?;
+        assert!(nice_long > 0.0);
+        assert!(paths.get(long_pos.x as usize, 3).is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn weather_movement_probabilities() -> Result<()> {
+        let start_pos = Position::new((2, 2));
+        let ball_pos = Position::new((4, 2));
+        let mut state = GameStateBuilder::new()
+            .add_home_player(start_pos)
+            .add_ball_pos(ball_pos)
+            .build();
+        let id = state.get_player_id_at(start_pos).unwrap();
+        let prob_at = |state: &GameState, x: usize, y: usize| {
+            PathFinder::player_paths(state, id)
+                .unwrap()
+                .get(x, y)
+                .as_ref()
+                .unwrap()
+                .prob
+        };
+
+        // ag 3 lineman picks up on 3+, two rushes to reach ten squares away
+        assert!((prob_at(&state, 4, 2) - 4.0 / 6.0).abs() < 0.001);
+        assert!((prob_at(&state, 2, 10) - 5.0 / 6.0 * 5.0 / 6.0).abs() < 0.001);
+
+        state.info.weather = Weather::Rain;
+        assert!((prob_at(&state, 4, 2) - 3.0 / 6.0).abs() < 0.001);
+        assert!((prob_at(&state, 2, 10) - 5.0 / 6.0 * 5.0 / 6.0).abs() < 0.001);
+
+        state.info.weather = Weather::Blizzard;
+        assert!((prob_at(&state, 4, 2) - 4.0 / 6.0).abs() < 0.001);
+        assert!((prob_at(&state, 2, 10) - 4.0 / 6.0 * 4.0 / 6.0).abs() < 0.001);
+        Ok(())
+    }
+
+    #[test]
+    fn sprint_and_jump_up() -> Result<()> {
+        let start_pos = Position::new((2, 5));
+        let mut state = GameStateBuilder::new().add_home_player(start_pos).build();
+        let id = state.get_player_id_at(start_pos).unwrap();
+        let far_pos = Position::new((2 + 9, 5));
+        assert!(PathFinder::player_paths(&state, id)?[far_pos].is_none());
+
+        let player = state.get_mut_player_unsafe(id);
+        player.stats.give_skill(Skill::Sprint);
+        player.stats.give_skill(Skill::JumpUp);
+        player.status = PlayerStatus::Down;
+        assert_eq!(player.total_movement_left(), 6 + 3);
+        assert!(PathFinder::player_paths(&state, id)?[far_pos].is_some());
+
+        state.step_positional(PosAT::StartMove, start_pos);
+        for _ in 0..3 {
+            state.fixes.fix_d6(2);
+        }
+        state.step_positional(PosAT::Move, far_pos);
+        let player = state.get_player_unsafe(id);
+        assert_eq!(player.position, far_pos);
+        assert_eq!(player.status, PlayerStatus::Up);
+        assert_eq!(player.total_movement_left(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn break_tackle() -> Result<()> {
+        let start_pos = Position::new((5, 5));
+        let mut state = GameStateBuilder::new()
+            .add_home_player(start_pos)
+            .add_away_player(Position::new((6, 5)))
+            .build();
+        let id = state.get_player_id_at(start_pos).unwrap();
+        state
+            .get_mut_player_unsafe(id)
+            .stats
+            .give_skill(Skill::BreakTackle);
+
+        let paths = PathFinder::player_paths(&state, id)?;
+        let events: Vec<PositionOrEvent> = paths.get(4, 5).as_ref().unwrap().iter().collect();
+        assert!(events.contains(&PositionOrEvent::Event(PathingEvent::Dodge(
+            D6Target::TwoPlus
+        ))));
+
+        state.step_positional(PosAT::StartMove, start_pos);
+        state.fixes.fix_d6(2);
+        state.step_positional(PosAT::Move, Position::new((4, 5)));
+        let player = state.get_player_unsafe(id);
+        assert_eq!(player.position, Position::new((4, 5)));
+        assert!(!player.can_use_skill(Skill::BreakTackle));
+        Ok(())
+    }
+
+    fn diving_tackle_state() -> (GameState, PlayerID, PlayerID) {
+        let mut state = GameStateBuilder::new()
+            .add_home_player(Position::new((5, 5)))
+            .add_away_player(Position::new((6, 5)))
+            .build();
+        let id = state.get_player_id_at_coord(5, 5).unwrap();
+        let tackler_id = state.get_player_id_at_coord(6, 5).unwrap();
+        state
+            .get_mut_player_unsafe(tackler_id)
+            .stats
+            .give_skill(Skill::DivingTackle);
+        state.home.rerolls = 0;
+        (state, id, tackler_id)
+    }
+
+    #[test]
+    fn diving_tackle() -> Result<()> {
+        let (mut state, id, tackler_id) = diving_tackle_state();
+        let start_pos = Position::new((5, 5));
+        let tackler_pos = Position::new((6, 5));
+
+        // the path does not assume the dive
+        let paths = PathFinder::player_paths(&state, id)?;
+        assert!((paths.get(4, 5).as_ref().unwrap().prob - 4.0 / 6.0).abs() < 0.001);
+
+        state.step_positional(PosAT::StartMove, start_pos);
+        state.fixes.fix_d6(4); //dodge, passed on 3+ but not on 5+
+        state.step_positional(PosAT::Move, Position::new((4, 5)));
+        assert_eq!(state.get_available_actions().team, Some(TeamType::Away));
+        assert!(state.is_legal_action(&Action::Simple(SimpleAT::Decline)));
+
+        state.fixes.fix_d6(1); //armor
+        state.fixes.fix_d6(1); //armor
+        state.step_positional(PosAT::SelectPosition, tackler_pos);
+
+        let tackler = state.get_player_unsafe(tackler_id);
+        assert_eq!(tackler.position, start_pos);
+        assert_eq!(tackler.status, PlayerStatus::Down);
+        assert_eq!(state.get_player_unsafe(id).status, PlayerStatus::Down);
+        assert_eq!(state.info.team_turn, TeamType::Away);
+        Ok(())
+    }
+
+    #[test]
+    fn diving_tackle_after_failed_dodge() {
+        let (mut state, id, tackler_id) = diving_tackle_state();
+        state.step_positional(PosAT::StartMove, Position::new((5, 5)));
+        state.fixes.fix_d6(2); //dodge
+        state.fixes.fix_d6(1); //armor
+        state.fixes.fix_d6(1); //armor
+        state.step_positional(PosAT::Move, Position::new((4, 5)));
+
+        // no dive is offered when the dodge fails anyway
+        let tackler = state.get_player_unsafe(tackler_id);
+        assert_eq!(tackler.position, Position::new((6, 5)));
+        assert_eq!(tackler.status, PlayerStatus::Up);
+        assert_eq!(state.get_player_unsafe(id).status, PlayerStatus::Down);
+        assert_eq!(state.info.team_turn, TeamType::Away);
+    }
+
+    #[test]
+    fn diving_tackle_declined_or_passed_anyway() {
+        let (mut state, id, tackler_id) = diving_tackle_state();
+        state.step_positional(PosAT::StartMove, Position::new((5, 5)));
+        state.fixes.fix_d6(4);
+        state.step_positional(PosAT::Move, Position::new((4, 5)));
+        state.step_simple(SimpleAT::Decline);
+        assert_eq!(state.get_player_unsafe(id).position, Position::new((4, 5)));
+        assert_eq!(state.get_player_unsafe(id).status, PlayerStatus::Up);
+        assert_eq!(state.get_player_unsafe(tackler_id).status, PlayerStatus::Up);
+
+        let (mut state, id, tackler_id) = diving_tackle_state();
+        state.step_positional(PosAT::StartMove, Position::new((5, 5)));
+        state.fixes.fix_d6(6);
+        state.step_positional(PosAT::Move, Position::new((4, 5)));
+        state.step_positional(PosAT::SelectPosition, Position::new((6, 5)));
+        // a 6 still passes with -2, but the tackler is placed prone
+        assert_eq!(state.get_player_unsafe(id).status, PlayerStatus::Up);
+        let tackler = state.get_player_unsafe(tackler_id);
+        assert_eq!(tackler.position, Position::new((5, 5)));
+        assert_eq!(tackler.status, PlayerStatus::Down);
+        assert_eq!(state.info.team_turn, TeamType::Home);
+    }
+
+    #[test]
+    fn diving_tackle_on_jump() {
+        let (mut state, id) = jump_state(PlayerStatus::Stunned);
+        let tackler_id = state.get_player_id_at_coord(1, 8).unwrap();
+        state
+            .get_mut_player_unsafe(tackler_id)
+            .stats
+            .give_skill(Skill::DivingTackle);
+        state.home.rerolls = 0;
+
+        state.step_positional(PosAT::StartMove, Position::new((2, 8)));
+        state.fixes.fix_d6(5); //jump, passed on 5+ but not with -2
+        state.step_positional(PosAT::Move, Position::new((4, 7)));
+        assert_eq!(state.get_available_actions().team, Some(TeamType::Away));
+        state.fixes.fix_d6(1); //armor
+        state.fixes.fix_d6(1); //armor
+        state.step_positional(PosAT::SelectPosition, Position::new((1, 8)));
+
+        let tackler = state.get_player_unsafe(tackler_id);
+        assert_eq!(tackler.position, Position::new((2, 8)));
+        assert_eq!(tackler.status, PlayerStatus::Down);
+        assert_eq!(state.get_player_unsafe(id).status, PlayerStatus::Down);
+        assert!(state.away_to_act());
+    }
+
+    #[test]
+    fn no_shadowing_after_diving_tackle() {
+        let mut state = GameStateBuilder::new()
+            .add_home_player(Position::new((5, 5)))
+            .add_away_players(&[(6, 5), (6, 4)])
+            .build();
+        let id = state.get_player_id_at_coord(5, 5).unwrap();
+        let tackler_id = state.get_player_id_at_coord(6, 5).unwrap();
+        let shadow_id = state.get_player_id_at_coord(6, 4).unwrap();
+        state
+            .get_mut_player_unsafe(tackler_id)
+            .stats
+            .give_skill(Skill::DivingTackle);
+        state
+            .get_mut_player_unsafe(shadow_id)
+            .stats
+            .give_skill(Skill::Shadowing);
+        state.home.rerolls = 0;
+
+        state.step_positional(PosAT::StartMove, Position::new((5, 5)));
+        state.fixes.fix_d6(6);
+        state.step_positional(PosAT::Move, Position::new((4, 5)));
+        state.step_positional(PosAT::SelectPosition, Position::new((6, 5)));
+
+        // the square left is taken by the prone tackler, so there's nothing to shadow into
+        assert_eq!(state.get_player_unsafe(id).position, Position::new((4, 5)));
+        assert_eq!(
+            state.get_player_unsafe(tackler_id).position,
+            Position::new((5, 5))
+        );
+        assert_eq!(
+            state.get_player_unsafe(shadow_id).position,
+            Position::new((6, 4))
+        );
+        assert_eq!(state.get_available_actions().team, Some(TeamType::Home));
+    }
+
+    #[test]
+    fn shadowing() {
+        let start_pos = Position::new((5, 5));
+        let shadow_pos = Position::new((6, 5));
+        let mut state = GameStateBuilder::new()
+            .add_home_player(start_pos)
+            .add_away_player(shadow_pos)
+            .build();
+        let shadow_id = state.get_player_id_at(shadow_pos).unwrap();
+        let shadower = state.get_mut_player_unsafe(shadow_id);
+        shadower.stats.give_skill(Skill::Shadowing);
+        shadower.stats.ma = 7;
+
+        state.step_positional(PosAT::StartMove, start_pos);
+        state.fixes.fix_d6(3);
+        state.step_positional(PosAT::Move, Position::new((4, 5)));
+        assert_eq!(state.get_available_actions().team, Some(TeamType::Away));
+
+        state.fixes.fix_d6(5); // 5 + 7 - 6
+        state.step_positional(PosAT::SelectPosition, shadow_pos);
+        assert_eq!(state.get_player_unsafe(shadow_id).position, start_pos);
+        assert_eq!(state.get_available_actions().team, Some(TeamType::Home));
+    }
+
+    #[test]
+    fn mutation_dodge_and_pickup_targets() -> Result<()> {
+        let start_pos = Position::new((5, 5));
+        let marker_pos = Position::new((6, 5));
+        let mut state = GameStateBuilder::new()
+            .add_home_player(start_pos)
+            .add_away_player(marker_pos)
+            .build();
+        let id = state.get_player_id_at(start_pos).unwrap();
+        let marker_id = state.get_player_id_at(marker_pos).unwrap();
+        let dodge_prob = |state: &GameState| {
+            let paths = PathFinder::player_paths(state, id).unwrap();
+            paths.get(4, 5).as_ref().unwrap().prob
+        };
+        assert!((dodge_prob(&state) - 4.0 / 6.0).abs() < 0.001);
+
+        state
+            .get_mut_player_unsafe(id)
+            .stats
+            .give_skill(Skill::TwoHeads);
+        assert!((dodge_prob(&state) - 5.0 / 6.0).abs() < 0.001);
+
+        state
+            .get_mut_player_unsafe(marker_id)
+            .stats
+            .give_skill(Skill::PrehensileTail);
+        assert!((dodge_prob(&state) - 4.0 / 6.0).abs() < 0.001);
+
+        let mut state = GameStateBuilder::new()
+            .add_home_player(Position::new((2, 2)))
+            .add_away_players(&[(5, 1), (5, 3)])
+            .add_ball((4, 2))
+            .build();
+        state.info.weather = Weather::Rain;
+        let id = state.get_player_id_at_coord(2, 2).unwrap();
+        let pickup_prob = |state: &GameState| {
+            let paths = PathFinder::player_paths(state, id).unwrap();
+            paths.get(4, 2).as_ref().unwrap().prob
+        };
+        assert!((pickup_prob(&state) - 1.0 / 6.0).abs() < 0.001);
+
+        state
+            .get_mut_player_unsafe(id)
+            .stats
+            .give_skill(Skill::ExtraArms);
+        assert!((pickup_prob(&state) - 2.0 / 6.0).abs() < 0.001);
+
+        state
+            .get_mut_player_unsafe(id)
+            .stats
+            .give_skill(Skill::BigHand);
+        assert!((pickup_prob(&state) - 5.0 / 6.0).abs() < 0.001);
+        Ok(())
+    }
+
+    #[test]
+    fn tentacles() {
+        for (roll, held) in [(5, true), (4, false)] {
+            let start_pos = Position::new((5, 5));
+            let target_pos = Position::new((3, 5));
+            let holder_pos = Position::new((6, 5));
+            let mut state = GameStateBuilder::new()
+                .add_home_player(start_pos)
+                .add_away_player(holder_pos)
+                .build();
+            let id = state.get_player_id_at(start_pos).unwrap();
+            let holder_id = state.get_player_id_at(holder_pos).unwrap();
+            let holder = state.get_mut_player_unsafe(holder_id);
+            holder.stats.give_skill(Skill::Tentacles);
+            holder.stats.str_ = 4;
+
+            state.step_positional(PosAT::StartMove, start_pos);
+            state.step_positional(PosAT::Move, target_pos);
+            assert_eq!(state.get_available_actions().team, Some(TeamType::Away));
+
+            state.fixes.fix_d6(roll); // roll + 4 - 3
+            if !held {
+                state.fixes.fix_d6(3); // dodge
+            }
+            state.step_positional(PosAT::SelectPosition, holder_pos);
+
+            let player = state.get_player_unsafe(id);
+            assert_eq!(state.get_available_actions().team, Some(TeamType::Home));
+            if held {
+                assert_eq!(player.position, start_pos);
+                assert!(player.used);
+                assert_eq!(state.info.active_player, None);
+                assert!(!state.is_legal_action(&Action::Positional(PosAT::Move, target_pos)));
+                assert!(!state.is_legal_action(&Action::Positional(PosAT::StartMove, start_pos)));
+            } else {
+                assert_eq!(player.position, target_pos);
+            }
+        }
+    }
+
+    #[test]
+    fn pro_and_leader_rerolls() {
+        for (pro_roll, success) in [(3, true), (2, false)] {
+            let start_pos = Position::new((5, 5));
+            let mut state = GameStateBuilder::new()
+                .add_home_players(&[(5, 5), (1, 1)])
+                .add_away_player(Position::new((6, 5)))
+                .build();
+            let id = state.get_player_id_at(start_pos).unwrap();
+            state.get_mut_player_unsafe(id).stats.give_skill(Skill::Pro);
+            let leader_id = state.get_player_id_at_coord(1, 1).unwrap();
+            state
+                .get_mut_player_unsafe(leader_id)
+                .stats
+                .give_skill(Skill::Leader);
+            state.home.leader_reroll = true;
+
+            state.step_positional(PosAT::StartMove, start_pos);
+            state.fixes.fix_d6(2); // dodge
+            state.step_positional(PosAT::Move, Position::new((4, 5)));
+            for at in [
+                SimpleAT::UseReroll,
+                SimpleAT::UseLeaderReroll,
+                SimpleAT::UsePro,
+                SimpleAT::Decline,
+            ] {
+                assert!(state.is_legal_action(&Action::Simple(at)));
+            }
+
+            state.fixes.fix_d6(pro_roll);
+            if success {
+                state.fixes.fix_d6(3); // dodge
+            } else {
+                state.fixes.fix_d6(1); // armor
+                state.fixes.fix_d6(1); // armor
+            }
+            state.step_simple(SimpleAT::UsePro);
+
+            assert!(!state.get_player_unsafe(id).can_use_skill(Skill::Pro));
+            assert_eq!(state.home.rerolls, 3);
+            assert!(state.home.can_use_leader_reroll());
+            assert_eq!(state.home_to_act(), success);
+            let status = state.get_player_unsafe(id).status;
+            assert_eq!(status == PlayerStatus::Up, success);
+        }
+    }
+
+    #[test]
+    fn jump_up_to_block() {
+        for (roll, success) in [(3, true), (2, false)] {
+            let start_pos = Position::new((5, 5));
+            let mut state = GameStateBuilder::new()
+                .add_home_player(start_pos)
+                .add_away_player(Position::new((6, 5)))
+                .build();
+            let id = state.get_player_id_at(start_pos).unwrap();
+            let player = state.get_mut_player_unsafe(id);
+            player.stats.give_skill(Skill::JumpUp);
+            player.status = PlayerStatus::Down;
+            state.home.rerolls = 0;
+
+            state.fixes.fix_d6(roll);
+            state.step_positional(PosAT::StartBlock, start_pos);
+            let block = Action::Positional(PosAT::Block, Position::new((6, 5)));
+            assert_eq!(state.is_legal_action(&block), success);
+            let player = state.get_player_unsafe(id);
+            assert_eq!(player.status == PlayerStatus::Up, success);
+            assert!(!state.info.turnover);
+        }
+    }
 }
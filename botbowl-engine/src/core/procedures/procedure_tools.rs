@@ -1,13 +1,114 @@
 use serde::{Deserialize, Serialize};
 
-use crate::core::dices::{D6Target, RequestedRoll, RollResult};
+use crate::core::dices::{D6Target, RequestedRoll, RollResult, RollTarget};
 use crate::core::gamestate::GameState;
 use crate::core::model::ProcInput;
-use crate::core::model::{Action, AvailableActions, PlayerID, ProcState, Procedure};
+use crate::core::model::{Action, AvailableActions, PlayerID, ProcState, Procedure, TeamType};
 use crate::core::table::{SimpleAT, Skill};
 
 use super::AnyProc;
 
+/// Where the re-roll of a failed roll comes from, a roll is never re-rolled more than once
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RerollSource {
+    /// A skill re-rolling this kind of roll, e.g. Dodge. It costs nothing so it's used right away
+    Skill(Skill),
+    Team,
+    /// Usable instead of the team re-roll while a player with Leader is on the pitch
+    Leader,
+    /// Once per turn, the player gets to re-roll on a 3+
+    Pro,
+}
+impl RerollSource {
+    /// The action of the coach choosing the re-roll, skill re-rolls don't need one
+    pub fn action(&self) -> Option<SimpleAT> {
+        match self {
+            RerollSource::Skill(_) => None,
+            RerollSource::Team => Some(SimpleAT::UseReroll),
+            RerollSource::Leader => Some(SimpleAT::UseLeaderReroll),
+            RerollSource::Pro => Some(SimpleAT::UsePro),
+        }
+    }
+    pub fn from_action(action_type: SimpleAT) -> Option<RerollSource> {
+        match action_type {
+            SimpleAT::UseReroll => Some(RerollSource::Team),
+            SimpleAT::UseLeaderReroll => Some(RerollSource::Leader),
+            SimpleAT::UsePro => Some(RerollSource::Pro),
+            _ => None,
+        }
+    }
+}
+
+/// The re-roll sources for a failed roll of the player, the skill re-roll first if there is one
+pub fn reroll_sources(
+    game_state: &GameState,
+    id: PlayerID,
+    skill: Option<Skill>,
+) -> Vec<RerollSource> {
+    let player = game_state.get_player_unsafe(id);
+    let team = game_state.get_team(player.stats.team);
+    let mut sources = Vec::new();
+    match skill {
+        Some(skill) if player.can_use_skill(skill) => sources.push(RerollSource::Skill(skill)),
+        _ => (),
+    }
+    if team.can_use_reroll() {
+        sources.push(RerollSource::Team);
+    }
+    if team.can_use_leader_reroll()
+        && game_state
+            .get_players_on_pitch_in_team(player.stats.team)
+            .any(|p| p.has_skill(Skill::Leader))
+    {
+        sources.push(RerollSource::Leader);
+    }
+    if player.can_use_skill(Skill::Pro) {
+        sources.push(RerollSource::Pro);
+    }
+    sources
+}
+
+/// Spends the re-roll. Returns the target of a roll needed before re-rolling, the 3+ of Pro or
+/// the roll of a Loner using a team re-roll.
+pub fn use_reroll(
+    game_state: &mut GameState,
+    id: PlayerID,
+    source: RerollSource,
+) -> Option<D6Target> {
+    let team = game_state.get_player_unsafe(id).stats.team;
+    match source {
+        RerollSource::Skill(skill) => game_state.get_mut_player_unsafe(id).use_skill(skill),
+        RerollSource::Team => game_state.get_mut_team(team).use_reroll(),
+        RerollSource::Leader => game_state.get_mut_team(team).use_leader_reroll(),
+        RerollSource::Pro => {
+            game_state.get_mut_player_unsafe(id).use_skill(Skill::Pro);
+            return Some(D6Target::ThreePlus);
+        }
+    }
+    let team_reroll = matches!(source, RerollSource::Team | RerollSource::Leader);
+    if team_reroll {
+        game_state.get_player_unsafe(id).stats.loner_target()
+    } else {
+        None
+    }
+}
+
+/// Adds the actions choosing between the re-roll sources, without declining them
+pub fn insert_reroll_actions(aa: &mut AvailableActions, sources: &[RerollSource]) {
+    sources
+        .iter()
+        .filter_map(RerollSource::action)
+        .for_each(|at| aa.insert_simple(at));
+}
+
+/// The coach of the team chooses between the re-roll sources or not to re-roll
+pub fn reroll_actions(team: TeamType, sources: &[RerollSource]) -> Box<AvailableActions> {
+    let mut aa = AvailableActions::new(team);
+    insert_reroll_actions(&mut aa, sources);
+    aa.insert_simple(SimpleAT::Decline);
+    aa
+}
+
 #[allow(unused_variables)]
 pub trait SimpleProc {
     fn d6_target(&self) -> D6Target; //called immidiately before
@@ -17,6 +118,15 @@ pub trait SimpleProc {
     }
     fn apply_failure(&mut self, game_state: &mut GameState) -> Vec<AnyProc>;
     fn player_id(&self) -> PlayerID;
+    /// The other team may react to a passed roll with a modifier to it, e.g. Diving Tackle. The
+    /// modifier and the reaction window.
+    fn reaction(&self, game_state: &GameState) -> Option<(i8, Box<AvailableActions>)> {
+        None
+    }
+    /// The other team reacted to the passed roll
+    fn apply_reaction(&mut self, game_state: &mut GameState, action: Action) -> Vec<AnyProc> {
+        Vec::new()
+    }
 }
 impl From<Vec<AnyProc>> for ProcState {
     fn from(procs: Vec<AnyProc>) -> Self {
@@ -31,24 +141,68 @@ impl From<Vec<AnyProc>> for ProcState {
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum RollProcState {
     Init,
+    /// The re-roll needs a roll of its own first, Pro's 3+ or a Loner's roll
+    RerollRoll,
     RerollUsed,
     //WaitingForSkillReroll,
 }
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReactionState {
+    /// The roll passed and the other team may react, it fails with the reaction if `narrow`
+    Offered { narrow: bool },
+    /// The procedures of the reaction come first, then the roll passes or fails
+    Resolving { passed: bool },
+}
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct SimpleProcContainer<T: SimpleProc + std::fmt::Debug> {
     proc: T,
     state: RollProcState,
+    reaction: Option<ReactionState>,
 }
 impl<T: SimpleProc + std::fmt::Debug> SimpleProcContainer<T> {
     pub fn new(proc: T) -> Self {
         SimpleProcContainer {
             proc,
             state: RollProcState::Init,
+            reaction: None,
         }
     }
     pub fn id(&self) -> PlayerID {
         self.proc.player_id()
     }
+    /// With a possible reaction the roll tells whether it also passes with the reaction's modifier
+    fn roll(&self, game_state: &GameState) -> ProcState {
+        let target = self.proc.d6_target();
+        match self.proc.reaction(game_state) {
+            Some((modifier, _)) => {
+                let mut reacted_target = target;
+                reacted_target.add_modifer(modifier);
+                ProcState::NeedRoll(RequestedRoll::D6ThreeOutcomes(target, reacted_target))
+            }
+            None => ProcState::NeedRoll(RequestedRoll::D6PassFail(target)),
+        }
+    }
+    fn offer_reaction(&mut self, game_state: &mut GameState, narrow: bool) -> ProcState {
+        match self.proc.reaction(game_state) {
+            Some((_, aa)) => {
+                self.reaction = Some(ReactionState::Offered { narrow });
+                ProcState::NeedAction(aa)
+            }
+            None => ProcState::from(self.proc.apply_success(game_state)),
+        }
+    }
+    fn reroll(&mut self, game_state: &mut GameState, source: RerollSource) -> ProcState {
+        match use_reroll(game_state, self.id(), source) {
+            Some(target) => {
+                self.state = RollProcState::RerollRoll;
+                ProcState::NeedRoll(RequestedRoll::D6PassFail(target))
+            }
+            None => {
+                self.state = RollProcState::RerollUsed;
+                self.roll(game_state)
+            }
+        }
+    }
 }
 
 impl<T> Procedure for SimpleProcContainer<T>
@@ -57,49 +211,64 @@ where
 {
     fn step(&mut self, game_state: &mut GameState, input: ProcInput) -> ProcState {
         match input {
-            ProcInput::Nothing => {
-                return ProcState::NeedRoll(RequestedRoll::D6PassFail(self.proc.d6_target()));
+            ProcInput::Nothing => match self.reaction.take() {
+                Some(ReactionState::Resolving { passed: true }) => {
+                    return ProcState::from(self.proc.apply_success(game_state))
+                }
+                Some(ReactionState::Resolving { passed: false }) => (),
+                _ => return self.roll(game_state),
+            },
+            ProcInput::Roll(RollResult::Pass) if self.state == RollProcState::RerollRoll => {
+                self.state = RollProcState::RerollUsed;
+                return self.roll(game_state);
             }
-            ProcInput::Roll(RollResult::Pass) => {
-                return ProcState::from(self.proc.apply_success(game_state))
+            ProcInput::Roll(RollResult::Pass) => return self.offer_reaction(game_state, false),
+            ProcInput::Roll(RollResult::MiddleOutcome) => {
+                return self.offer_reaction(game_state, true)
             }
-            ProcInput::Roll(RollResult::Fail) if self.state == RollProcState::RerollUsed => {
-                return ProcState::from(self.proc.apply_failure(game_state))
+            ProcInput::Roll(RollResult::Fail) if self.state == RollProcState::RerollRoll => {
+                game_state.log("the re-roll is wasted".to_string());
+                return ProcState::from(self.proc.apply_failure(game_state));
             }
-            ProcInput::Roll(RollResult::Fail) => (/*figure out if reroll is available below*/),
-            ProcInput::Action(Action::Simple(SimpleAT::DontUseReroll)) => {
+            ProcInput::Roll(RollResult::Fail) => (),
+            ProcInput::Action(Action::Simple(SimpleAT::Decline)) => {
                 return ProcState::from(self.proc.apply_failure(game_state));
             }
-            ProcInput::Action(Action::Simple(SimpleAT::UseReroll)) => {
-                // since the proc input is an action, available actions is garanteed to be set. unwrap is safe
-                let team = game_state.available_actions.team.unwrap();
-                game_state.get_mut_team(team).use_reroll();
-                self.state = RollProcState::RerollUsed;
-                return ProcState::NeedRoll(RequestedRoll::D6PassFail(self.proc.d6_target()));
+            ProcInput::Action(Action::Simple(at)) if RerollSource::from_action(at).is_some() => {
+                return self.reroll(game_state, RerollSource::from_action(at).unwrap());
+            }
+            ProcInput::Reaction(_, Action::Simple(SimpleAT::Decline)) => {
+                self.reaction = None;
+                return ProcState::from(self.proc.apply_success(game_state));
+            }
+            ProcInput::Reaction(_, action) => {
+                let Some(ReactionState::Offered { narrow }) = self.reaction else {
+                    panic!("Unexpected reaction: {:?}", action);
+                };
+                self.reaction = Some(ReactionState::Resolving { passed: !narrow });
+                let procs = self.proc.apply_reaction(game_state, action);
+                if !procs.is_empty() {
+                    return ProcState::NotDoneNewProcs(procs);
+                }
+                return self.step(game_state, ProcInput::Nothing);
             }
             _ => panic!("Unexpected input: {:?}", input),
         };
 
-        match self.proc.reroll_skill() {
-            Some(skill) if game_state.get_player_unsafe(self.id()).can_use_skill(skill) => {
-                game_state.get_mut_player_unsafe(self.id()).use_skill(skill);
-                self.state = RollProcState::RerollUsed;
-                return ProcState::NeedRoll(RequestedRoll::D6PassFail(self.proc.d6_target()));
-            }
-            _ => (),
+        // the roll failed, figure out if a re-roll is available
+        if self.state == RollProcState::RerollUsed {
+            return ProcState::from(self.proc.apply_failure(game_state));
         }
-
-        if game_state
-            .get_team_from_player(self.id())
-            .unwrap()
-            .can_use_reroll()
-        {
-            let team = game_state.get_player_unsafe(self.id()).stats.team;
-            let mut aa = AvailableActions::new(team);
-            aa.insert_simple(SimpleAT::UseReroll);
-            aa.insert_simple(SimpleAT::DontUseReroll);
-            return ProcState::NeedAction(aa);
+        let sources = reroll_sources(game_state, self.id(), self.proc.reroll_skill());
+        match sources.first() {
+            None => ProcState::from(self.proc.apply_failure(game_state)),
+            Some(&RerollSource::Skill(skill)) => {
+                self.reroll(game_state, RerollSource::Skill(skill))
+            }
+            Some(_) => {
+                let team = game_state.get_player_unsafe(self.id()).stats.team;
+                ProcState::NeedAction(reroll_actions(team, &sources))
+            }
         }
-        ProcState::from(self.proc.apply_failure(game_state))
     }
 }
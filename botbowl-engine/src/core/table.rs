@@ -17,6 +17,15 @@ pub enum PosAT {
     Foul,
     StartBlock,
     Block,
+    PlacePlayer,
+    PlaceLineman,
+    PlaceBlitzer,
+    PlaceThrower,
+    PlaceCatcher,
+    SendToReserves,
+    KickoffAim,
+    StartThrowTeamMate,
+    ThrowTeamMate,
 }
 
 #[derive(Debug, Eq, Hash, PartialEq, Clone, Copy, Serialize, Deserialize)]
@@ -27,7 +36,18 @@ pub enum SimpleAT {
     SelectPowPush,
     SelectSkull,
     UseReroll,
-    DontUseReroll,
+    UseLeaderReroll,
+    UsePro,
+    UseApothecary,
+    SelectFirstCasualty,
+    SelectSecondCasualty,
+    UseBribe,
+    ArgueTheCall,
+    UseStandFirm,
+    UseFend,
+    UseWrestle,
+    UseJuggernaut,
+    UseMultipleBlock,
     EndPlayerTurn,
     EndTurn,
     Heads,
@@ -35,8 +55,21 @@ pub enum SimpleAT {
     Kick,
     Receive,
     SetupLine,
+    SetupWedge,
+    SetupSpread,
+    SetupZone,
+    SetupKickToBlitzers,
+    SetupCustom(u8),
     EndSetup,
     KickoffAimMiddle,
+    /// Declines any optional choice, e.g. a re-roll, the apothecary or a reaction
+    Decline,
+    BuyExtraTeamTraining,
+    BuyBribe,
+    BuyWanderingApothecary,
+    BuyAssistantCoach,
+    BuyCheerleader,
+    EndInducements,
 }
 
 #[derive(Eq, Hash, PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
@@ -63,6 +96,97 @@ pub enum Skill {
     Catch,
     SureHands,
     SureFeet,
+    Leap,
+    Leader,
+    Guard,
+    Tackle,
+    Wrestle,
+    StandFirm,
+    SideStep,
+    Juggernaut,
+    Dauntless,
+    Frenzy,
+    MultipleBlock,
+    Sprint,
+    JumpUp,
+    BreakTackle,
+    DivingTackle,
+    Shadowing,
+    Accurate,
+    StrongArm,
+    NervesOfSteel,
+    SafePass,
+    HailMaryPass,
+    DivingCatch,
+    PassBlock,
+    MightyBlow,
+    Claws,
+    ThickSkull,
+    DirtyPlayer,
+    SneakyGit,
+    Regeneration,
+    Stunty,
+    BoneHead,
+    ReallyStupid,
+    WildAnimal,
+    UnchannelledFury,
+    AnimalSavagery,
+    Loner,
+    ThrowTeamMate,
+    RightStuff,
+    AlwaysHungry,
+    StripBall,
+    Fend,
+    Tentacles,
+    TwoHeads,
+    ExtraArms,
+    BigHand,
+    PrehensileTail,
+    Horns,
+    VeryLongLegs,
+    Pro,
+    Kick,
+    KickoffReturn,
+    DumpOff,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Characteristic {
+    MA,
+    ST,
+    AG,
+    PA,
+    AV,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LastingInjury {
+    HeadInjury,
+    SmashedKnee,
+    BrokenArm,
+    NeckInjury,
+    DislocatedShoulder,
+}
+impl LastingInjury {
+    pub fn characteristic(&self) -> Characteristic {
+        match self {
+            LastingInjury::HeadInjury => Characteristic::AV,
+            LastingInjury::SmashedKnee => Characteristic::MA,
+            LastingInjury::BrokenArm => Characteristic::PA,
+            LastingInjury::NeckInjury => Characteristic::AG,
+            LastingInjury::DislocatedShoulder => Characteristic::ST,
+        }
+    }
+}
+
+/// Result on the casualty table, the lasting injury is `None` until it's rolled for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CasualtyOutcome {
+    BadlyHurt,
+    SeriouslyHurt,
+    SeriousInjury,
+    LastingInjury(Option<LastingInjury>),
+    Dead,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
@@ -86,25 +210,92 @@ impl From<NumBlockDices> for u8 {
     }
 }
 
-// #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize, Deserialize)]
-// enum PrayerToNuffleEffect {
-//     TreachearousTrapdoor,
-//     FriendsWithTheRef(TeamType),
-//     Stiletto(PlayerID),
-//     IronMan(PlayerID),
-//     KnuckleDusters(PlayerID),
-//     BadHabit(PlayerID),
-//     GreasyCleats(PlayerID),
-//     BlessedStatueOfNuffle(PlayerID),
-//     MalesUnderThePitch,
-//     PerfectPassing(TeamType),
-//     FanInteraction(TeamType),
-//     NecessaryViolence(TeamType),
-//     FoulingFrenzy(TeamType),
-//     ThrowARock(TeamType),
-//     UnderScrutiny(TeamType),
-//     IntensiveTraining(PlayerID, Skill),
-// }
+/// How long an effect lasts, shorter durations end before longer ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum EffectDuration {
+    Drive,
+    Half,
+    Game,
+}
+
+/// The results of the Prayers to Nuffle table. Prayers without a rule in the engine are only
+/// kept track of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PrayerToNuffle {
+    TreacherousTrapdoor,
+    FriendsWithTheRef,
+    Stiletto,
+    /// A player of the team gets +1 AV, up to 11+
+    IronMan,
+    /// A player of the team gains Mighty Blow
+    KnuckleDusters,
+    /// D3 players of the opposing team gain Loner (2+)
+    BadHabits,
+    /// A player of the opposing team gets -1 MA
+    GreasyCleats,
+    /// A player of the team gains Pro
+    BlessedStatueOfNuffle,
+    /// -1 to all rushes, for both teams
+    MolesUnderThePitch,
+    PerfectPassing,
+    FanInteraction,
+    NecessaryViolence,
+    FoulingFrenzy,
+    ThrowARock,
+    UnderScrutiny,
+    IntensiveTraining,
+}
+impl PrayerToNuffle {
+    pub fn duration(&self) -> EffectDuration {
+        match self {
+            PrayerToNuffle::TreacherousTrapdoor
+            | PrayerToNuffle::MolesUnderThePitch
+            | PrayerToNuffle::UnderScrutiny => EffectDuration::Half,
+            PrayerToNuffle::IronMan
+            | PrayerToNuffle::BlessedStatueOfNuffle
+            | PrayerToNuffle::PerfectPassing
+            | PrayerToNuffle::IntensiveTraining => EffectDuration::Game,
+            PrayerToNuffle::FriendsWithTheRef
+            | PrayerToNuffle::Stiletto
+            | PrayerToNuffle::KnuckleDusters
+            | PrayerToNuffle::BadHabits
+            | PrayerToNuffle::GreasyCleats
+            | PrayerToNuffle::FanInteraction
+            | PrayerToNuffle::NecessaryViolence
+            | PrayerToNuffle::FoulingFrenzy
+            | PrayerToNuffle::ThrowARock => EffectDuration::Drive,
+        }
+    }
+    /// The skill a player blessed by the prayer gains
+    pub fn skill(&self) -> Option<Skill> {
+        match self {
+            PrayerToNuffle::KnuckleDusters => Some(Skill::MightyBlow),
+            PrayerToNuffle::BadHabits => Some(Skill::Loner),
+            PrayerToNuffle::BlessedStatueOfNuffle => Some(Skill::Pro),
+            _ => None,
+        }
+    }
+    /// Whose players the prayer affects
+    pub fn target(&self) -> PrayerTarget {
+        match self {
+            PrayerToNuffle::IronMan
+            | PrayerToNuffle::KnuckleDusters
+            | PrayerToNuffle::BlessedStatueOfNuffle => PrayerTarget::Own,
+            PrayerToNuffle::BadHabits | PrayerToNuffle::GreasyCleats => PrayerTarget::Opponent,
+            _ => PrayerTarget::None,
+        }
+    }
+}
+
+/// The players a prayer to Nuffle affects
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PrayerTarget {
+    /// A player of the praying team's choice
+    Own,
+    /// Random players of the opposing team
+    Opponent,
+    None,
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum PlayerRole {
@@ -113,3 +304,13 @@ pub enum PlayerRole {
     Thrower,
     Catcher,
 }
+impl From<PlayerRole> for PosAT {
+    fn from(role: PlayerRole) -> Self {
+        match role {
+            PlayerRole::Lineman => PosAT::PlaceLineman,
+            PlayerRole::Blitzer => PosAT::PlaceBlitzer,
+            PlayerRole::Thrower => PosAT::PlaceThrower,
+            PlayerRole::Catcher => PosAT::PlaceCatcher,
+        }
+    }
+}
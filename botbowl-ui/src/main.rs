@@ -4,7 +4,6 @@ use std::{
     io::{self, stdout, Stdout},
     rc::Rc,
     time::{Duration, Instant},
-    u16,
 };
 
 use botbowl_engine::core::{
@@ -41,6 +40,7 @@ impl App {
         let runner = BotGameRunnerBuilder::new().build();
         App { game: runner }
     }
+    #[allow(dead_code)]
     fn new_show_state() -> App {
         let down_pos = Position::from((1, 1));
         let stunned_pos = Position::from((2, 2));